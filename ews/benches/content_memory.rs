@@ -0,0 +1,111 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Compares the peak memory used to deserialize a response containing a large
+//! file attachment when buffering its content and when streaming it.
+//!
+//! Run with `cargo bench --bench content_memory`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    io,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ews::{get_attachment::GetAttachmentResponse, soap::Envelope};
+
+/// An allocator which keeps track of the peak number of bytes allocated.
+struct PeakAlloc {
+    current: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+impl PeakAlloc {
+    /// Resets the peak to the number of bytes currently allocated.
+    fn reset(&self) {
+        self.peak
+            .store(self.current.load(Ordering::SeqCst), Ordering::SeqCst);
+    }
+
+    /// The peak number of bytes allocated since the last reset, excluding
+    /// those allocated at the time of the reset.
+    fn peak_since(&self, baseline: usize) -> usize {
+        self.peak.load(Ordering::SeqCst) - baseline
+    }
+}
+
+unsafe impl GlobalAlloc for PeakAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let current = self.current.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            self.peak.fetch_max(current, Ordering::SeqCst);
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.current.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: PeakAlloc = PeakAlloc {
+    current: AtomicUsize::new(0),
+    peak: AtomicUsize::new(0),
+};
+
+/// The size of the decoded attachment.
+const ATTACHMENT_SIZE: usize = 16 * 1024 * 1024;
+
+/// Builds a `GetAttachment` response envelope with a single file attachment of
+/// `ATTACHMENT_SIZE` bytes.
+fn build_document() -> Vec<u8> {
+    // Every group of four "QUJD" characters decodes to "ABC".
+    let content = "QUJD".repeat(ATTACHMENT_SIZE / 3);
+
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?><s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Header><h:ServerVersionInfo MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08" xmlns:h="http://schemas.microsoft.com/exchange/services/2006/types"/></s:Header><s:Body><m:GetAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:GetAttachmentResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Attachments><t:FileAttachment><t:AttachmentId Id="AAMkADk="/><t:Name>large.bin</t:Name><t:ContentType>application/octet-stream</t:ContentType><t:Content>{content}</t:Content></t:FileAttachment></m:Attachments></m:GetAttachmentResponseMessage></m:ResponseMessages></m:GetAttachmentResponse></s:Body></s:Envelope>"#
+    )
+    .into_bytes()
+}
+
+/// Runs `f` and returns the peak number of bytes it allocated.
+fn measure(f: impl FnOnce()) -> usize {
+    let baseline = ALLOCATOR.current.load(Ordering::SeqCst);
+    ALLOCATOR.reset();
+
+    f();
+
+    ALLOCATOR.peak_since(baseline)
+}
+
+fn main() {
+    let document = build_document();
+    println!(
+        "response document: {} MiB, attachment: {} MiB",
+        document.len() / (1024 * 1024),
+        ATTACHMENT_SIZE / (1024 * 1024)
+    );
+
+    let buffered = measure(|| {
+        let envelope = Envelope::<GetAttachmentResponse>::from_xml_document(&document)
+            .expect("deserialization should succeed");
+        drop(envelope);
+    });
+
+    let streamed = measure(|| {
+        let envelope = Envelope::<GetAttachmentResponse>::from_xml_reader_with_content_sinks(
+            &document[..],
+            |_| Ok(io::sink()),
+        )
+        .expect("deserialization should succeed");
+        drop(envelope);
+    });
+
+    println!("peak allocated, buffered: {} KiB", buffered / 1024);
+    println!("peak allocated, streamed: {} KiB", streamed / 1024);
+}
@@ -0,0 +1,694 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Access to the base64-encoded content of attachments and items.
+//!
+//! By default, the content of [`FileAttachment`]s and [`MimeContent`] is
+//! buffered in full as a base64 string when deserializing a response, which
+//! can be expensive for large attachments. The functions in this module
+//! instead decode that content as it is read, and write it to a caller-provided
+//! sink, such as a [`TempFileBuffer`].
+//!
+//! Helpers for encoding and decoding smaller base64 values in memory are also
+//! provided; most callers will want the typed accessors on the relevant types
+//! instead, such as [`MimeContent::decoded_bytes`].
+//!
+//! [`MimeContent::decoded_bytes`]: crate::MimeContent::decoded_bytes
+//! [`FileAttachment`]: crate::Attachment::FileAttachment
+//! [`MimeContent`]: crate::MimeContent
+
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    fs::{File, OpenOptions},
+    hash::{BuildHasher, Hasher},
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use quick_xml::{
+    events::Event,
+    name::{Namespace, ResolveResult},
+    NsReader, Writer,
+};
+
+use crate::{Error, TYPES_NS_URI};
+
+/// The kind of element whose content is being streamed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContentKind {
+    /// The content of a [`FileAttachment`].
+    ///
+    /// [`FileAttachment`]: crate::Attachment::FileAttachment
+    FileAttachment,
+
+    /// The [`MimeContent`] of an item.
+    ///
+    /// [`MimeContent`]: crate::MimeContent
+    MimeContent,
+}
+
+impl ContentKind {
+    /// Returns the kind of content held by the element `name` whose parent is
+    /// `parent`, both given as local names in the types namespace.
+    fn from_element(name: &[u8], parent: &[u8]) -> Option<Self> {
+        match (name, parent) {
+            (b"Content", b"FileAttachment") => Some(Self::FileAttachment),
+            (b"MimeContent", parent) if ITEM_ELEMENTS.contains(&parent) => Some(Self::MimeContent),
+            _ => None,
+        }
+    }
+}
+
+/// The local names of the elements representing items, which may have a
+/// `MimeContent` child.
+const ITEM_ELEMENTS: &[&[u8]] = &[
+    b"Item",
+    b"Message",
+    b"CalendarItem",
+    b"Contact",
+    b"DistributionList",
+    b"MeetingMessage",
+    b"MeetingRequest",
+    b"MeetingResponse",
+    b"MeetingCancellation",
+    b"PostItem",
+    b"Task",
+];
+
+/// Copies an XML document from `reader`, decoding the content of any
+/// attachment or MIME content element into the sink returned by `sink_for`
+/// instead of copying it.
+///
+/// `sink_for` is called with the kind of each such element, in document
+/// order. The returned document is identical to the original, except that
+/// these elements are empty, and can be deserialized as usual.
+pub fn stream_content<R, F, W>(reader: R, mut sink_for: F) -> Result<Vec<u8>, Error>
+where
+    R: BufRead,
+    F: FnMut(ContentKind) -> io::Result<W>,
+    W: Write,
+{
+    let mut reader = NsReader::from_reader(reader);
+    let mut writer = Writer::new(Vec::new());
+    let mut buf = Vec::new();
+
+    // The local names of the open elements, or `None` for those outside of
+    // the types namespace.
+    let mut open_elements: Vec<Option<Vec<u8>>> = Vec::new();
+
+    loop {
+        let (namespace, event) = reader.read_resolved_event_into(&mut buf)?;
+        let kind = match &event {
+            Event::Start(start) => {
+                let name = match namespace {
+                    ResolveResult::Bound(Namespace(namespace))
+                        if namespace == TYPES_NS_URI.as_bytes() =>
+                    {
+                        Some(start.local_name().into_inner().to_vec())
+                    }
+                    _ => None,
+                };
+
+                let kind = match (&name, open_elements.last()) {
+                    (Some(name), Some(Some(parent))) => ContentKind::from_element(name, parent),
+                    _ => None,
+                };
+
+                open_elements.push(name);
+                kind
+            }
+            Event::End(_) => {
+                open_elements.pop();
+                None
+            }
+            Event::Eof => break,
+            _ => None,
+        };
+
+        writer.write_event(event)?;
+
+        if let Some(kind) = kind {
+            // The reader doesn't buffer anything past the end of the event
+            // it returned, so we can take over from it until the start of
+            // the next tag.
+            let mut decoder = Base64Decoder::new(sink_for(kind)?);
+            copy_text(reader.get_mut(), &mut decoder).map_err(content_error)?;
+            decoder.finish().map_err(content_error)?.flush()?;
+        }
+
+        buf.clear();
+    }
+
+    Ok(writer.into_inner())
+}
+
+/// Converts an error from decoding content into an [`Error`], surfacing
+/// invalid base64 as such rather than as an I/O error.
+fn content_error(err: io::Error) -> Error {
+    if let Some(err) = err
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<Base64Error>())
+    {
+        return Error::InvalidBase64(err.clone());
+    }
+
+    Error::Io(err)
+}
+
+/// Writes the bytes from `reader` to `writer` until the start of the next XML
+/// tag, which is left unread.
+fn copy_text<R, W>(reader: &mut R, writer: &mut W) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    loop {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            // The document ended in the middle of an element. Leave it to the
+            // XML reader to report this.
+            return Ok(());
+        }
+
+        match available.iter().position(|&byte| byte == b'<') {
+            Some(end) => {
+                writer.write_all(&available[..end])?;
+                reader.consume(end);
+
+                return Ok(());
+            }
+            None => {
+                let len = available.len();
+                writer.write_all(available)?;
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+/// An error encountered while decoding base64 data.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Base64Error {
+    /// The data contains a byte which is neither part of the base64 alphabet
+    /// nor whitespace.
+    InvalidByte(u8),
+
+    /// The data contains padding before its end.
+    UnexpectedPadding,
+
+    /// The data ends with a single character after the last full group of
+    /// four, which cannot encode any bytes.
+    InvalidLength,
+}
+
+impl fmt::Display for Base64Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte(byte) => write!(f, "invalid byte {byte:#04x} in base64 data"),
+            Self::UnexpectedPadding => f.write_str("unexpected padding in base64 data"),
+            Self::InvalidLength => f.write_str("truncated base64 data"),
+        }
+    }
+}
+
+impl std::error::Error for Base64Error {}
+
+impl From<Base64Error> for io::Error {
+    fn from(value: Base64Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+/// Decodes a complete base64 string, ignoring any whitespace.
+pub fn decode_base64(data: &str) -> Result<Vec<u8>, Base64Error> {
+    let mut decoder = Base64Decoder::new(Vec::with_capacity(data.len() / 4 * 3));
+
+    // Writing to a `Vec` can't fail, so any error comes from decoding.
+    decoder
+        .write_all(data.as_bytes())
+        .and_then(|_| decoder.finish())
+        .map_err(|err| {
+            err.into_inner()
+                .and_then(|inner| inner.downcast::<Base64Error>().ok())
+                .map(|err| *err)
+                .expect("decoding into a `Vec` should only fail on invalid data")
+        })
+}
+
+/// Encodes bytes as a padded base64 string without line breaks.
+pub fn encode_base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bits = chunk.iter().enumerate().fold(0u32, |bits, (i, &byte)| {
+            bits | (byte as u32) << (16 - 8 * i)
+        });
+
+        // Each input byte contributes to one more output character.
+        for i in 0..=chunk.len() {
+            let index = (bits >> (18 - 6 * i)) & 0x3f;
+            encoded.push(ALPHABET[index as usize] as char);
+        }
+        for _ in chunk.len()..3 {
+            encoded.push('=');
+        }
+    }
+
+    encoded
+}
+
+/// Decodes text in the given character set, assuming UTF-8 if none is given.
+///
+/// Only UTF-8, US-ASCII, ISO-8859-1 and windows-1252 are supported. Character
+/// set names are matched case-insensitively, and common aliases such as
+/// `utf8`, `ascii`, `latin1` and `cp1252` are accepted.
+pub fn decode_text(bytes: Vec<u8>, character_set: Option<&str>) -> Result<String, Error> {
+    let label = character_set.unwrap_or("UTF-8");
+    let is_any_of = |names: &[&str]| {
+        names
+            .iter()
+            .any(|name| label.trim().eq_ignore_ascii_case(name))
+    };
+
+    if is_any_of(&["UTF-8", "utf8"]) {
+        Ok(String::from_utf8(bytes)?)
+    } else if is_any_of(&[
+        "US-ASCII",
+        "ascii",
+        "us",
+        "ANSI_X3.4-1968",
+        "ISO646-US",
+        "iso-ir-6",
+        "cp367",
+        "IBM367",
+        "csASCII",
+    ]) {
+        match bytes.iter().position(|byte| !byte.is_ascii()) {
+            Some(position) => Err(Error::InvalidAscii { position }),
+            None => Ok(bytes.into_iter().map(char::from).collect()),
+        }
+    } else if is_any_of(&[
+        "ISO-8859-1",
+        "ISO8859-1",
+        "ISO_8859-1",
+        "ISO_8859-1:1987",
+        "latin1",
+        "l1",
+        "iso-ir-100",
+        "cp819",
+        "IBM819",
+        "csISOLatin1",
+    ]) {
+        // The first 256 Unicode code points match ISO-8859-1.
+        Ok(bytes.into_iter().map(char::from).collect())
+    } else if is_any_of(&["windows-1252", "cp1252", "x-cp1252", "cswindows1252"]) {
+        Ok(bytes.into_iter().map(windows_1252_char).collect())
+    } else {
+        Err(Error::UnsupportedCharacterSet(label.to_string()))
+    }
+}
+
+/// Maps a windows-1252 byte to the character it encodes.
+///
+/// windows-1252 differs from ISO-8859-1 only in the range 0x80 to 0x9F. The
+/// five bytes in that range which it leaves undefined are mapped to the C1
+/// control characters of the same value, as in the WHATWG Encoding Standard.
+fn windows_1252_char(byte: u8) -> char {
+    const HIGH_RANGE: [char; 32] = [
+        '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}',
+        '\u{2021}', '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}',
+        '\u{8f}', '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}',
+        '\u{2014}', '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}',
+        '\u{178}',
+    ];
+
+    match byte {
+        0x80..=0x9f => HIGH_RANGE[(byte - 0x80) as usize],
+        _ => char::from(byte),
+    }
+}
+
+/// The maximum number of bytes of base64 data decoded in a single write.
+const DECODE_CHUNK_SIZE: usize = 8 * 1024;
+
+/// A [`Write`] adapter which decodes base64 data written to it and writes the
+/// decoded bytes to an inner sink.
+///
+/// Whitespace in the data is ignored. [`finish`](Self::finish) must be called
+/// once all of the data has been written to decode the final bytes.
+pub struct Base64Decoder<W> {
+    inner: W,
+    group: [u8; 4],
+    group_len: usize,
+    padding: usize,
+}
+
+impl<W> Base64Decoder<W>
+where
+    W: Write,
+{
+    /// Creates a decoder which writes decoded bytes to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            group: [0; 4],
+            group_len: 0,
+            padding: 0,
+        }
+    }
+
+    /// Decodes any remaining data and returns the inner sink.
+    pub fn finish(mut self) -> io::Result<W> {
+        match self.group_len {
+            0 => {}
+
+            // Tolerate missing padding.
+            2 | 3 => {
+                let mut decoded = Vec::with_capacity(2);
+                self.decode_group(&mut decoded);
+                self.inner.write_all(&decoded)?;
+            }
+
+            _ => return Err(Base64Error::InvalidLength.into()),
+        }
+
+        Ok(self.inner)
+    }
+
+    /// Appends the bytes encoded by the current group to `out`.
+    fn decode_group(&mut self, out: &mut Vec<u8>) {
+        let [a, b, c, d] = self.group;
+        let bits = (a as u32) << 18 | (b as u32) << 12 | (c as u32) << 6 | d as u32;
+        let bytes = [(bits >> 16) as u8, (bits >> 8) as u8, bits as u8];
+
+        // Each character beyond the first encodes (a little over) a byte.
+        out.extend_from_slice(&bytes[..self.group_len - 1]);
+
+        self.group = [0; 4];
+        self.group_len = 0;
+    }
+}
+
+impl<W> Write for Base64Decoder<W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Decode in bounded chunks, so that memory use doesn't depend on how
+        // much data the caller hands us at once.
+        let buf = &buf[..buf.len().min(DECODE_CHUNK_SIZE)];
+        let mut decoded = Vec::with_capacity(buf.len() / 4 * 3 + 3);
+
+        for &byte in buf {
+            if byte.is_ascii_whitespace() {
+                continue;
+            }
+
+            if byte == b'=' {
+                // Padding is only valid in the last two positions of the last
+                // group.
+                if self.group_len < 2 {
+                    return Err(Base64Error::UnexpectedPadding.into());
+                }
+
+                self.padding += 1;
+                if self.group_len + self.padding == 4 {
+                    self.decode_group(&mut decoded);
+                }
+
+                continue;
+            }
+
+            if self.padding > 0 {
+                return Err(Base64Error::UnexpectedPadding.into());
+            }
+
+            self.group[self.group_len] = decode_byte(byte)?;
+            self.group_len += 1;
+            if self.group_len == 4 {
+                self.decode_group(&mut decoded);
+            }
+        }
+
+        self.inner.write_all(&decoded)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Returns the 6-bit value of a base64 character.
+fn decode_byte(byte: u8) -> Result<u8, Base64Error> {
+    match byte {
+        b'A'..=b'Z' => Ok(byte - b'A'),
+        b'a'..=b'z' => Ok(byte - b'a' + 26),
+        b'0'..=b'9' => Ok(byte - b'0' + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        _ => Err(Base64Error::InvalidByte(byte)),
+    }
+}
+
+/// A buffer backed by a file in the system's temporary directory, for content
+/// too large to comfortably keep in memory.
+///
+/// The file has a random name and, on Unix, is only accessible to its owner.
+/// It is deleted when the buffer is dropped.
+pub struct TempFileBuffer {
+    file: File,
+    path: PathBuf,
+}
+
+impl TempFileBuffer {
+    /// Creates a new, empty buffer.
+    pub fn new() -> io::Result<Self> {
+        let dir = std::env::temp_dir();
+        loop {
+            let path = dir.join(format!("ews-content-{:016x}", random_u64()));
+
+            let mut options = OpenOptions::new();
+            options.read(true).write(true).create_new(true);
+            #[cfg(unix)]
+            std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+            match options.open(&path) {
+                Ok(file) => return Ok(Self { file, path }),
+
+                // Never reuse an existing file, which may have been created
+                // by someone else.
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// The path to the backing file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The number of bytes written to the buffer.
+    pub fn len(&self) -> io::Result<u64> {
+        Ok(self.file.metadata()?.len())
+    }
+
+    /// Whether nothing has been written to the buffer.
+    pub fn is_empty(&self) -> io::Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns a handle which appends to the buffer, for use as a sink while
+    /// the buffer itself is kept elsewhere.
+    pub fn writer(&self) -> io::Result<File> {
+        self.file.try_clone()
+    }
+
+    /// Returns a reader over the content of the buffer, from its start.
+    pub fn reader(&mut self) -> io::Result<impl Read + '_> {
+        self.file.flush()?;
+        self.file.seek(SeekFrom::Start(0))?;
+
+        Ok(&mut self.file)
+    }
+}
+
+impl Write for TempFileBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+impl Drop for TempFileBuffer {
+    fn drop(&mut self) {
+        // There's nothing useful we can do if this fails.
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Returns a random number, for use in file names which shouldn't be
+/// predictable.
+///
+/// The keys of each [`RandomState`] are derived from a seed obtained from the
+/// operating system's random number generator.
+fn random_u64() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut decoder = Base64Decoder::new(Vec::new());
+
+        // Feed the data in small chunks, so that groups span several writes.
+        for chunk in data.chunks(3) {
+            decoder.write_all(chunk)?;
+        }
+
+        decoder.finish()
+    }
+
+    #[test]
+    fn decode_text_character_sets() {
+        let decoded = decode_text(b"Caf\xc3\xa9".to_vec(), Some("utf8")).unwrap();
+        assert_eq!(decoded, "Caf\u{e9}");
+
+        let decoded = decode_text(b"Cafe".to_vec(), Some("ascii")).unwrap();
+        assert_eq!(decoded, "Cafe");
+        assert!(matches!(
+            decode_text(b"Caf\xe9".to_vec(), Some("US-ASCII")),
+            Err(Error::InvalidAscii { position: 3 })
+        ));
+
+        let decoded = decode_text(b"Caf\xe9 \x80".to_vec(), Some("Latin1")).unwrap();
+        assert_eq!(decoded, "Caf\u{e9} \u{80}");
+
+        let decoded = decode_text(b"Caf\xe9 \x80\x93\x81".to_vec(), Some("cp1252")).unwrap();
+        assert_eq!(decoded, "Caf\u{e9} \u{20ac}\u{201c}\u{81}");
+        let decoded = decode_text(b"\x9f".to_vec(), Some("Windows-1252")).unwrap();
+        assert_eq!(decoded, "\u{178}");
+
+        assert!(matches!(
+            decode_text(b"Cafe".to_vec(), Some("Shift_JIS")),
+            Err(Error::UnsupportedCharacterSet(charset)) if charset == "Shift_JIS"
+        ));
+    }
+
+    #[test]
+    fn decode_valid_base64() {
+        assert_eq!(decode(b"aGVsbG8gd29ybGQ=").unwrap(), b"hello world");
+        assert_eq!(
+            decode(b"aGVs\r\nbG8g\r\nd29y\r\nbGQh").unwrap(),
+            b"hello world!"
+        );
+        assert_eq!(decode(b"aGVsbG8gd29ybA==").unwrap(), b"hello worl");
+        assert_eq!(decode(b"aGVsbG8gd29ybA").unwrap(), b"hello worl");
+        assert_eq!(decode(b"").unwrap(), b"");
+    }
+
+    #[test]
+    fn encode_base64_round_trip() {
+        assert_eq!(encode_base64(b"hello world"), "aGVsbG8gd29ybGQ=");
+        assert_eq!(encode_base64(b"hello worl"), "aGVsbG8gd29ybA==");
+        assert_eq!(encode_base64(b"hello world!"), "aGVsbG8gd29ybGQh");
+        assert_eq!(encode_base64(b""), "");
+
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode_base64(&encode_base64(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn decode_invalid_base64() {
+        for (data, expected) in [
+            (&b"aGVs*G8="[..], Base64Error::InvalidByte(b'*')),
+            (b"aG=sbG8=", Base64Error::UnexpectedPadding),
+            (b"aGVsb", Base64Error::InvalidLength),
+        ] {
+            let err = decode(data).unwrap_err();
+            let err = err
+                .into_inner()
+                .and_then(|err| err.downcast::<Base64Error>().ok())
+                .unwrap();
+
+            assert_eq!(*err, expected);
+        }
+    }
+
+    #[test]
+    fn stream_attachment_content() {
+        let document = br#"<m:GetAttachmentResponseMessage ResponseClass="Success" xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:Attachments><t:FileAttachment><t:Name>a.txt</t:Name><t:Content>aGVsbG8=</t:Content></t:FileAttachment><t:FileAttachment><t:Name>b.txt</t:Name><t:Content>d29y
+bGQ=</t:Content></t:FileAttachment></m:Attachments></m:GetAttachmentResponseMessage>"#;
+
+        let mut sinks = Vec::new();
+        let remaining = stream_content(&document[..], |kind| {
+            assert_eq!(kind, ContentKind::FileAttachment);
+            sinks.push(TempFileBuffer::new()?);
+
+            sinks.last().unwrap().writer()
+        })
+        .unwrap();
+
+        let expected = br#"<m:GetAttachmentResponseMessage ResponseClass="Success" xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:Attachments><t:FileAttachment><t:Name>a.txt</t:Name><t:Content></t:Content></t:FileAttachment><t:FileAttachment><t:Name>b.txt</t:Name><t:Content></t:Content></t:FileAttachment></m:Attachments></m:GetAttachmentResponseMessage>"#;
+        assert_eq!(
+            std::str::from_utf8(&remaining).unwrap(),
+            std::str::from_utf8(expected).unwrap()
+        );
+
+        let contents: Vec<_> = sinks
+            .iter_mut()
+            .map(|sink| {
+                let mut content = String::new();
+                sink.reader().unwrap().read_to_string(&mut content).unwrap();
+                content
+            })
+            .collect();
+        assert_eq!(contents, ["hello", "world"]);
+    }
+
+    #[test]
+    fn stream_only_content_elements() {
+        let document = br#"<m:Items xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><t:Message><t:MimeContent CharacterSet="UTF-8">aGk=</t:MimeContent><m:Content>aGk=</m:Content><t:Body><t:Content>aGk=</t:Content></t:Body></t:Message><t:MimeContent>aGk=</t:MimeContent></m:Items>"#;
+
+        let mut kinds = Vec::new();
+        let remaining = stream_content(&document[..], |kind| {
+            kinds.push(kind);
+
+            Ok(Vec::new())
+        })
+        .unwrap();
+
+        // Only the MIME content of the message is streamed.
+        let expected = br#"<m:Items xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><t:Message><t:MimeContent CharacterSet="UTF-8"></t:MimeContent><m:Content>aGk=</m:Content><t:Body><t:Content>aGk=</t:Content></t:Body></t:Message><t:MimeContent>aGk=</t:MimeContent></m:Items>"#;
+        assert_eq!(
+            std::str::from_utf8(&remaining).unwrap(),
+            std::str::from_utf8(expected).unwrap()
+        );
+        assert_eq!(kinds, [ContentKind::MimeContent]);
+    }
+
+    #[test]
+    fn temp_file_buffers_are_private() {
+        let first = TempFileBuffer::new().unwrap();
+        let second = TempFileBuffer::new().unwrap();
+        assert_ne!(first.path(), second.path());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mode = first.path().metadata().unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
@@ -11,6 +11,9 @@ mod types;
 
 pub use types::*;
 
+pub mod content;
+pub mod push;
+
 #[cfg(feature = "interop")]
 pub mod interop;
 
@@ -33,6 +36,58 @@ pub enum Error {
     #[error("a fault occurred in the request")]
     RequestFault(Box<soap::Fault>),
 
+    // Boxed for the same reason as `RequestFault`.
+    #[error("the server returned an error: {0}")]
+    ResponseError(Box<response::ResponseError>),
+
+    #[error("expected {expected} response messages, got {actual}")]
+    UnexpectedResponseMessageCount { expected: usize, actual: usize },
+
+    #[error("response is missing the expected {0}")]
+    MissingResponseContent(&'static str),
+
+    #[error("cannot compare items of different kinds")]
+    MismatchedItemKinds,
+
     #[error("unknown server version: {0}")]
     UnknownServerVersion(String),
+
+    #[error("invalid base64 content")]
+    InvalidBase64(#[from] content::Base64Error),
+
+    #[error("decoded content is not valid UTF-8")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+
+    #[error("decoded content is not valid US-ASCII: byte at {position} is out of range")]
+    InvalidAscii { position: usize },
+
+    #[error("unsupported character set: {0}")]
+    UnsupportedCharacterSet(String),
+
+    #[error("expected extended property of type {expected:?}, got {actual:?}")]
+    UnexpectedPropertyType {
+        expected: PropertyType,
+        actual: PropertyType,
+    },
+
+    #[error("invalid value for extended property of type {property_type:?}: {value}")]
+    InvalidPropertyValue {
+        property_type: PropertyType,
+        value: String,
+    },
+
+    #[error("wrong number of values for extended property of type {0:?}")]
+    UnexpectedPropertyValueCount(PropertyType),
+
+    #[error("invalid category list: {0}")]
+    InvalidCategoryList(&'static str),
+
+    #[error("invalid push notification request: {0}")]
+    InvalidPushRequest(&'static str),
+
+    #[error("push notification connection failed")]
+    PushConnection(#[source] std::io::Error),
+
+    #[error("failed to write streamed content")]
+    Io(#[from] std::io::Error),
 }
@@ -0,0 +1,456 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A minimal HTTP handler for receiving push notifications.
+//!
+//! Once a push subscription has been created with a
+//! [`PushSubscriptionRequest`], Exchange sends each notification as an HTTP
+//! `POST` request to the subscription's URL. [`handle_http_request`] reads one
+//! such request from a connection, passes its notifications to the caller and
+//! writes the reply which tells Exchange whether to keep the subscription.
+//!
+//! The handler only supports what Exchange needs: requests with a
+//! `Content-Length` and one request per connection. TLS, if required, is left
+//! to the caller or to a reverse proxy.
+//!
+//! [`PushSubscriptionRequest`]: crate::subscribe::PushSubscriptionRequest
+
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    send_notification::{SendNotification, SendNotificationResult, SubscriptionStatus},
+    soap::Envelope,
+    Error, Notification, OperationResponse, ResponseClass,
+};
+
+/// The maximum size of the body of a request, in bytes.
+pub const MAX_REQUEST_BODY_LEN: usize = 16 * 1024 * 1024;
+
+/// The maximum size of the request line and headers of a request, in bytes.
+const MAX_REQUEST_HEAD_LEN: u64 = 64 * 1024;
+
+/// The time allowed for a client to send a complete request.
+pub const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Handles a request as with [`handle_http_request`], after setting the read
+/// and write timeouts of `stream` to [`REQUEST_TIMEOUT`].
+pub fn handle_tcp_connection<F>(
+    stream: &mut TcpStream,
+    on_notification: F,
+) -> Result<SubscriptionStatus, Error>
+where
+    F: FnMut(Notification) -> SubscriptionStatus,
+{
+    stream
+        .set_read_timeout(Some(REQUEST_TIMEOUT))
+        .map_err(Error::PushConnection)?;
+    stream
+        .set_write_timeout(Some(REQUEST_TIMEOUT))
+        .map_err(Error::PushConnection)?;
+
+    handle_http_request(stream, on_notification)
+}
+
+/// Reads a `SendNotification` request from `stream` and writes the reply.
+///
+/// `on_notification` is called with each notification in the request, and
+/// decides whether the subscription should be kept. If it returns
+/// [`SubscriptionStatus::Unsubscribe`] for any of them, Exchange is asked to
+/// end the subscription. The status sent to Exchange is returned.
+///
+/// If the request is invalid, an HTTP error is written instead and an error is
+/// returned. In all cases, the connection should be closed afterwards.
+///
+/// Reading the request fails once [`REQUEST_TIMEOUT`] has passed. This is
+/// only checked between reads, so `stream` should also have a read timeout of
+/// its own; [`handle_tcp_connection`] takes care of this for TCP connections.
+pub fn handle_http_request<S, F>(
+    stream: &mut S,
+    mut on_notification: F,
+) -> Result<SubscriptionStatus, Error>
+where
+    S: Read + Write,
+    F: FnMut(Notification) -> SubscriptionStatus,
+{
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+    let body = match read_request_body(stream, deadline) {
+        Ok(body) => body,
+        Err(Rejection::Io(err)) => return Err(Error::PushConnection(err)),
+        Err(Rejection::Status(status, reason)) => {
+            write_response(
+                stream,
+                status,
+                "text/plain; charset=utf-8",
+                reason.as_bytes(),
+            )
+            .map_err(Error::PushConnection)?;
+
+            return Err(Error::InvalidPushRequest(reason));
+        }
+    };
+
+    let envelope = match Envelope::<SendNotification>::from_xml_document(&body) {
+        Ok(envelope) => envelope,
+        Err(err) => {
+            write_response(
+                stream,
+                "400 Bad Request",
+                "text/plain; charset=utf-8",
+                b"invalid SendNotification request",
+            )
+            .map_err(Error::PushConnection)?;
+
+            return Err(err);
+        }
+    };
+
+    let mut status = SubscriptionStatus::Ok;
+    for message in envelope.body.into_response_messages() {
+        let notification = match message {
+            ResponseClass::Success(message) | ResponseClass::Warning(message) => {
+                message.notification
+            }
+            ResponseClass::Error(err) => {
+                log::warn!("ignoring push notification error: {err}");
+                continue;
+            }
+        };
+
+        if on_notification(notification) == SubscriptionStatus::Unsubscribe {
+            status = SubscriptionStatus::Unsubscribe;
+        }
+    }
+
+    let reply = Envelope {
+        headers: vec![],
+        body: SendNotificationResult {
+            subscription_status: status,
+        },
+    }
+    .as_xml_document()?;
+    write_response(stream, "200 OK", "text/xml; charset=utf-8", &reply)
+        .map_err(Error::PushConnection)?;
+
+    Ok(status)
+}
+
+/// The reason a request could not be read.
+enum Rejection {
+    /// The request is invalid, and should be answered with the given HTTP
+    /// status and reason.
+    Status(&'static str, &'static str),
+
+    /// The connection failed.
+    Io(io::Error),
+}
+
+impl From<io::Error> for Rejection {
+    fn from(value: io::Error) -> Self {
+        match value.kind() {
+            // Read timeouts are reported as `WouldBlock` on some platforms.
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => Self::Status(
+                "408 Request Timeout",
+                "the request was not received in time",
+            ),
+            _ => Self::Io(value),
+        }
+    }
+}
+
+/// A stream whose reads fail once a deadline has passed.
+struct WithDeadline<'a, S> {
+    stream: &'a mut S,
+    deadline: Instant,
+}
+
+impl<S> Read for WithDeadline<'_, S>
+where
+    S: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if Instant::now() >= self.deadline {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+
+        self.stream.read(buf)
+    }
+}
+
+impl<S> Write for WithDeadline<'_, S>
+where
+    S: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+/// Reads the head of an HTTP request and returns its body, failing if the
+/// request isn't complete by `deadline`.
+fn read_request_body<S>(stream: &mut S, deadline: Instant) -> Result<Vec<u8>, Rejection>
+where
+    S: Read + Write,
+{
+    let mut reader = BufReader::new(WithDeadline { stream, deadline });
+
+    let mut content_length = None;
+    let mut expect_continue = false;
+    {
+        let mut head = (&mut reader).take(MAX_REQUEST_HEAD_LEN);
+        let mut line = String::new();
+
+        head.read_line(&mut line)?;
+        let mut request_line = line.split_ascii_whitespace();
+        match (
+            request_line.next(),
+            request_line.next(),
+            request_line.next(),
+        ) {
+            (Some("POST"), Some(_), Some(version)) if version.starts_with("HTTP/1.") => (),
+            (Some(_), Some(_), Some(_)) => {
+                return Err(Rejection::Status(
+                    "405 Method Not Allowed",
+                    "only POST requests are supported",
+                ))
+            }
+            _ => return Err(Rejection::Status("400 Bad Request", "invalid request line")),
+        }
+
+        loop {
+            line.clear();
+            if head.read_line(&mut line)? == 0 {
+                return Err(Rejection::Status(
+                    "400 Bad Request",
+                    "incomplete request headers",
+                ));
+            }
+
+            let header = line.trim_end_matches(['\r', '\n']);
+            if header.is_empty() {
+                break;
+            }
+
+            let Some((name, value)) = header.split_once(':') else {
+                return Err(Rejection::Status("400 Bad Request", "invalid header"));
+            };
+            let value = value.trim();
+
+            if name.eq_ignore_ascii_case("Content-Length") {
+                let length = value.parse().map_err(|_| {
+                    Rejection::Status("400 Bad Request", "invalid Content-Length header")
+                })?;
+                if content_length.is_some_and(|previous| previous != length) {
+                    return Err(Rejection::Status(
+                        "400 Bad Request",
+                        "conflicting Content-Length headers",
+                    ));
+                }
+                content_length = Some(length);
+            } else if name.eq_ignore_ascii_case("Transfer-Encoding") {
+                return Err(Rejection::Status(
+                    "411 Length Required",
+                    "a Content-Length header is required",
+                ));
+            } else if name.eq_ignore_ascii_case("Expect") {
+                expect_continue = value.eq_ignore_ascii_case("100-continue");
+            }
+        }
+    }
+
+    let content_length = content_length.ok_or(Rejection::Status(
+        "411 Length Required",
+        "a Content-Length header is required",
+    ))?;
+    if content_length > MAX_REQUEST_BODY_LEN {
+        return Err(Rejection::Status(
+            "413 Content Too Large",
+            "the request body is too large",
+        ));
+    }
+
+    // Exchange may wait for the server to accept the request before sending
+    // its body.
+    if expect_continue {
+        let stream = reader.get_mut();
+        stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+        stream.flush()?;
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+
+    Ok(body)
+}
+
+fn write_response<W>(
+    stream: &mut W,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::{Shutdown, TcpListener, TcpStream},
+        thread,
+    };
+
+    use super::*;
+    use crate::NotificationEvent;
+
+    const SEND_NOTIFICATION: &str = r#"<?xml version="1.0" encoding="utf-8"?><soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/"><soap:Header><t:RequestServerVersion xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types" Version="Exchange2010_SP2"/></soap:Header><soap:Body><m:SendNotification xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:SendNotificationResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Notification><t:SubscriptionId>FQBkYjVwcjA2bWIxNDY0</t:SubscriptionId><t:PreviousWatermark>AQAAAMAGAAAAAAAAAQ==</t:PreviousWatermark><t:MoreEvents>false</t:MoreEvents><t:NewMailEvent><t:Watermark>AQAAAM4GAAAAAAAAAQ==</t:Watermark><t:TimeStamp>2024-05-02T09:12:44Z</t:TimeStamp><t:ItemId Id="AAMkADk=" ChangeKey="CQAAAA=="/><t:ParentFolderId Id="AQMkAGI=" ChangeKey="AQAAAA=="/></t:NewMailEvent></m:Notification></m:SendNotificationResponseMessage></m:ResponseMessages></m:SendNotification></soap:Body></soap:Envelope>"#;
+
+    /// Serves a single connection on a loopback port with `handle_http_request`,
+    /// sends `request` to it and returns the raw response and the result of
+    /// the handler.
+    fn exchange(
+        request: Vec<u8>,
+        status: SubscriptionStatus,
+    ) -> (String, Result<SubscriptionStatus, Error>, Vec<Notification>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut notifications = Vec::new();
+            let result = handle_http_request(&mut stream, |notification| {
+                notifications.push(notification);
+                status
+            });
+
+            (result, notifications)
+        });
+
+        let mut client = TcpStream::connect(address).unwrap();
+        client.write_all(&request).unwrap();
+        client.shutdown(Shutdown::Write).unwrap();
+
+        let mut response = String::new();
+        client.read_to_string(&mut response).unwrap();
+
+        let (result, notifications) = server.join().unwrap();
+        (response, result, notifications)
+    }
+
+    fn post(body: &str, extra_headers: &str) -> Vec<u8> {
+        format!(
+            "POST /ews/push HTTP/1.1\r\nHost: client.example.com\r\nContent-Type: text/xml; charset=utf-8\r\n{extra_headers}Content-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .into_bytes()
+    }
+
+    #[test]
+    fn receive_notification() {
+        let (response, result, notifications) =
+            exchange(post(SEND_NOTIFICATION, ""), SubscriptionStatus::Ok);
+
+        assert_eq!(result.unwrap(), SubscriptionStatus::Ok);
+        assert!(
+            response.starts_with("HTTP/1.1 200 OK\r\n"),
+            "got {response}"
+        );
+        assert!(response.ends_with("<SendNotificationResult xmlns=\"http://schemas.microsoft.com/exchange/services/2006/messages\"><SubscriptionStatus>OK</SubscriptionStatus></SendNotificationResult></soap:Body></soap:Envelope>"), "got {response}");
+
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0].subscription_id, "FQBkYjVwcjA2bWIxNDY0");
+        assert!(matches!(
+            notifications[0].events.as_slice(),
+            [NotificationEvent::NewMailEvent(_)]
+        ));
+    }
+
+    #[test]
+    fn unsubscribe_with_expect_continue() {
+        let (response, result, _) = exchange(
+            post(SEND_NOTIFICATION, "Expect: 100-continue\r\n"),
+            SubscriptionStatus::Unsubscribe,
+        );
+
+        assert_eq!(result.unwrap(), SubscriptionStatus::Unsubscribe);
+        assert!(
+            response.starts_with("HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 200 OK\r\n"),
+            "got {response}"
+        );
+        assert!(
+            response.contains("<SubscriptionStatus>Unsubscribe</SubscriptionStatus>"),
+            "got {response}"
+        );
+    }
+
+    #[test]
+    fn reject_invalid_requests() {
+        let (response, result, _) = exchange(
+            b"GET /ews/push HTTP/1.1\r\nHost: client.example.com\r\n\r\n".to_vec(),
+            SubscriptionStatus::Ok,
+        );
+        assert!(
+            response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"),
+            "got {response}"
+        );
+        assert!(matches!(result, Err(Error::InvalidPushRequest(_))));
+
+        let (response, result, _) = exchange(
+            b"POST /ews/push HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n".to_vec(),
+            SubscriptionStatus::Ok,
+        );
+        assert!(
+            response.starts_with("HTTP/1.1 411 Length Required\r\n"),
+            "got {response}"
+        );
+        assert!(matches!(result, Err(Error::InvalidPushRequest(_))));
+
+        let (response, result, notifications) =
+            exchange(post("<soap:Envelope>", ""), SubscriptionStatus::Ok);
+        assert!(
+            response.starts_with("HTTP/1.1 400 Bad Request\r\n"),
+            "got {response}"
+        );
+        assert!(result.is_err());
+        assert!(notifications.is_empty());
+
+        let (response, result, notifications) = exchange(
+            post(SEND_NOTIFICATION, "Content-Length: 1\r\n"),
+            SubscriptionStatus::Ok,
+        );
+        assert!(
+            response.starts_with("HTTP/1.1 400 Bad Request\r\n"),
+            "got {response}"
+        );
+        assert!(matches!(result, Err(Error::InvalidPushRequest(_))));
+        assert!(notifications.is_empty());
+    }
+
+    #[test]
+    fn reject_requests_after_deadline() {
+        let mut stream = io::Cursor::new(post(SEND_NOTIFICATION, ""));
+
+        let result = read_request_body(&mut stream, Instant::now());
+        assert!(matches!(
+            result,
+            Err(Rejection::Status("408 Request Timeout", _))
+        ));
+    }
+}
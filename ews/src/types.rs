@@ -9,22 +9,49 @@ pub use common::*;
 pub use operations::*;
 pub mod soap;
 
+pub mod add_delegate;
+pub mod apply_conversation_action;
 pub mod copy_folder;
 pub mod copy_item;
+pub mod create_attachment;
 pub mod create_folder;
 pub mod create_item;
+pub mod delete_attachment;
 pub mod delete_folder;
 pub mod delete_item;
 pub mod empty_folder;
+pub mod expand_dl;
+pub mod find_conversation;
 pub mod find_item;
+pub mod find_people;
+pub mod get_attachment;
+pub mod get_conversation_items;
+pub mod get_delegate;
+pub mod get_events;
 pub mod get_folder;
+pub mod get_inbox_rules;
 pub mod get_item;
+pub mod get_persona;
+pub mod get_streaming_events;
+pub mod get_user_configuration;
+pub mod get_user_oof_settings;
+pub mod item_diff;
 pub mod mark_all_read;
 pub mod mark_as_junk;
 pub mod move_folder;
 pub mod move_item;
+pub mod remove_delegate;
+pub mod resolve_names;
+pub mod send_item;
+pub mod send_notification;
 pub mod server_version;
+pub mod set_user_oof_settings;
+pub mod subscribe;
 pub mod sync_folder_hierarchy;
 pub mod sync_folder_items;
+pub mod unsubscribe;
+pub mod update_delegate;
 pub mod update_folder;
+pub mod update_inbox_rules;
 pub mod update_item;
+pub mod update_user_configuration;
@@ -0,0 +1,105 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    DelegateResponseMessage, DelegateUsers, DeliverMeetingRequests, Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to add delegates to a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/adddelegate-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(DelegateResponseMessage, single_message)]
+pub struct AddDelegate {
+    /// The mailbox to add the delegates to. Only the address is required.
+    pub mailbox: Mailbox,
+
+    pub delegate_users: DelegateUsers,
+
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DelegateFolderPermissionLevel, DelegatePermissions, DelegateUser,
+        DelegateUserResponseMessage, ResponseClass, ResponseMessages, UserId,
+    };
+
+    #[test]
+    fn serialize_add_delegate() {
+        let add_delegate = AddDelegate {
+            mailbox: Mailbox {
+                email_address: Some("boss@example.com".to_string()),
+                ..Default::default()
+            },
+            delegate_users: DelegateUsers(vec![DelegateUser {
+                view_private_items: Some(false),
+                ..DelegateUser::new(
+                    UserId::from_smtp_address("assistant@example.com"),
+                    DelegatePermissions {
+                        calendar_folder_permission_level: Some(
+                            DelegateFolderPermissionLevel::Editor,
+                        ),
+                        ..Default::default()
+                    },
+                )
+            }]),
+            deliver_meeting_requests: Some(DeliverMeetingRequests::DelegatesAndMe),
+        };
+
+        let expected = r#"<AddDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></Mailbox><DelegateUsers><t:DelegateUser><t:UserId><t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress></t:UserId><t:DelegatePermissions><t:CalendarFolderPermissionLevel>Editor</t:CalendarFolderPermissionLevel></t:DelegatePermissions><t:ViewPrivateItems>false</t:ViewPrivateItems></t:DelegateUser></DelegateUsers><DeliverMeetingRequests>DelegatesAndMe</DeliverMeetingRequests></AddDelegate>"#;
+
+        assert_serialized_content(&add_delegate, "AddDelegate", expected);
+    }
+
+    #[test]
+    fn deserialize_add_delegate_response() {
+        let content = r#"<m:AddDelegateResponse ResponseClass="Success"
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseCode>NoError</m:ResponseCode>
+                <m:ResponseMessages>
+                    <m:DelegateUserResponseMessageType ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:DelegateUser>
+                            <t:UserId>
+                                <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                                <t:DisplayName>Assistant</t:DisplayName>
+                            </t:UserId>
+                            <t:ReceiveCopiesOfMeetingMessages>false</t:ReceiveCopiesOfMeetingMessages>
+                            <t:ViewPrivateItems>false</t:ViewPrivateItems>
+                        </m:DelegateUser>
+                    </m:DelegateUserResponseMessageType>
+                </m:ResponseMessages>
+            </m:AddDelegateResponse>"#;
+
+        let expected = AddDelegateResponse {
+            response_message: ResponseClass::Success(DelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::Success(DelegateUserResponseMessage {
+                        delegate_user: Some(DelegateUser {
+                            user_id: UserId {
+                                primary_smtp_address: Some("assistant@example.com".to_string()),
+                                display_name: Some("Assistant".to_string()),
+                                ..Default::default()
+                            },
+                            delegate_permissions: None,
+                            receive_copies_of_meeting_messages: Some(false),
+                            view_private_items: Some(false),
+                        }),
+                    })],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,240 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ArrayOfStrings, BaseFolderId, DateTime, DeleteType, Flag, ItemId, MESSAGES_NS_URI};
+
+/// A request to apply actions to the items of one or more conversations.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/applyconversationaction>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ApplyConversationActionResponseMessage)]
+pub struct ApplyConversationAction {
+    pub conversation_actions: ConversationActions,
+}
+
+/// The actions to apply, each to a single conversation.
+#[derive(Clone, Debug, Default)]
+pub struct ConversationActions(pub Vec<ConversationAction>);
+
+impl XmlSerialize for ConversationActions {
+    /// Serializes each action as a `<t:ConversationAction>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for action in &self.0 {
+            action.serialize_as_element(writer, "t:ConversationAction")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An action to apply to the items of a conversation.
+///
+/// Which of the optional fields are required depends on `action`; see
+/// [`ConversationActionType`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversationaction>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct ConversationAction {
+    #[xml_struct(ns_prefix = "t")]
+    pub action: ConversationActionType,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub conversation_id: ItemId,
+
+    /// The folder the action is limited to. If omitted, the action applies to
+    /// the items of the conversation in all folders.
+    #[xml_struct(ns_prefix = "t")]
+    pub context_folder_id: Option<BaseFolderId>,
+
+    /// Limits one-time actions to the items received before this time, e.g.
+    /// to avoid acting on messages the user hasn't seen yet.
+    #[xml_struct(ns_prefix = "t")]
+    pub conversation_last_sync_time: Option<DateTime>,
+
+    /// Whether an always-on action should also be applied to the existing
+    /// items of the conversation before returning.
+    #[xml_struct(ns_prefix = "t")]
+    pub process_right_away: Option<bool>,
+
+    /// The folder to move or copy items to.
+    #[xml_struct(ns_prefix = "t")]
+    pub destination_folder_id: Option<BaseFolderId>,
+
+    /// The categories to apply to the items of the conversation.
+    #[xml_struct(ns_prefix = "t")]
+    pub categories: Option<ArrayOfStrings>,
+
+    /// Whether items of the conversation should always be deleted.
+    #[xml_struct(ns_prefix = "t")]
+    pub enable_always_delete: Option<bool>,
+
+    /// The read state to set on the items of the conversation.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_read: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub delete_type: Option<DeleteType>,
+
+    /// The follow-up flag to set on the items of the conversation.
+    ///
+    /// This was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub flag: Option<Flag>,
+}
+
+impl ConversationAction {
+    /// Creates an action of the given type on a conversation, with no
+    /// optional fields set.
+    pub fn new(action: ConversationActionType, conversation_id: ItemId) -> Self {
+        Self {
+            action,
+            conversation_id,
+            context_folder_id: None,
+            conversation_last_sync_time: None,
+            process_right_away: None,
+            destination_folder_id: None,
+            categories: None,
+            enable_always_delete: None,
+            is_read: None,
+            delete_type: None,
+            flag: None,
+        }
+    }
+}
+
+/// The kind of action to apply to a conversation.
+///
+/// The "always" actions apply to the existing items of the conversation as
+/// well as to those received later, until they are disabled.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/action>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum ConversationActionType {
+    /// Always apply `categories`; an empty list disables the action.
+    AlwaysCategorize,
+
+    /// Always delete items, if `enable_always_delete` is `true`.
+    AlwaysDelete,
+
+    /// Always move items to `destination_folder_id`; omitting the folder
+    /// disables the action.
+    AlwaysMove,
+
+    /// Delete items, as specified by `delete_type`.
+    Delete,
+
+    /// Move items to `destination_folder_id`.
+    Move,
+
+    /// Copy items to `destination_folder_id`.
+    Copy,
+
+    /// Set the read state of items to `is_read`.
+    SetReadState,
+
+    /// Set the follow-up flag of items to `flag`.
+    Flag,
+}
+
+/// A response to an [`ApplyConversationAction`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/applyconversationactionresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ApplyConversationActionResponseMessage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DistinguishedFolderName, FlagStatus, ResponseClass, ResponseMessages,
+    };
+
+    fn conversation_id() -> ItemId {
+        ItemId {
+            id: "AAQkADk=".to_string(),
+            change_key: Some("CQAAAA==".to_string()),
+        }
+    }
+
+    #[test]
+    fn serialize_apply_conversation_action() {
+        let always_categorize = ConversationAction {
+            process_right_away: Some(true),
+            categories: Some(ArrayOfStrings {
+                inner: vec!["Blue category".to_string()],
+            }),
+            ..ConversationAction::new(ConversationActionType::AlwaysCategorize, conversation_id())
+        };
+
+        let move_action = ConversationAction {
+            context_folder_id: Some(BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Inbox,
+                change_key: None,
+                mailbox: None,
+            }),
+            destination_folder_id: Some(BaseFolderId::FolderId {
+                id: "AAMkAGI=".to_string(),
+                change_key: None,
+            }),
+            ..ConversationAction::new(ConversationActionType::Move, conversation_id())
+        };
+
+        let flag = ConversationAction {
+            flag: Some(Flag {
+                flag_status: FlagStatus::Flagged,
+                start_date: None,
+                due_date: None,
+                complete_date: None,
+            }),
+            ..ConversationAction::new(ConversationActionType::Flag, conversation_id())
+        };
+
+        let apply_conversation_action = ApplyConversationAction {
+            conversation_actions: ConversationActions(vec![always_categorize, move_action, flag]),
+        };
+
+        let expected = r#"<ApplyConversationAction xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><ConversationActions><t:ConversationAction><t:Action>AlwaysCategorize</t:Action><t:ConversationId Id="AAQkADk=" ChangeKey="CQAAAA=="/><t:ProcessRightAway>true</t:ProcessRightAway><t:Categories><t:String>Blue category</t:String></t:Categories></t:ConversationAction><t:ConversationAction><t:Action>Move</t:Action><t:ConversationId Id="AAQkADk=" ChangeKey="CQAAAA=="/><t:ContextFolderId><t:DistinguishedFolderId Id="inbox"/></t:ContextFolderId><t:DestinationFolderId><t:FolderId Id="AAMkAGI="/></t:DestinationFolderId></t:ConversationAction><t:ConversationAction><t:Action>Flag</t:Action><t:ConversationId Id="AAQkADk=" ChangeKey="CQAAAA=="/><t:Flag><t:FlagStatus>Flagged</t:FlagStatus></t:Flag></t:ConversationAction></ConversationActions></ApplyConversationAction>"#;
+
+        assert_serialized_content(
+            &apply_conversation_action,
+            "ApplyConversationAction",
+            expected,
+        );
+    }
+
+    #[test]
+    fn deserialize_apply_conversation_action_response() {
+        let content = r#"<ApplyConversationActionResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:ResponseMessages>
+                    <m:ApplyConversationActionResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                    </m:ApplyConversationActionResponseMessage>
+                </m:ResponseMessages>
+            </ApplyConversationActionResponse>"#;
+
+        let expected = ApplyConversationActionResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    ApplyConversationActionResponseMessage {},
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -4,14 +4,86 @@
 
 use std::ops::{Deref, DerefMut};
 
+use quick_xml::events::{BytesEnd, BytesStart, Event};
 use serde::{Deserialize, Deserializer};
 use time::format_description::well_known::Iso8601;
 use xml_struct::XmlSerialize;
 
+use crate::{
+    content::{decode_base64, decode_text, encode_base64},
+    Error,
+};
+
 pub mod response;
 pub use self::response::{ResponseClass, ResponseMessages};
 pub mod message_xml;
 pub use self::message_xml::MessageXml;
+pub mod calendar_item;
+pub use self::calendar_item::{
+    ArrayOfAttendees, Attendee, CalendarItem, CalendarItemType, LegacyFreeBusyStatus,
+    MeetingResponseType,
+};
+pub mod category_list;
+pub use self::category_list::{Category, CategoryColor, CategoryList};
+pub mod contact;
+pub use self::contact::{
+    CompleteName, Contact, ContactSource, Dictionary, EmailAddressEntry, EmailAddressKey,
+    ImAddressEntry, ImAddressKey, PhoneNumberEntry, PhoneNumberKey, PhysicalAddressEntry,
+    PhysicalAddressKey,
+};
+pub mod delegate;
+pub use self::delegate::{
+    DelegateFolderPermissionLevel, DelegatePermissions, DelegateResponseMessage, DelegateUser,
+    DelegateUserResponseMessage, DelegateUsers, DeliverMeetingRequests, UserIds,
+};
+pub mod distribution_list;
+pub use self::distribution_list::{DistributionList, Member, MemberStatus, Members};
+pub mod field_uri;
+pub use self::field_uri::{DictionaryURI, UnindexedFieldURI};
+pub mod inbox_rule;
+pub use self::inbox_rule::{
+    ArrayOfEmailAddresses, FlaggedForAction, Rule, RuleActions, RuleDateRange, RuleFieldURI,
+    RuleOperation, RuleOperationError, RuleOperationErrors, RulePredicates, RuleSizeRange,
+    RuleValidationError, RuleValidationErrorCode, RuleValidationErrors, TargetFolderId,
+};
+pub mod notification;
+pub use self::notification::{
+    EventType, EventTypes, ModifiedEvent, MovedOrCopiedEvent, Notification, NotificationEvent,
+    ObjectChangedEvent, StatusEvent, SubscriptionIds,
+};
+pub mod oof_settings;
+pub use self::oof_settings::{Duration, ExternalAudience, OofState, ReplyBody, UserOofSettings};
+pub mod permission;
+pub use self::permission::{
+    CalendarPermission, CalendarPermissionLevel, CalendarPermissionReadAccess, CalendarPermissions,
+    Permission, PermissionAction, PermissionLevel, PermissionReadAccess, PermissionSet,
+    Permissions,
+};
+pub mod persona;
+pub use self::persona::{
+    AttributedValue, AttributedValues, Attribution, AttributionIds, Attributions, Persona,
+    PersonaId, PersonaPhoneNumber, PersonaShape,
+};
+pub mod property_value;
+pub use self::property_value::{ExtendedPropertyValue, ExtendedPropertyValues, PropertyValue};
+pub mod response_objects;
+pub use self::response_objects::{
+    NewBodyContent, PostReplyItem, ResponseObject, SmartResponse, SuppressReadReceipt,
+};
+pub mod restriction;
+pub use self::restriction::{
+    Constant, ContainmentComparison, ContainmentMode, FieldURIOrConstant, Not, Restriction,
+    SearchExpression,
+};
+mod string_enum;
+pub mod user_configuration;
+pub use self::user_configuration::{
+    UserConfiguration, UserConfigurationData, UserConfigurationName, UserConfigurationProperties,
+};
+pub mod well_known_properties;
+pub use self::well_known_properties::{
+    NamedProperty, NamedPropertyKey, PropertySet, TaggedProperty,
+};
 
 pub(crate) const MESSAGES_NS_URI: &str =
     "http://schemas.microsoft.com/exchange/services/2006/messages";
@@ -25,6 +97,13 @@ pub(crate) const TYPES_NS_URI: &str = "http://schemas.microsoft.com/exchange/ser
 pub struct FolderShape {
     #[xml_struct(ns_prefix = "t")]
     pub base_shape: BaseShape,
+
+    /// A list of properties which should be included in addition to those
+    /// implied by the base shape, e.g. [`UnindexedFieldURI::FolderPermissionSet`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/additionalproperties>
+    #[xml_struct(ns_prefix = "t")]
+    pub additional_properties: Option<Vec<PathToElement>>,
 }
 
 /// The item properties which should be included in the response.
@@ -108,10 +187,8 @@ pub enum PathToElement {
         // TODO: Adjust xml_struct to support field renaming to avoid non-snake
         // case identifiers.
         // https://github.com/thunderbird/xml-struct-rs/issues/6
-        // TODO: We could use an enum for this field. It's just large and not
-        // worth typing out by hand.
         #[xml_struct(attribute)]
-        field_URI: String,
+        field_URI: UnindexedFieldURI,
     },
 
     /// An identifier for a specific element of a dictionary-based property.
@@ -121,7 +198,7 @@ pub enum PathToElement {
     IndexedFieldURI {
         /// The well-known string identifier of the property.
         #[xml_struct(attribute)]
-        field_URI: String,
+        field_URI: DictionaryURI,
 
         /// The member within the dictionary to access.
         #[xml_struct(attribute)]
@@ -139,31 +216,50 @@ pub enum PathToElement {
 #[derive(Clone, Debug, Deserialize, XmlSerialize, Eq, PartialEq)]
 pub struct ExtendedFieldURI {
     /// A well-known identifier for a property set.
+    #[serde(rename = "@DistinguishedPropertySetId")]
     #[xml_struct(attribute)]
     pub distinguished_property_set_id: Option<DistinguishedPropertySet>,
 
     /// A GUID representing a property set.
     // TODO: This could use a strong type for representing a GUID.
+    #[serde(rename = "@PropertySetId")]
     #[xml_struct(attribute)]
     pub property_set_id: Option<String>,
 
     /// Specifies a property by integer tag.
+    #[serde(rename = "@PropertyTag")]
     #[xml_struct(attribute)]
     pub property_tag: Option<String>,
 
     /// The name of a property within a specified property set.
+    #[serde(rename = "@PropertyName")]
     #[xml_struct(attribute)]
     pub property_name: Option<String>,
 
     /// The dispatch ID of a property within a specified property set.
+    #[serde(rename = "@PropertyId")]
     #[xml_struct(attribute)]
     pub property_id: Option<String>,
 
     /// The value type of the desired property.
+    #[serde(rename = "@PropertyType")]
     #[xml_struct(attribute)]
     pub property_type: PropertyType,
 }
 
+impl From<ExtendedFieldURI> for PathToElement {
+    fn from(value: ExtendedFieldURI) -> Self {
+        Self::ExtendedFieldURI {
+            distinguished_property_set_id: value.distinguished_property_set_id,
+            property_set_id: value.property_set_id,
+            property_tag: value.property_tag,
+            property_name: value.property_name,
+            property_id: value.property_id,
+            property_type: value.property_type,
+        }
+    }
+}
+
 /// A well-known MAPI property set identifier.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/extendedfielduri#distinguishedpropertysetid-attribute>
@@ -292,17 +388,16 @@ pub struct ItemResponseMessage {
 }
 
 /// An identifier for an Exchange folder.
-#[derive(Clone, Debug, XmlSerialize)]
-#[xml_struct(variant_ns_prefix = "t")]
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 pub enum BaseFolderId {
     /// An identifier for an arbitrary folder.
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderid>.
     FolderId {
-        #[xml_struct(attribute)]
+        #[serde(rename = "@Id")]
         id: String,
 
-        #[xml_struct(attribute)]
+        #[serde(rename = "@ChangeKey")]
         change_key: Option<String>,
     },
 
@@ -311,14 +406,154 @@ pub enum BaseFolderId {
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguishedfolderid>.
     DistinguishedFolderId {
-        #[xml_struct(attribute)]
-        id: String,
+        #[serde(rename = "@Id")]
+        id: DistinguishedFolderName,
 
-        #[xml_struct(attribute)]
+        #[serde(rename = "@ChangeKey")]
         change_key: Option<String>,
+
+        /// The mailbox the folder belongs to, if not the authenticated
+        /// user's, e.g. a shared or delegated mailbox. Only the address is
+        /// required.
+        #[serde(rename = "Mailbox")]
+        mailbox: Option<Mailbox>,
     },
 }
 
+impl BaseFolderId {
+    /// Creates an identifier for a folder of the authenticated user's
+    /// mailbox by name.
+    pub fn distinguished(id: DistinguishedFolderName) -> Self {
+        Self::DistinguishedFolderId {
+            id,
+            change_key: None,
+            mailbox: None,
+        }
+    }
+
+    /// Creates an identifier for a folder of another user's mailbox by name.
+    pub fn distinguished_in_mailbox(
+        id: DistinguishedFolderName,
+        address: impl Into<String>,
+    ) -> Self {
+        Self::DistinguishedFolderId {
+            id,
+            change_key: None,
+            mailbox: Some(Mailbox {
+                email_address: Some(address.into()),
+                ..Default::default()
+            }),
+        }
+    }
+}
+
+impl XmlSerialize for BaseFolderId {
+    /// Serializes the identifier as a `<t:FolderId>` or
+    /// `<t:DistinguishedFolderId>` element.
+    ///
+    /// Implemented manually so that an identifier without a mailbox is
+    /// written as an empty element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        let (name, id, change_key, mailbox) = match self {
+            Self::FolderId { id, change_key } => ("t:FolderId", id.as_str(), change_key, None),
+            Self::DistinguishedFolderId {
+                id,
+                change_key,
+                mailbox,
+            } => (
+                "t:DistinguishedFolderId",
+                id.as_str(),
+                change_key,
+                mailbox.as_ref(),
+            ),
+        };
+
+        let mut start = BytesStart::new(name);
+        start.push_attribute(("Id", id));
+        if let Some(change_key) = change_key {
+            start.push_attribute(("ChangeKey", change_key.as_str()));
+        }
+
+        if let Some(mailbox) = mailbox {
+            writer.write_event(Event::Start(start))?;
+            mailbox.serialize_as_element(writer, "t:Mailbox")?;
+            writer.write_event(Event::End(BytesEnd::new(name)))?;
+        } else {
+            writer.write_event(Event::Empty(start))?;
+        }
+
+        Ok(())
+    }
+}
+
+string_enum::string_enum! {
+    /// The name of a well-known folder, for use in
+    /// [`BaseFolderId::DistinguishedFolderId`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguishedfolderid>
+    pub enum DistinguishedFolderName {
+        Calendar => "calendar",
+        Contacts => "contacts",
+        DeletedItems => "deleteditems",
+        Drafts => "drafts",
+        Inbox => "inbox",
+        Journal => "journal",
+        Notes => "notes",
+        Outbox => "outbox",
+        SentItems => "sentitems",
+        Tasks => "tasks",
+        MsgFolderRoot => "msgfolderroot",
+        PublicFoldersRoot => "publicfoldersroot",
+        Root => "root",
+        JunkEmail => "junkemail",
+        SearchFolders => "searchfolders",
+        VoiceMail => "voicemail",
+        RecoverableItemsRoot => "recoverableitemsroot",
+        RecoverableItemsDeletions => "recoverableitemsdeletions",
+        RecoverableItemsVersions => "recoverableitemsversions",
+        RecoverableItemsPurges => "recoverableitemspurges",
+        ArchiveRoot => "archiveroot",
+        ArchiveMsgFolderRoot => "archivemsgfolderroot",
+        ArchiveDeletedItems => "archivedeleteditems",
+        ArchiveInbox => "archiveinbox",
+        ArchiveRecoverableItemsRoot => "archiverecoverableitemsroot",
+        ArchiveRecoverableItemsDeletions => "archiverecoverableitemsdeletions",
+        ArchiveRecoverableItemsVersions => "archiverecoverableitemsversions",
+        ArchiveRecoverableItemsPurges => "archiverecoverableitemspurges",
+        SyncIssues => "syncissues",
+        Conflicts => "conflicts",
+        LocalFailures => "localfailures",
+        ServerFailures => "serverfailures",
+        RecipientCache => "recipientcache",
+        QuickContacts => "quickcontacts",
+        ConversationHistory => "conversationhistory",
+        AdminAuditLogs => "adminauditlogs",
+        ToDoSearch => "todosearch",
+        MyContacts => "mycontacts",
+        Directory => "directory",
+        IMContactList => "imcontactlist",
+        PeopleConnect => "peopleconnect",
+        Favorites => "favorites",
+    }
+}
+
+impl<'de> Deserialize<'de> for DistinguishedFolderName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let name = String::deserialize(deserializer)?;
+
+        Ok(Self::from(name.as_str()))
+    }
+}
+
 /// The unique identifier of a folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderid>
@@ -404,6 +639,11 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The permissions of the folder. Only included in responses if
+        /// requested as an additional property.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
     },
 
     /// A contacts folder in a mailbox.
@@ -431,6 +671,11 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The permissions of the folder. Only included in responses if
+        /// requested as an additional property.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
     },
 
     /// A folder in a mailbox.
@@ -459,6 +704,11 @@ pub enum Folder {
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
 
+        /// The permissions of the folder. Only included in responses if
+        /// requested as an additional property.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
+
         #[xml_struct(ns_prefix = "t")]
         unread_count: Option<u32>,
     },
@@ -488,6 +738,11 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The permissions of the folder. Only included in responses if
+        /// requested as an additional property.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
     },
 
     /// A task folder in a mailbox.
@@ -515,6 +770,11 @@ pub enum Folder {
 
         #[xml_struct(ns_prefix = "t")]
         extended_property: Option<Vec<ExtendedProperty>>,
+
+        /// The permissions of the folder. Only included in responses if
+        /// requested as an additional property.
+        #[xml_struct(ns_prefix = "t")]
+        permission_set: Option<PermissionSet>,
     },
 }
 
@@ -542,36 +802,47 @@ pub struct Folders {
 #[xml_struct(variant_ns_prefix = "t")]
 #[non_exhaustive]
 pub enum RealItem {
-    CalendarItem(Message),
+    CalendarItem(CalendarItem),
     Message(Message),
     MeetingMessage(Message),
     MeetingRequest(Message),
     MeetingResponse(Message),
     MeetingCancellation(Message),
+    Contact(Contact),
+    DistributionList(DistributionList),
 }
 
 impl RealItem {
     /// Return the [`Message`] object contained within this [`RealItem`].
-    pub fn inner_message(&self) -> &Message {
+    ///
+    /// Returns `None` if this item is not represented as a [`Message`], e.g.
+    /// if it is a [`CalendarItem`], a [`Contact`] or a [`DistributionList`].
+    pub fn inner_message(&self) -> Option<&Message> {
         match self {
-            RealItem::CalendarItem(message)
-            | RealItem::Message(message)
+            RealItem::Message(message)
             | RealItem::MeetingMessage(message)
             | RealItem::MeetingRequest(message)
             | RealItem::MeetingResponse(message)
-            | RealItem::MeetingCancellation(message) => message,
+            | RealItem::MeetingCancellation(message) => Some(message),
+            RealItem::CalendarItem(_) | RealItem::Contact(_) | RealItem::DistributionList(_) => {
+                None
+            }
         }
     }
 
     /// Take ownership of the inner [`Message`].
-    pub fn into_inner_message(self) -> Message {
+    ///
+    /// Returns `None` if this item is not represented as a [`Message`].
+    pub fn into_inner_message(self) -> Option<Message> {
         match self {
-            RealItem::CalendarItem(message)
-            | RealItem::Message(message)
+            RealItem::Message(message)
             | RealItem::MeetingMessage(message)
             | RealItem::MeetingRequest(message)
             | RealItem::MeetingResponse(message)
-            | RealItem::MeetingCancellation(message) => message,
+            | RealItem::MeetingCancellation(message) => Some(message),
+            RealItem::CalendarItem(_) | RealItem::Contact(_) | RealItem::DistributionList(_) => {
+                None
+            }
         }
     }
 }
@@ -581,17 +852,18 @@ impl RealItem {
 /// See [`Attachment::ItemAttachment`] for details.
 // N.B.: Commented-out variants are not yet implemented.
 #[non_exhaustive]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
 pub enum AttachmentItem {
     // Item(Item),
     Message(Message),
-    // CalendarItem(CalendarItem),
-    // Contact(Contact),
+    CalendarItem(CalendarItem),
+    Contact(Contact),
     // Task(Task),
-    // MeetingMessage(MeetingMessage),
-    // MeetingRequest(MeetingRequest),
-    // MeetingResponse(MeetingResponse),
-    // MeetingCancellation(MeetingCancellation),
+    MeetingMessage(Message),
+    MeetingRequest(Message),
+    MeetingResponse(Message),
+    MeetingCancellation(Message),
 }
 
 /// A date and time with second precision.
@@ -670,6 +942,7 @@ pub struct Message {
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/categories-ex15websvcsotherref>
     #[xml_struct(ns_prefix = "t")]
+    #[serde(default, deserialize_with = "deserialize_categories")]
     pub categories: Option<Vec<StringElement>>,
 
     // Extended MAPI properties of the message.
@@ -787,6 +1060,12 @@ pub struct Message {
     #[xml_struct(ns_prefix = "t")]
     pub references: Option<String>,
 
+    /// The follow-up flag of the message.
+    ///
+    /// This element was introduced in Exchange 2013.
+    #[xml_struct(ns_prefix = "t")]
+    pub flag: Option<Flag>,
+
     /// A short preview of the first 256 characters of an item.
     ///
     /// This value isn't documented for either the `Item` or `Message`
@@ -804,12 +1083,96 @@ pub struct Message {
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/extendedproperty>
 #[allow(non_snake_case)]
 #[derive(Clone, Debug, Deserialize, XmlSerialize, Eq, PartialEq)]
+#[serde(from = "RawExtendedProperty")]
 pub struct ExtendedProperty {
     #[xml_struct(ns_prefix = "t")]
     pub extended_field_URI: ExtendedFieldURI,
 
-    #[xml_struct(ns_prefix = "t")]
-    pub value: String,
+    /// The value of the property, as sent to or received from Exchange.
+    ///
+    /// Use [`ExtendedProperty::typed_value`] to interpret it according to the
+    /// type of the property.
+    #[xml_struct(flatten)]
+    pub value: ExtendedPropertyValue,
+}
+
+/// The deserialized form of an [`ExtendedProperty`].
+///
+/// `quick-xml` can't deserialize the value as an enum alongside the
+/// (prefixed) `ExtendedFieldURI` element, so we deserialize both possible
+/// value elements separately instead.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawExtendedProperty {
+    #[serde(rename = "ExtendedFieldURI")]
+    extended_field_uri: ExtendedFieldURI,
+    value: Option<String>,
+    values: Option<ExtendedPropertyValues>,
+}
+
+impl From<RawExtendedProperty> for ExtendedProperty {
+    fn from(raw: RawExtendedProperty) -> Self {
+        let value = match (raw.value, raw.values) {
+            (_, Some(values)) => ExtendedPropertyValue::Values(values),
+            // An empty `<t:Value/>` may be deserialized as no value at all.
+            (value, None) => ExtendedPropertyValue::Value(value.unwrap_or_default()),
+        };
+
+        Self {
+            extended_field_URI: raw.extended_field_uri,
+            value,
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+impl ExtendedProperty {
+    /// Creates a property with the given value, which must match the type
+    /// given by `extended_field_URI`.
+    pub fn new(
+        extended_field_URI: ExtendedFieldURI,
+        value: impl Into<PropertyValue>,
+    ) -> Result<Self, Error> {
+        let value = value.into();
+        expect_property_type(&extended_field_URI, value.property_type())?;
+
+        Ok(Self {
+            extended_field_URI,
+            value: value.to_raw()?,
+        })
+    }
+
+    /// Creates a [`PropertyType::Binary`] property with the given value.
+    pub fn from_binary(extended_field_URI: ExtendedFieldURI, value: &[u8]) -> Result<Self, Error> {
+        Self::new(extended_field_URI, PropertyValue::Binary(value.to_vec()))
+    }
+
+    /// Interprets the value of the property according to its type.
+    pub fn typed_value(&self) -> Result<PropertyValue, Error> {
+        PropertyValue::from_raw(self.extended_field_URI.property_type, &self.value)
+    }
+
+    /// Decodes the value of a [`PropertyType::Binary`] property.
+    pub fn decoded_binary(&self) -> Result<Vec<u8>, Error> {
+        expect_property_type(&self.extended_field_URI, PropertyType::Binary)?;
+
+        match self.typed_value()? {
+            PropertyValue::Binary(value) => Ok(value),
+            _ => unreachable!("binary properties have binary values"),
+        }
+    }
+}
+
+/// Returns an error if a property is not of the expected type.
+fn expect_property_type(uri: &ExtendedFieldURI, expected: PropertyType) -> Result<(), Error> {
+    if uri.property_type == expected {
+        Ok(())
+    } else {
+        Err(Error::UnexpectedPropertyType {
+            expected,
+            actual: uri.property_type,
+        })
+    }
 }
 
 /// A list of attachments.
@@ -879,6 +1242,28 @@ where
         .collect())
 }
 
+/// Deserializes a list of categories.
+///
+/// Each category is a `<t:String>` element, which `quick-xml` can't
+/// deserialize directly into a `Vec<StringElement>` when there is more than
+/// one.
+pub(crate) fn deserialize_categories<'de, D>(
+    deserializer: D,
+) -> Result<Option<Vec<StringElement>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let strings = ArrayOfStrings::deserialize(deserializer)?;
+
+    Ok(Some(
+        strings
+            .inner
+            .into_iter()
+            .map(|string| StringElement { string })
+            .collect(),
+    ))
+}
+
 /// A list of Internet Message Format headers.
 #[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
@@ -910,18 +1295,42 @@ pub struct Mailbox {
     /// possible values, it also appears that `SYSTEM` is a value that sometimes
     /// occurs. Since the documentation isn't clear, this is a free-form string
     /// field.
+    #[xml_struct(ns_prefix = "t")]
     pub routing_type: Option<String>,
 
     /// The type of sender/recipient represented by this mailbox.
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxtype>
+    #[xml_struct(ns_prefix = "t")]
     pub mailbox_type: Option<MailboxType>,
 
     /// An identifier for a contact or list of contacts corresponding to this
     /// mailbox.
+    #[xml_struct(ns_prefix = "t")]
     pub item_id: Option<ItemId>,
 }
 
+/// The address of a mailbox, as used by availability operations such as
+/// [`GetUserOofSettings`].
+///
+/// Unlike [`Mailbox`], the address is given in an `Address` element.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailbox-availability>
+///
+/// [`GetUserOofSettings`]: crate::get_user_oof_settings::GetUserOofSettings
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct EmailAddress {
+    #[xml_struct(ns_prefix = "t")]
+    pub name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub address: String,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub routing_type: Option<String>,
+}
+
 /// The type of sender or recipient a mailbox represents.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/mailboxtype>
@@ -938,6 +1347,57 @@ pub enum MailboxType {
     GroupMailbox,
 }
 
+/// A user to grant permissions to, such as a delegate.
+///
+/// Only one of the identifying fields is required.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userid>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[allow(non_snake_case)]
+pub struct UserId {
+    /// The security identifier of the user's account.
+    #[xml_struct(ns_prefix = "t")]
+    pub SID: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub primary_smtp_address: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: Option<String>,
+
+    /// A group of users rather than a specific one.
+    #[xml_struct(ns_prefix = "t")]
+    pub distinguished_user: Option<DistinguishedUser>,
+
+    /// The identity of a user outside of the organization.
+    #[xml_struct(ns_prefix = "t")]
+    pub external_user_identity: Option<String>,
+}
+
+impl UserId {
+    /// Creates an identifier for the user with the given address.
+    pub fn from_smtp_address(address: impl Into<String>) -> Self {
+        Self {
+            primary_smtp_address: Some(address.into()),
+            ..Default::default()
+        }
+    }
+}
+
+/// A group of users which can be granted permissions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distinguisheduser>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DistinguishedUser {
+    /// Any authenticated user not otherwise granted permissions.
+    Default,
+
+    /// Any unauthenticated user.
+    Anonymous,
+}
+
 /// The priority level of an item.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/importance>
@@ -959,6 +1419,107 @@ pub struct StringElement {
     pub string: String,
 }
 
+/// A list of strings, each in its own `<t:String>` element.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/arrayofstringstype>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ArrayOfStrings {
+    #[serde(rename = "String", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for ArrayOfStrings {
+    /// Serializes each string as a `<t:String>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for string in &self.inner {
+            string.serialize_as_element(writer, "t:String")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A follow-up flag on an item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/flag>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Flag {
+    #[xml_struct(ns_prefix = "t")]
+    pub flag_status: FlagStatus,
+
+    /// When work on the item is due to start.
+    #[xml_struct(ns_prefix = "t")]
+    pub start_date: Option<DateTime>,
+
+    /// When work on the item is due to be completed.
+    #[xml_struct(ns_prefix = "t")]
+    pub due_date: Option<DateTime>,
+
+    /// When the item was marked as complete.
+    #[xml_struct(ns_prefix = "t")]
+    pub complete_date: Option<DateTime>,
+}
+
+impl Flag {
+    /// A flag marking an item for follow-up, without a start or due date.
+    pub fn flagged() -> Self {
+        Self {
+            flag_status: FlagStatus::Flagged,
+            start_date: None,
+            due_date: None,
+            complete_date: None,
+        }
+    }
+
+    /// A flag marking an item for follow-up between two dates.
+    ///
+    /// Exchange requires that the start and due dates are either both set or
+    /// both omitted.
+    pub fn flagged_between(start_date: DateTime, due_date: DateTime) -> Self {
+        Self {
+            start_date: Some(start_date),
+            due_date: Some(due_date),
+            ..Self::flagged()
+        }
+    }
+
+    /// A flag marking the follow-up of an item as complete.
+    pub fn completed(complete_date: DateTime) -> Self {
+        Self {
+            flag_status: FlagStatus::Complete,
+            start_date: None,
+            due_date: None,
+            complete_date: Some(complete_date),
+        }
+    }
+
+    /// A flag clearing any follow-up of an item.
+    pub fn not_flagged() -> Self {
+        Self {
+            flag_status: FlagStatus::NotFlagged,
+            ..Self::flagged()
+        }
+    }
+}
+
+/// The state of a follow-up flag.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/flagstatus>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum FlagStatus {
+    NotFlagged,
+    Flagged,
+    Complete,
+}
+
 /// The sensitivity of the contents of an item.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sensitivity>
@@ -1004,27 +1565,53 @@ pub enum BodyType {
     Text,
 }
 
+/// The content type in which an item's body should be returned.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/bodytype>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum ResponseBodyType {
+    /// The richest available content type, usually HTML.
+    Best,
+    HTML,
+    Text,
+}
+
 /// An attachment to an Exchange item.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attachments-ex15websvcsotherref>
+// Item attachments are much larger than file attachments, but boxing the
+// attached item would make this type much more awkward to build and match on.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
 pub enum Attachment {
     /// An attachment containing an Exchange item.
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemattachment>
-    #[serde(rename_all = "PascalCase")]
+    #[serde(deserialize_with = "deserialize_item_attachment")]
     ItemAttachment {
         /// An identifier for the attachment.
-        attachment_id: AttachmentId,
+        ///
+        /// This is set by Exchange, and must be omitted when creating an
+        /// attachment.
+        #[xml_struct(ns_prefix = "t")]
+        attachment_id: Option<AttachmentId>,
 
         /// The name of the attachment.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/name-attachmenttype>
+        // Omitted from the attachments echoed in `CreateAttachment` responses.
+        #[serde(default)]
+        #[xml_struct(ns_prefix = "t")]
         name: String,
 
         /// The MIME type of the attachment's content.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contenttype>
+        // Omitted from the attachments echoed in `CreateAttachment` responses.
+        #[serde(default)]
+        #[xml_struct(ns_prefix = "t")]
         content_type: String,
 
         /// An arbitrary identifier for the attachment.
@@ -1033,33 +1620,38 @@ pub enum Attachment {
         /// external applications.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contentid>
+        #[xml_struct(ns_prefix = "t")]
         content_id: Option<String>,
 
         /// A URI representing the location of the attachment's content.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contentlocation>
+        #[xml_struct(ns_prefix = "t")]
         content_location: Option<String>,
 
         /// The size of the attachment's content in bytes.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/size>
+        #[xml_struct(ns_prefix = "t")]
         size: Option<usize>,
 
         /// The most recent modification time for the attachment.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/lastmodifiedtime>
+        #[xml_struct(ns_prefix = "t")]
         last_modified_time: Option<DateTime>,
 
         /// Whether the attachment appears inline in the item body.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isinline>
+        #[xml_struct(ns_prefix = "t")]
         is_inline: Option<bool>,
-        // XXX: With this field in place, parsing will fail if there is no
-        // `AttachmentItem` in the response.
-        // See https://github.com/tafia/quick-xml/issues/683
-        // /// The attached item.
-        // #[serde(rename = "$value")]
-        // content: Option<AttachmentItem>,
+
+        /// The attached item.
+        ///
+        /// This is only included in responses to `GetAttachment` requests.
+        #[xml_struct(flatten)]
+        content: Option<AttachmentItem>,
     },
 
     /// An attachment containing a file.
@@ -1068,16 +1660,26 @@ pub enum Attachment {
     #[serde(rename_all = "PascalCase")]
     FileAttachment {
         /// An identifier for the attachment.
-        attachment_id: AttachmentId,
+        ///
+        /// This is set by Exchange, and must be omitted when creating an
+        /// attachment.
+        #[xml_struct(ns_prefix = "t")]
+        attachment_id: Option<AttachmentId>,
 
         /// The name of the attachment.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/name-attachmenttype>
+        // Omitted from the attachments echoed in `CreateAttachment` responses.
+        #[serde(default)]
+        #[xml_struct(ns_prefix = "t")]
         name: String,
 
         /// The MIME type of the attachment's content.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contenttype>
+        // Omitted from the attachments echoed in `CreateAttachment` responses.
+        #[serde(default)]
+        #[xml_struct(ns_prefix = "t")]
         content_type: String,
 
         /// An arbitrary identifier for the attachment.
@@ -1086,40 +1688,170 @@ pub enum Attachment {
         /// external applications.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contentid>
+        #[xml_struct(ns_prefix = "t")]
         content_id: Option<String>,
 
         /// A URI representing the location of the attachment's content.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contentlocation>
+        #[xml_struct(ns_prefix = "t")]
         content_location: Option<String>,
 
         /// The size of the attachment's content in bytes.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/size>
+        #[xml_struct(ns_prefix = "t")]
         size: Option<usize>,
 
         /// The most recent modification time for the attachment.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/lastmodifiedtime>
+        #[xml_struct(ns_prefix = "t")]
         last_modified_time: Option<DateTime>,
 
         /// Whether the attachment appears inline in the item body.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isinline>
+        #[xml_struct(ns_prefix = "t")]
         is_inline: Option<bool>,
 
         /// Whether the attachment represents a contact photo.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/iscontactphoto>
+        #[xml_struct(ns_prefix = "t")]
         is_contact_photo: Option<bool>,
 
         /// The base64-encoded content of the attachment.
         ///
         /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/content>
+        #[xml_struct(ns_prefix = "t")]
         content: Option<String>,
     },
 }
 
+impl Attachment {
+    /// Creates a file attachment with the given content, for use in a
+    /// [`CreateAttachment`] or [`CreateItem`] request.
+    ///
+    /// [`CreateAttachment`]: crate::create_attachment::CreateAttachment
+    /// [`CreateItem`]: crate::create_item::CreateItem
+    pub fn new_file_attachment(
+        name: impl Into<String>,
+        content_type: impl Into<String>,
+        content: &[u8],
+    ) -> Self {
+        Self::FileAttachment {
+            attachment_id: None,
+            name: name.into(),
+            content_type: content_type.into(),
+            content_id: None,
+            content_location: None,
+            size: None,
+            last_modified_time: None,
+            is_inline: None,
+            is_contact_photo: None,
+            content: Some(encode_base64(content)),
+        }
+    }
+
+    /// Decodes the content of a file attachment.
+    ///
+    /// Returns `None` for item attachments and for file attachments fetched
+    /// without their content.
+    pub fn decoded_content(&self) -> Result<Option<Vec<u8>>, Error> {
+        match self {
+            Self::FileAttachment {
+                content: Some(content),
+                ..
+            } => Ok(Some(decode_base64(content)?)),
+            _ => Ok(None),
+        }
+    }
+}
+
+/// The fields of an [`Attachment::ItemAttachment`], in declaration order.
+type ItemAttachmentFields = (
+    Option<AttachmentId>,
+    String,
+    String,
+    Option<String>,
+    Option<String>,
+    Option<usize>,
+    Option<DateTime>,
+    Option<bool>,
+    Option<AttachmentItem>,
+);
+
+/// Deserializes the content of an [`Attachment::ItemAttachment`].
+///
+/// The attached item is represented by an element named after its type, which
+/// would usually be captured with a `$value` field. However, `quick-xml`
+/// compares the prefixed names of elements to the names of fields when a
+/// `$value` field is present, so such a field would also capture every other
+/// (prefixed) element of the attachment. Instead, we go through an
+/// intermediate struct with a field for each type of item.
+///
+/// See <https://github.com/tafia/quick-xml/issues/683>
+fn deserialize_item_attachment<'de, D>(deserializer: D) -> Result<ItemAttachmentFields, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct ItemAttachment {
+        attachment_id: Option<AttachmentId>,
+        #[serde(default)]
+        name: String,
+        #[serde(default)]
+        content_type: String,
+        content_id: Option<String>,
+        content_location: Option<String>,
+        size: Option<usize>,
+        last_modified_time: Option<DateTime>,
+        is_inline: Option<bool>,
+        message: Option<Message>,
+        calendar_item: Option<CalendarItem>,
+        contact: Option<Contact>,
+        meeting_message: Option<Message>,
+        meeting_request: Option<Message>,
+        meeting_response: Option<Message>,
+        meeting_cancellation: Option<Message>,
+    }
+
+    let attachment = ItemAttachment::deserialize(deserializer)?;
+
+    // An attachment contains at most one item, so at most one of these is set.
+    let content = attachment
+        .message
+        .map(AttachmentItem::Message)
+        .or(attachment.calendar_item.map(AttachmentItem::CalendarItem))
+        .or(attachment.contact.map(AttachmentItem::Contact))
+        .or(attachment
+            .meeting_message
+            .map(AttachmentItem::MeetingMessage))
+        .or(attachment
+            .meeting_request
+            .map(AttachmentItem::MeetingRequest))
+        .or(attachment
+            .meeting_response
+            .map(AttachmentItem::MeetingResponse))
+        .or(attachment
+            .meeting_cancellation
+            .map(AttachmentItem::MeetingCancellation));
+
+    Ok((
+        attachment.attachment_id,
+        attachment.name,
+        attachment.content_type,
+        attachment.content_id,
+        attachment.content_location,
+        attachment.size,
+        attachment.last_modified_time,
+        attachment.is_inline,
+        content,
+    ))
+}
+
 /// An identifier for an attachment.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attachmentid>
@@ -1141,6 +1873,25 @@ pub struct AttachmentId {
     pub root_item_change_key: Option<String>,
 }
 
+/// An identifier for an attachment in a request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attachmentids>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum RequestAttachmentId {
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attachmentid>
+    AttachmentId {
+        #[xml_struct(attribute)]
+        id: String,
+    },
+}
+
+impl From<AttachmentId> for RequestAttachmentId {
+    fn from(value: AttachmentId) -> Self {
+        Self::AttachmentId { id: value.id }
+    }
+}
+
 /// The content of an item, represented according to MIME (Multipurpose Internet
 /// Mail Extensions).
 ///
@@ -1156,11 +1907,54 @@ pub struct MimeContent {
     pub character_set: Option<String>,
 
     /// The item content.
-    #[serde(rename = "$text")]
+    ///
+    /// Empty if the content was streamed with [`crate::content`].
+    #[serde(rename = "$text", default)]
     #[xml_struct(flatten)]
     pub content: String,
 }
 
+impl MimeContent {
+    /// Creates MIME content from the raw bytes of an [RFC 822] message.
+    ///
+    /// [RFC 822]: https://datatracker.ietf.org/doc/html/rfc822
+    pub fn from_rfc822_bytes(bytes: &[u8]) -> Self {
+        Self {
+            character_set: None,
+            content: encode_base64(bytes),
+        }
+    }
+
+    /// Creates MIME content from the text of an [RFC 822] message, marked as
+    /// UTF-8.
+    ///
+    /// [RFC 822]: https://datatracker.ietf.org/doc/html/rfc822
+    pub fn from_rfc822_text(text: &str) -> Self {
+        Self {
+            character_set: Some("UTF-8".to_string()),
+            content: encode_base64(text.as_bytes()),
+        }
+    }
+
+    /// Decodes the raw bytes of the MIME content.
+    pub fn decoded_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(decode_base64(&self.content)?)
+    }
+
+    /// Decodes the MIME content as text in its [`character_set`], assuming
+    /// UTF-8 if none is specified.
+    ///
+    /// The supported character sets are UTF-8, US-ASCII, ISO-8859-1 and
+    /// windows-1252, along with their common aliases such as `utf8`, `ascii`,
+    /// `latin1` and `cp1252`. Names are matched case-insensitively. Any other
+    /// character set results in [`Error::UnsupportedCharacterSet`].
+    ///
+    /// [`character_set`]: Self::character_set
+    pub fn decoded_text(&self) -> Result<String, Error> {
+        decode_text(self.decoded_bytes()?, self.character_set.as_deref())
+    }
+}
+
 /// The headers of an Exchange item's MIME content.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/internetmessageheader>
@@ -1256,6 +2050,51 @@ pub enum BasePoint {
     End,
 }
 
+/// The order in which results are returned, as a list of properties to sort
+/// by in decreasing precedence.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sortorder>
+#[derive(Clone, Debug, Default)]
+pub struct SortOrder(pub Vec<FieldOrder>);
+
+impl XmlSerialize for SortOrder {
+    /// Serializes each property as a `<t:FieldOrder>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for field_order in &self.0 {
+            field_order.serialize_as_element(writer, "t:FieldOrder")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A property to sort results by.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fieldorder>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct FieldOrder {
+    #[xml_struct(attribute)]
+    pub order: SortDirection,
+
+    /// The property to sort by.
+    #[xml_struct(flatten)]
+    pub path: PathToElement,
+}
+
+/// The direction in which to sort results.
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "PascalCase")]
 pub struct Groups {
@@ -1382,4 +2221,160 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn mime_content_round_trip() -> Result<(), Error> {
+        let mime_content = MimeContent::from_rfc822_text("Subject: Caf\u{e9}\r\n\r\nHi");
+        assert_eq!(mime_content.character_set.as_deref(), Some("UTF-8"));
+        assert_eq!(mime_content.content, "U3ViamVjdDogQ2Fmw6kNCg0KSGk=");
+        assert_eq!(mime_content.decoded_text()?, "Subject: Caf\u{e9}\r\n\r\nHi");
+
+        let mime_content = MimeContent {
+            character_set: Some("ISO-8859-1".to_string()),
+            content: "U3ViamVjdDogQ2Fm6Q0KDQpIaQ==".to_string(),
+        };
+        assert_eq!(mime_content.decoded_bytes()?, b"Subject: Caf\xe9\r\n\r\nHi");
+        assert_eq!(mime_content.decoded_text()?, "Subject: Caf\u{e9}\r\n\r\nHi");
+
+        let mime_content = MimeContent {
+            character_set: Some("KOI8-R".to_string()),
+            ..mime_content
+        };
+        assert!(matches!(
+            mime_content.decoded_text(),
+            Err(Error::UnsupportedCharacterSet(charset)) if charset == "KOI8-R"
+        ));
+
+        let mime_content = MimeContent {
+            character_set: None,
+            content: "not base64!".to_string(),
+        };
+        assert!(matches!(
+            mime_content.decoded_bytes(),
+            Err(Error::InvalidBase64(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_extended_property() -> Result<(), Error> {
+        let uri = ExtendedFieldURI {
+            distinguished_property_set_id: None,
+            property_set_id: None,
+            property_tag: Some("0x0FFF".to_string()),
+            property_name: None,
+            property_id: None,
+            property_type: PropertyType::Binary,
+        };
+
+        let property = ExtendedProperty::from_binary(uri.clone(), &[0, 1, 2, 0xff])?;
+        assert_eq!(
+            property.value,
+            ExtendedPropertyValue::Value("AAEC/w==".to_string())
+        );
+        assert_eq!(property.decoded_binary()?, [0, 1, 2, 0xff]);
+
+        let uri = ExtendedFieldURI {
+            property_type: PropertyType::String,
+            ..uri
+        };
+        assert!(matches!(
+            ExtendedProperty::from_binary(uri, b"text"),
+            Err(Error::UnexpectedPropertyType {
+                expected: PropertyType::Binary,
+                actual: PropertyType::String,
+            })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_attachment_content() -> Result<(), Error> {
+        let attachment = Attachment::new_file_attachment("notes.txt", "text/plain", b"hello");
+        assert_eq!(
+            attachment.decoded_content()?.as_deref(),
+            Some(&b"hello"[..])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn deserialize_message_flag_and_categories() -> Result<(), Error> {
+        let xml = r#"<t:Message xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><t:Categories><t:String>Red category</t:String><t:String>Blue category</t:String></t:Categories><t:Flag><t:FlagStatus>Flagged</t:FlagStatus><t:StartDate>2024-05-02T09:12:44Z</t:StartDate><t:DueDate>2024-05-02T09:12:44Z</t:DueDate></t:Flag></t:Message>"#;
+
+        let mut de = quick_xml::de::Deserializer::from_reader(xml.as_bytes());
+        let message: Message = serde_path_to_error::deserialize(&mut de)?;
+
+        let date = DateTime(time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap());
+        assert_eq!(
+            message.flag,
+            Some(Flag::flagged_between(date.clone(), date))
+        );
+        assert_eq!(
+            message.categories,
+            Some(vec![
+                StringElement {
+                    string: "Red category".to_string()
+                },
+                StringElement {
+                    string: "Blue category".to_string()
+                },
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn distinguished_folder_id_in_other_mailbox() -> Result<(), Error> {
+        let folder_id = BaseFolderId::distinguished_in_mailbox(
+            DistinguishedFolderName::Calendar,
+            "boss@example.com",
+        );
+
+        let expected = r#"<ParentFolderId><t:DistinguishedFolderId Id="calendar"><t:Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></t:Mailbox></t:DistinguishedFolderId></ParentFolderId>"#;
+        assert_serialized_content(&folder_id, "ParentFolderId", expected);
+
+        let xml = r#"<t:MoveToFolder xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><t:DistinguishedFolderId Id="calendar"><t:Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></t:Mailbox></t:DistinguishedFolderId></t:MoveToFolder>"#;
+
+        let mut de = quick_xml::de::Deserializer::from_reader(xml.as_bytes());
+        let target: TargetFolderId = serde_path_to_error::deserialize(&mut de)?;
+        assert_eq!(target.folder_id, folder_id);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_mixed_folder_ids() {
+        let folder_ids = vec![
+            BaseFolderId::FolderId {
+                id: "AQMkAGI=".to_string(),
+                change_key: Some("AQAAAA==".to_string()),
+            },
+            BaseFolderId::distinguished_in_mailbox(
+                DistinguishedFolderName::Inbox,
+                "boss@example.com",
+            ),
+        ];
+
+        let expected = r#"<FolderIds><t:FolderId Id="AQMkAGI=" ChangeKey="AQAAAA=="/><t:DistinguishedFolderId Id="inbox"><t:Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></t:Mailbox></t:DistinguishedFolderId></FolderIds>"#;
+        assert_serialized_content(&folder_ids, "FolderIds", expected);
+    }
+
+    #[test]
+    fn distinguished_folder_name_string_round_trip() {
+        for name in ["inbox", "archivemsgfolderroot", "recoverableitemsdeletions"] {
+            let folder_name = DistinguishedFolderName::from(name);
+            assert!(!matches!(folder_name, DistinguishedFolderName::Other(_)));
+            assert_eq!(folder_name.as_str(), name);
+        }
+
+        assert_eq!(
+            DistinguishedFolderName::from("allitems"),
+            DistinguishedFolderName::Other("allitems".to_string())
+        );
+    }
 }
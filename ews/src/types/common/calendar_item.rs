@@ -0,0 +1,491 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use super::deserialize_categories;
+
+use crate::{
+    Attachments, Body, DateTime, ExtendedProperty, FolderId, Importance, InternetMessageHeaders,
+    ItemId, Mailbox, MimeContent, Recipient, Sensitivity, StringElement,
+};
+
+/// An appointment or meeting in a calendar.
+///
+/// Recurrence patterns and time zone definitions are not yet represented.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendaritem>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CalendarItem {
+    /// The MIME content of the item.
+    #[xml_struct(ns_prefix = "t")]
+    pub mime_content: Option<MimeContent>,
+
+    /// The item's Exchange identifier.
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: Option<ItemId>,
+
+    /// The identifier for the containing folder.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/parentfolderid>
+    #[xml_struct(ns_prefix = "t")]
+    pub parent_folder_id: Option<FolderId>,
+
+    /// The Exchange class value of the item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemclass>
+    #[xml_struct(ns_prefix = "t")]
+    pub item_class: Option<String>,
+
+    /// The subject of the item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/subject>
+    #[xml_struct(ns_prefix = "t")]
+    pub subject: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sensitivity: Option<Sensitivity>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub body: Option<Body>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub attachments: Option<Attachments>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_received: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub size: Option<usize>,
+
+    /// A list of categories describing an item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/categories-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(default, deserialize_with = "deserialize_categories")]
+    pub categories: Option<Vec<StringElement>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub importance: Option<Importance>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub in_reply_to: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_submitted: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_draft: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_from_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_resend: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_unmodified: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub internet_message_headers: Option<InternetMessageHeaders>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_sent: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_created: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub reminder_due_by: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub reminder_is_set: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub reminder_minutes_before_start: Option<usize>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub display_cc: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub display_to: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub has_attachments: Option<bool>,
+
+    // Extended MAPI properties of the calendar item.
+    #[xml_struct(ns_prefix = "t")]
+    pub extended_property: Option<Vec<ExtendedProperty>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub culture: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_time: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_associated: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub conversation_id: Option<ItemId>,
+
+    /// The iCalendar identifier of the appointment, shared by all of its
+    /// occurrences.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/uid>
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(rename = "UID")]
+    pub uid: Option<String>,
+
+    /// The original start time of an occurrence of a recurring appointment.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/recurrenceid>
+    #[xml_struct(ns_prefix = "t")]
+    pub recurrence_id: Option<DateTime>,
+
+    /// When the appointment was last sent or received as a meeting message.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/datetimestamp>
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_stamp: Option<DateTime>,
+
+    /// The start of the appointment.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/start>
+    #[xml_struct(ns_prefix = "t")]
+    pub start: Option<DateTime>,
+
+    /// The end of the appointment.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/end-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    pub end: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub original_start: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_all_day_event: Option<bool>,
+
+    /// How the time of the appointment is shown to other users.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/legacyfreebusystatus>
+    #[xml_struct(ns_prefix = "t")]
+    pub legacy_free_busy_status: Option<LegacyFreeBusyStatus>,
+
+    /// The location of the appointment, as free text.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/location>
+    #[xml_struct(ns_prefix = "t")]
+    pub location: Option<String>,
+
+    /// A description of when the appointment occurs, as free text.
+    #[xml_struct(ns_prefix = "t")]
+    pub when: Option<String>,
+
+    /// Whether the appointment has attendees other than its organizer.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_meeting: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_cancelled: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_recurring: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub meeting_request_was_sent: Option<bool>,
+
+    /// Whether attendees are asked to respond to the meeting request.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_response_requested: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_item_type: Option<CalendarItemType>,
+
+    /// The response of the mailbox's owner to the meeting.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/myresponsetype>
+    #[xml_struct(ns_prefix = "t")]
+    pub my_response_type: Option<MeetingResponseType>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub organizer: Option<Recipient>,
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/requiredattendees>
+    #[xml_struct(ns_prefix = "t")]
+    pub required_attendees: Option<ArrayOfAttendees>,
+
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/optionalattendees>
+    #[xml_struct(ns_prefix = "t")]
+    pub optional_attendees: Option<ArrayOfAttendees>,
+
+    /// The rooms and equipment booked for the meeting.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resources>
+    #[xml_struct(ns_prefix = "t")]
+    pub resources: Option<ArrayOfAttendees>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub conflicting_meeting_count: Option<usize>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub adjacent_meeting_count: Option<usize>,
+
+    /// The length of the appointment, as an `xs:duration` such as `PT30M`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/duration>
+    #[xml_struct(ns_prefix = "t")]
+    pub duration: Option<String>,
+
+    /// The display name of the time zone of the appointment.
+    #[xml_struct(ns_prefix = "t")]
+    pub time_zone: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub appointment_reply_time: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub appointment_sequence_number: Option<u32>,
+
+    /// A bit field describing the state of the appointment.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/appointmentstate>
+    #[xml_struct(ns_prefix = "t")]
+    pub appointment_state: Option<u32>,
+
+    /// The kind of online meeting, as a MAPI `PidLidConferencingType` value.
+    #[xml_struct(ns_prefix = "t")]
+    pub conference_type: Option<u32>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub allow_new_time_proposal: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_online_meeting: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub meeting_workspace_url: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub net_show_url: Option<String>,
+}
+
+/// How the time of an appointment is shown to other users.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/legacyfreebusystatus>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum LegacyFreeBusyStatus {
+    Free,
+    Tentative,
+    Busy,
+
+    /// Out of office.
+    OOF,
+
+    WorkingElsewhere,
+
+    /// No information is available.
+    NoData,
+}
+
+/// The place of a calendar item relative to a recurring series.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendaritemtype>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CalendarItemType {
+    /// An appointment which doesn't recur.
+    Single,
+
+    /// An unmodified occurrence of a recurring appointment.
+    Occurrence,
+
+    /// A modified occurrence of a recurring appointment.
+    Exception,
+
+    /// The appointment defining a recurring series.
+    RecurringMaster,
+}
+
+/// The response of an attendee to a meeting.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/responsetype>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum MeetingResponseType {
+    Unknown,
+    Organizer,
+    Tentative,
+    Accept,
+    Decline,
+    NoResponseReceived,
+}
+
+/// A list of meeting attendees.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ArrayOfAttendees {
+    #[serde(rename = "Attendee", default)]
+    pub inner: Vec<Attendee>,
+}
+
+impl XmlSerialize for ArrayOfAttendees {
+    /// Serializes each attendee as a `<t:Attendee>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for attendee in &self.inner {
+            attendee.serialize_as_element(writer, "t:Attendee")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An attendee of a meeting.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attendee>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attendee {
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: Mailbox,
+
+    /// The attendee's response to the meeting. Only included in responses.
+    #[xml_struct(ns_prefix = "t")]
+    pub response_type: Option<MeetingResponseType>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_response_time: Option<DateTime>,
+}
+
+impl Attendee {
+    /// Creates an attendee with the given address, for use in a request.
+    pub fn new(email_address: impl Into<String>) -> Self {
+        Self {
+            mailbox: Mailbox {
+                email_address: Some(email_address.into()),
+                ..Default::default()
+            },
+            response_type: None,
+            last_response_time: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_deserialized_content, assert_serialized_content};
+
+    #[test]
+    fn serialize_calendar_item() {
+        let calendar_item = CalendarItem {
+            subject: Some("Project sync".to_string()),
+            start: Some(DateTime(
+                time::OffsetDateTime::from_unix_timestamp(1715007600).unwrap(),
+            )),
+            end: Some(DateTime(
+                time::OffsetDateTime::from_unix_timestamp(1715009400).unwrap(),
+            )),
+            legacy_free_busy_status: Some(LegacyFreeBusyStatus::Busy),
+            location: Some("Room 4".to_string()),
+            required_attendees: Some(ArrayOfAttendees {
+                inner: vec![
+                    Attendee::new("alice@example.com"),
+                    Attendee::new("bob@example.com"),
+                ],
+            }),
+            ..Default::default()
+        };
+
+        let expected = r#"<t:CalendarItem><t:Subject>Project sync</t:Subject><t:Start>2024-05-06T15:00:00.000000000Z</t:Start><t:End>2024-05-06T15:30:00.000000000Z</t:End><t:LegacyFreeBusyStatus>Busy</t:LegacyFreeBusyStatus><t:Location>Room 4</t:Location><t:RequiredAttendees><t:Attendee><t:Mailbox><t:EmailAddress>alice@example.com</t:EmailAddress></t:Mailbox></t:Attendee><t:Attendee><t:Mailbox><t:EmailAddress>bob@example.com</t:EmailAddress></t:Mailbox></t:Attendee></t:RequiredAttendees></t:CalendarItem>"#;
+
+        assert_serialized_content(&calendar_item, "t:CalendarItem", expected);
+    }
+
+    #[test]
+    fn deserialize_calendar_item() {
+        let content = r#"<t:CalendarItem xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <t:ItemId Id="AAMkADp=" ChangeKey="DwAAABYA" />
+                <t:Subject>Project sync</t:Subject>
+                <t:UID>040000008200E00074C5B7101A82E0080000000012345678</t:UID>
+                <t:Start>2024-05-06T15:00:00Z</t:Start>
+                <t:End>2024-05-06T15:30:00Z</t:End>
+                <t:IsAllDayEvent>false</t:IsAllDayEvent>
+                <t:LegacyFreeBusyStatus>OOF</t:LegacyFreeBusyStatus>
+                <t:Location>Room 4</t:Location>
+                <t:IsMeeting>true</t:IsMeeting>
+                <t:CalendarItemType>Single</t:CalendarItemType>
+                <t:MyResponseType>Organizer</t:MyResponseType>
+                <t:Organizer>
+                    <t:Mailbox>
+                        <t:Name>Alice Test</t:Name>
+                        <t:EmailAddress>alice@example.com</t:EmailAddress>
+                    </t:Mailbox>
+                </t:Organizer>
+                <t:RequiredAttendees>
+                    <t:Attendee>
+                        <t:Mailbox>
+                            <t:EmailAddress>bob@example.com</t:EmailAddress>
+                        </t:Mailbox>
+                        <t:ResponseType>Accept</t:ResponseType>
+                        <t:LastResponseTime>2024-05-02T09:12:44Z</t:LastResponseTime>
+                    </t:Attendee>
+                </t:RequiredAttendees>
+                <t:Duration>PT30M</t:Duration>
+            </t:CalendarItem>"#;
+
+        let expected = CalendarItem {
+            item_id: Some(ItemId {
+                id: "AAMkADp=".to_string(),
+                change_key: Some("DwAAABYA".to_string()),
+            }),
+            subject: Some("Project sync".to_string()),
+            uid: Some("040000008200E00074C5B7101A82E0080000000012345678".to_string()),
+            start: Some(DateTime(
+                time::OffsetDateTime::from_unix_timestamp(1715007600).unwrap(),
+            )),
+            end: Some(DateTime(
+                time::OffsetDateTime::from_unix_timestamp(1715009400).unwrap(),
+            )),
+            is_all_day_event: Some(false),
+            legacy_free_busy_status: Some(LegacyFreeBusyStatus::OOF),
+            location: Some("Room 4".to_string()),
+            is_meeting: Some(true),
+            calendar_item_type: Some(CalendarItemType::Single),
+            my_response_type: Some(MeetingResponseType::Organizer),
+            organizer: Some(Recipient {
+                mailbox: Mailbox {
+                    name: Some("Alice Test".to_string()),
+                    email_address: Some("alice@example.com".to_string()),
+                    ..Default::default()
+                },
+            }),
+            required_attendees: Some(ArrayOfAttendees {
+                inner: vec![Attendee {
+                    response_type: Some(MeetingResponseType::Accept),
+                    last_response_time: Some(DateTime(
+                        time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap(),
+                    )),
+                    ..Attendee::new("bob@example.com")
+                }],
+            }),
+            duration: Some("PT30M".to_string()),
+            ..Default::default()
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
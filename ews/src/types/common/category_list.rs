@@ -0,0 +1,471 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The master category list of a mailbox.
+//!
+//! Items only store the names of their categories, in
+//! [`Message::categories`]. The colour and other settings of each category
+//! are defined by the master category list, which Outlook stores as the XML
+//! data of the `CategoryList` user configuration object of the calendar
+//! folder.
+
+use quick_xml::{
+    events::{BytesDecl, BytesEnd, BytesStart, Event},
+    Reader, Writer,
+};
+
+use crate::{
+    get_user_configuration::GetUserConfiguration,
+    update_user_configuration::UpdateUserConfiguration, BaseFolderId, DistinguishedFolderName,
+    Error, Message, UserConfiguration, UserConfigurationData, UserConfigurationName,
+    UserConfigurationProperties,
+};
+
+/// The name of the user configuration object storing the category list.
+const CATEGORY_LIST_NAME: &str = "CategoryList";
+
+/// The namespace of the category list document.
+const CATEGORY_LIST_NS: &str = "CategoryList.xsd";
+
+/// The master category list of a mailbox.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CategoryList {
+    /// The name of the category applied by default, e.g. by Outlook's quick
+    /// click action.
+    pub default_category: Option<String>,
+
+    pub categories: Vec<Category>,
+
+    /// Other attributes of the list, kept as-is when writing it back.
+    pub other_attributes: Vec<(String, String)>,
+}
+
+/// A category of the master category list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Category {
+    /// The name of the category, as found in [`Message::categories`].
+    pub name: String,
+
+    pub color: CategoryColor,
+
+    /// Other attributes of the category, such as its GUID and usage
+    /// statistics, kept as-is when writing it back.
+    pub other_attributes: Vec<(String, String)>,
+}
+
+impl Category {
+    /// Creates a category with the given name and colour.
+    pub fn new(name: impl Into<String>, color: CategoryColor) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            other_attributes: Vec::new(),
+        }
+    }
+}
+
+/// The colour of a category.
+///
+/// Outlook identifies colours by the index of a preset; the names used here
+/// match the ones it displays.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CategoryColor {
+    #[default]
+    None,
+    Red,
+    Orange,
+    Peach,
+    Yellow,
+    Green,
+    Teal,
+    Olive,
+    Blue,
+    Purple,
+    Maroon,
+    Steel,
+    DarkSteel,
+    Gray,
+    DarkGray,
+    Black,
+    DarkRed,
+    DarkOrange,
+    DarkPeach,
+    DarkYellow,
+    DarkGreen,
+    DarkTeal,
+    DarkOlive,
+    DarkBlue,
+    DarkPurple,
+    DarkMaroon,
+}
+
+impl CategoryColor {
+    /// The colour presets, in order of their indices.
+    const PRESETS: [Self; 25] = [
+        Self::Red,
+        Self::Orange,
+        Self::Peach,
+        Self::Yellow,
+        Self::Green,
+        Self::Teal,
+        Self::Olive,
+        Self::Blue,
+        Self::Purple,
+        Self::Maroon,
+        Self::Steel,
+        Self::DarkSteel,
+        Self::Gray,
+        Self::DarkGray,
+        Self::Black,
+        Self::DarkRed,
+        Self::DarkOrange,
+        Self::DarkPeach,
+        Self::DarkYellow,
+        Self::DarkGreen,
+        Self::DarkTeal,
+        Self::DarkOlive,
+        Self::DarkBlue,
+        Self::DarkPurple,
+        Self::DarkMaroon,
+    ];
+
+    /// Gets the colour with the given preset index, where `-1` means no
+    /// colour.
+    pub fn from_index(index: i32) -> Option<Self> {
+        match index {
+            -1 => Some(Self::None),
+            index => usize::try_from(index)
+                .ok()
+                .and_then(|index| Self::PRESETS.get(index))
+                .copied(),
+        }
+    }
+
+    /// The preset index of the colour, where `-1` means no colour.
+    pub fn index(self) -> i32 {
+        Self::PRESETS
+            .iter()
+            .position(|preset| *preset == self)
+            .map_or(-1, |index| index as i32)
+    }
+}
+
+impl CategoryList {
+    /// Parses the XML data of the `CategoryList` user configuration object.
+    pub fn from_xml(data: &[u8]) -> Result<Self, Error> {
+        // Outlook writes the document with a byte order mark.
+        let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+        let mut reader = Reader::from_reader(data);
+        reader.trim_text(true);
+
+        let mut list: Option<Self> = None;
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) | Event::Empty(element) => {
+                    match element.local_name().as_ref() {
+                        b"categories" => {
+                            let mut attributes = attributes(&element)?;
+
+                            // The namespace is added back when serializing.
+                            take_attribute(&mut attributes, "xmlns");
+
+                            list = Some(Self {
+                                default_category: take_attribute(&mut attributes, "default"),
+                                categories: Vec::new(),
+                                other_attributes: attributes,
+                            });
+                        }
+                        b"category" => {
+                            let list = list.as_mut().ok_or(Error::InvalidCategoryList(
+                                "category outside of the list",
+                            ))?;
+                            list.categories.push(parse_category(&element)?);
+                        }
+
+                        // Ignore anything added by later versions of Outlook.
+                        _ => (),
+                    }
+                }
+                Event::Eof => break,
+                _ => (),
+            }
+        }
+
+        list.ok_or(Error::InvalidCategoryList("missing `categories` element"))
+    }
+
+    /// Serializes the list as the XML data of the `CategoryList` user
+    /// configuration object.
+    pub fn to_xml(&self) -> Result<Vec<u8>, Error> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_event(Event::Decl(BytesDecl::new("1.0", None, None)))?;
+
+        let mut root = BytesStart::new("categories");
+        if let Some(default_category) = &self.default_category {
+            root.push_attribute(("default", default_category.as_str()));
+        }
+        push_attributes(&mut root, &self.other_attributes);
+        root.push_attribute(("xmlns", CATEGORY_LIST_NS));
+        writer.write_event(Event::Start(root))?;
+
+        for category in &self.categories {
+            let mut element = BytesStart::new("category");
+            element.push_attribute(("name", category.name.as_str()));
+            element.push_attribute(("color", category.color.index().to_string().as_str()));
+            push_attributes(&mut element, &category.other_attributes);
+            writer.write_event(Event::Empty(element))?;
+        }
+
+        writer.write_event(Event::End(BytesEnd::new("categories")))?;
+
+        Ok(writer.into_inner())
+    }
+
+    /// Gets the category with the given name.
+    ///
+    /// As in Outlook, names are compared case-insensitively.
+    pub fn get(&self, name: &str) -> Option<&Category> {
+        self.categories
+            .iter()
+            .find(|category| category.name.to_lowercase() == name.to_lowercase())
+    }
+
+    /// The colour of the category with the given name, or
+    /// [`CategoryColor::None`] if it isn't part of the list.
+    pub fn color_of(&self, name: &str) -> CategoryColor {
+        self.get(name)
+            .map_or(CategoryColor::None, |category| category.color)
+    }
+
+    /// The categories of a message, along with their colours.
+    pub fn message_categories<'a>(&self, message: &'a Message) -> Vec<(&'a str, CategoryColor)> {
+        message
+            .categories
+            .iter()
+            .flatten()
+            .map(|category| (category.string.as_str(), self.color_of(&category.string)))
+            .collect()
+    }
+
+    /// Creates a request to read the category list.
+    ///
+    /// The list can be parsed from the response with
+    /// [`CategoryList::from_user_configuration`].
+    pub fn get_request() -> GetUserConfiguration {
+        GetUserConfiguration {
+            user_configuration_name: user_configuration_name(),
+            user_configuration_properties: UserConfigurationProperties::XmlData,
+        }
+    }
+
+    /// Parses the category list from a user configuration object.
+    ///
+    /// An object without XML data is treated as an empty list.
+    pub fn from_user_configuration(data: &UserConfigurationData) -> Result<Self, Error> {
+        match data.decoded_xml_data()? {
+            Some(xml) => Self::from_xml(&xml),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Creates a request replacing the category list with this one.
+    ///
+    /// The `CategoryList` object must already exist, which is the case for
+    /// mailboxes that have been opened with Outlook.
+    pub fn update_request(&self) -> Result<UpdateUserConfiguration, Error> {
+        Ok(UpdateUserConfiguration {
+            user_configuration: UserConfiguration::with_xml_data(
+                user_configuration_name(),
+                &self.to_xml()?,
+            ),
+        })
+    }
+}
+
+fn user_configuration_name() -> UserConfigurationName {
+    UserConfigurationName {
+        name: CATEGORY_LIST_NAME.to_string(),
+        folder_id: BaseFolderId::distinguished(DistinguishedFolderName::Calendar),
+    }
+}
+
+fn parse_category(element: &BytesStart) -> Result<Category, Error> {
+    let mut attributes = attributes(element)?;
+
+    let name = take_attribute(&mut attributes, "name")
+        .ok_or(Error::InvalidCategoryList("category without a name"))?;
+    let color = match take_attribute(&mut attributes, "color") {
+        Some(color) => color
+            .parse()
+            .ok()
+            .and_then(CategoryColor::from_index)
+            .ok_or(Error::InvalidCategoryList("invalid category color"))?,
+        None => CategoryColor::None,
+    };
+
+    Ok(Category {
+        name,
+        color,
+        other_attributes: attributes,
+    })
+}
+
+/// Collects the attributes of an element as unescaped name/value pairs.
+fn attributes(element: &BytesStart) -> Result<Vec<(String, String)>, Error> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.map_err(quick_xml::Error::from)?;
+            let name = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+            let value = attribute.unescape_value()?.into_owned();
+
+            Ok((name, value))
+        })
+        .collect()
+}
+
+fn take_attribute(attributes: &mut Vec<(String, String)>, name: &str) -> Option<String> {
+    let index = attributes
+        .iter()
+        .position(|(attribute, _)| attribute == name)?;
+
+    Some(attributes.remove(index).1)
+}
+
+fn push_attributes(element: &mut BytesStart, attributes: &[(String, String)]) {
+    for (name, value) in attributes {
+        element.push_attribute((name.as_str(), value.as_str()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_serialized_content, StringElement};
+
+    /// A synthetic category list in the format Outlook writes, with made-up
+    /// GUIDs and values.
+    const SYNTHETIC_CATEGORY_LIST: &str = "\u{feff}<?xml version=\"1.0\"?><categories default=\"Red category\" lastSavedSession=\"2\" lastSavedTime=\"2024-05-02T09:12:44.000\" xmlns=\"CategoryList.xsd\"><category name=\"Red category\" color=\"0\" keyboardShortcut=\"0\" usageCount=\"3\" guid=\"{5A8CB8E4-0D8E-4D6B-8E2F-B0C1D2E3F405}\" renameOnFirstUse=\"1\"/><category name=\"Blue category\" color=\"7\" keyboardShortcut=\"0\" guid=\"{0B1C2D3E-4F50-6172-8394-A5B6C7D8E9F0}\" renameOnFirstUse=\"1\"/><category name=\"Travel &amp; expenses\" color=\"-1\" keyboardShortcut=\"0\" guid=\"{11111111-2222-3333-4444-555555555555}\"/></categories>";
+
+    #[test]
+    fn parse_category_list() {
+        let list = CategoryList::from_xml(SYNTHETIC_CATEGORY_LIST.as_bytes()).unwrap();
+
+        assert_eq!(list.default_category.as_deref(), Some("Red category"));
+        assert_eq!(
+            list.categories
+                .iter()
+                .map(|category| (category.name.as_str(), category.color))
+                .collect::<Vec<_>>(),
+            [
+                ("Red category", CategoryColor::Red),
+                ("Blue category", CategoryColor::Blue),
+                ("Travel & expenses", CategoryColor::None),
+            ]
+        );
+        assert_eq!(
+            list.categories[1].other_attributes,
+            [
+                ("keyboardShortcut".to_string(), "0".to_string()),
+                (
+                    "guid".to_string(),
+                    "{0B1C2D3E-4F50-6172-8394-A5B6C7D8E9F0}".to_string()
+                ),
+                ("renameOnFirstUse".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn category_list_round_trip() {
+        let mut list = CategoryList::from_xml(SYNTHETIC_CATEGORY_LIST.as_bytes()).unwrap();
+        list.categories
+            .push(Category::new("Follow up", CategoryColor::DarkOrange));
+
+        let xml = String::from_utf8(list.to_xml().unwrap()).unwrap();
+        assert!(
+            xml.ends_with("<category name=\"Travel &amp; expenses\" color=\"-1\" keyboardShortcut=\"0\" guid=\"{11111111-2222-3333-4444-555555555555}\"/><category name=\"Follow up\" color=\"16\"/></categories>"),
+            "got {xml}"
+        );
+
+        assert_eq!(CategoryList::from_xml(xml.as_bytes()).unwrap(), list);
+    }
+
+    #[test]
+    fn serialize_new_category_list() {
+        let list = CategoryList {
+            default_category: None,
+            categories: vec![Category::new("Green category", CategoryColor::Green)],
+            other_attributes: vec![],
+        };
+
+        assert_eq!(
+            String::from_utf8(list.to_xml().unwrap()).unwrap(),
+            r#"<?xml version="1.0"?><categories xmlns="CategoryList.xsd"><category name="Green category" color="4"/></categories>"#
+        );
+    }
+
+    #[test]
+    fn invalid_category_list() {
+        assert!(matches!(
+            CategoryList::from_xml(b"<categories><category color=\"1\"/></categories>"),
+            Err(Error::InvalidCategoryList(_))
+        ));
+        assert!(matches!(
+            CategoryList::from_xml(b"<categories><category name=\"a\" color=\"25\"/></categories>"),
+            Err(Error::InvalidCategoryList(_))
+        ));
+        assert!(matches!(
+            CategoryList::from_xml(b"<?xml version=\"1.0\"?>"),
+            Err(Error::InvalidCategoryList(_))
+        ));
+    }
+
+    #[test]
+    fn message_category_colors() {
+        let list = CategoryList::from_xml(SYNTHETIC_CATEGORY_LIST.as_bytes()).unwrap();
+        let message = Message {
+            categories: Some(vec![
+                StringElement {
+                    string: "blue CATEGORY".to_string(),
+                },
+                StringElement {
+                    string: "Unknown".to_string(),
+                },
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            list.message_categories(&message),
+            [
+                ("blue CATEGORY", CategoryColor::Blue),
+                ("Unknown", CategoryColor::None),
+            ]
+        );
+    }
+
+    #[test]
+    fn category_list_requests() {
+        assert_serialized_content(
+            &CategoryList::get_request(),
+            "GetUserConfiguration",
+            r#"<GetUserConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><UserConfigurationName Name="CategoryList"><t:DistinguishedFolderId Id="calendar"/></UserConfigurationName><UserConfigurationProperties>XmlData</UserConfigurationProperties></GetUserConfiguration>"#,
+        );
+
+        let list = CategoryList {
+            default_category: None,
+            categories: vec![],
+            other_attributes: vec![],
+        };
+        let request = list.update_request().unwrap();
+        let data = UserConfigurationData {
+            item_id: None,
+            xml_data: request.user_configuration.xml_data,
+            binary_data: None,
+        };
+        assert_eq!(CategoryList::from_user_configuration(&data).unwrap(), list);
+    }
+}
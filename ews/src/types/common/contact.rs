@@ -0,0 +1,658 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use super::deserialize_categories;
+
+use crate::{
+    Attachments, Body, DateTime, ExtendedProperty, FolderId, Importance, ItemId, MailboxType,
+    MimeContent, Sensitivity, StringElement,
+};
+
+/// A contact item in a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contact>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Contact {
+    /// The MIME content of the item.
+    #[xml_struct(ns_prefix = "t")]
+    pub mime_content: Option<MimeContent>,
+
+    /// The item's Exchange identifier.
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: Option<ItemId>,
+
+    /// The identifier for the containing folder.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/parentfolderid>
+    #[xml_struct(ns_prefix = "t")]
+    pub parent_folder_id: Option<FolderId>,
+
+    /// The Exchange class value of the item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemclass>
+    #[xml_struct(ns_prefix = "t")]
+    pub item_class: Option<String>,
+
+    /// The subject of the item.
+    ///
+    /// For contacts, Exchange keeps this in sync with the display name.
+    #[xml_struct(ns_prefix = "t")]
+    pub subject: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sensitivity: Option<Sensitivity>,
+
+    /// The notes attached to the contact.
+    #[xml_struct(ns_prefix = "t")]
+    pub body: Option<Body>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub attachments: Option<Attachments>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_received: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub size: Option<usize>,
+
+    /// A list of categories describing an item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/categories-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(default, deserialize_with = "deserialize_categories")]
+    pub categories: Option<Vec<StringElement>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub importance: Option<Importance>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_created: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub has_attachments: Option<bool>,
+
+    // Extended MAPI properties of the contact.
+    #[xml_struct(ns_prefix = "t")]
+    pub extended_property: Option<Vec<ExtendedProperty>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_time: Option<DateTime>,
+
+    /// The name under which the contact is filed.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fileas>
+    #[xml_struct(ns_prefix = "t")]
+    pub file_as: Option<String>,
+
+    /// The name displayed for the contact.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/displayname-string>
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: Option<String>,
+
+    /// The contact's first name.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/givenname>
+    #[xml_struct(ns_prefix = "t")]
+    pub given_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub initials: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub middle_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub nickname: Option<String>,
+
+    /// The contact's full name, broken down into its parts.
+    ///
+    /// This field is read-only and is computed by Exchange from the other name
+    /// fields.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/completename>
+    #[xml_struct(ns_prefix = "t")]
+    pub complete_name: Option<CompleteName>,
+
+    /// The name of the company the contact works for.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/companyname>
+    #[xml_struct(ns_prefix = "t")]
+    pub company_name: Option<String>,
+
+    /// The contact's email addresses, keyed by slot.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/emailaddresses>
+    #[xml_struct(ns_prefix = "t")]
+    pub email_addresses: Option<Dictionary<EmailAddressEntry>>,
+
+    /// The contact's postal addresses, keyed by kind.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/physicaladdresses>
+    #[xml_struct(ns_prefix = "t")]
+    pub physical_addresses: Option<Dictionary<PhysicalAddressEntry>>,
+
+    /// The contact's phone numbers, keyed by kind.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/phonenumbers>
+    #[xml_struct(ns_prefix = "t")]
+    pub phone_numbers: Option<Dictionary<PhoneNumberEntry>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub assistant_name: Option<String>,
+
+    /// The contact's date of birth.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/birthday>
+    #[xml_struct(ns_prefix = "t")]
+    pub birthday: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub business_home_page: Option<String>,
+
+    /// Where the contact's data is stored.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contactsource>
+    #[xml_struct(ns_prefix = "t")]
+    pub contact_source: Option<ContactSource>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub department: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub generation: Option<String>,
+
+    /// The contact's instant messaging addresses, keyed by slot.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/imaddresses>
+    #[xml_struct(ns_prefix = "t")]
+    pub im_addresses: Option<Dictionary<ImAddressEntry>>,
+
+    /// The contact's job title.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/jobtitle>
+    #[xml_struct(ns_prefix = "t")]
+    pub job_title: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub manager: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub office_location: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub profession: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub spouse_name: Option<String>,
+
+    /// The contact's last name.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/surname>
+    #[xml_struct(ns_prefix = "t")]
+    pub surname: Option<String>,
+}
+
+/// The location of a contact's data.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contactsource>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ContactSource {
+    /// The contact is an entry of the directory.
+    ActiveDirectory,
+
+    /// The contact is stored in a mailbox.
+    Store,
+}
+
+/// The full name of a contact, as computed by Exchange.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/completename>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompleteName {
+    #[xml_struct(ns_prefix = "t")]
+    pub title: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub first_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub middle_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub suffix: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub initials: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub full_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub nickname: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub yomi_first_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub yomi_last_name: Option<String>,
+}
+
+/// A collection of keyed entries, as used by contact properties such as email
+/// addresses or phone numbers.
+///
+/// Each entry is represented in XML as a `<t:Entry>` element with a `Key`
+/// attribute identifying its slot. An individual entry can be targeted by
+/// `UpdateItem` with a [`PathToElement::IndexedFieldURI`] whose `field_index`
+/// is the entry's key.
+///
+/// [`PathToElement::IndexedFieldURI`]: crate::PathToElement::IndexedFieldURI
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Dictionary<E> {
+    #[serde(rename = "Entry", default = "Vec::new")]
+    pub entries: Vec<E>,
+}
+
+impl<E> Default for Dictionary<E> {
+    fn default() -> Self {
+        Self { entries: vec![] }
+    }
+}
+
+impl<E> XmlSerialize for Dictionary<E>
+where
+    E: XmlSerialize,
+{
+    /// Serializes each entry as a `<t:Entry>` element.
+    ///
+    /// `xml_struct` serializes the members of a `Vec` using their child nodes
+    /// only, which would drop the `Key` attribute, so the element is written
+    /// explicitly here.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for entry in &self.entries {
+            entry.serialize_as_element(writer, "t:Entry")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An email address of a contact.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-emailaddress>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct EmailAddressEntry {
+    /// The slot this address occupies.
+    #[serde(rename = "@Key")]
+    #[xml_struct(attribute)]
+    pub key: EmailAddressKey,
+
+    /// The display name associated with the address.
+    #[serde(rename = "@Name")]
+    #[xml_struct(attribute)]
+    pub name: Option<String>,
+
+    /// The protocol used in routing to this address.
+    #[serde(rename = "@RoutingType")]
+    #[xml_struct(attribute)]
+    pub routing_type: Option<String>,
+
+    /// The type of mailbox this address represents.
+    #[serde(rename = "@MailboxType")]
+    #[xml_struct(attribute)]
+    pub mailbox_type: Option<MailboxType>,
+
+    /// The email address.
+    #[serde(rename = "$text")]
+    #[xml_struct(flatten)]
+    pub address: String,
+}
+
+/// The slot of a contact's email address.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-emailaddress#attributes>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum EmailAddressKey {
+    EmailAddress1,
+    EmailAddress2,
+    EmailAddress3,
+}
+
+impl EmailAddressKey {
+    /// The key as it appears in the `Key` attribute of an entry and in the
+    /// `FieldIndex` of an indexed field URI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EmailAddress1 => "EmailAddress1",
+            Self::EmailAddress2 => "EmailAddress2",
+            Self::EmailAddress3 => "EmailAddress3",
+        }
+    }
+}
+
+/// A phone number of a contact.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-phonenumber>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct PhoneNumberEntry {
+    /// The kind of phone number.
+    #[serde(rename = "@Key")]
+    #[xml_struct(attribute)]
+    pub key: PhoneNumberKey,
+
+    /// The phone number.
+    #[serde(rename = "$text")]
+    #[xml_struct(flatten)]
+    pub number: String,
+}
+
+/// The kind of a contact's phone number.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-phonenumber#attributes>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PhoneNumberKey {
+    AssistantPhone,
+    BusinessFax,
+    BusinessPhone,
+    BusinessPhone2,
+    Callback,
+    CarPhone,
+    CompanyMainPhone,
+    HomeFax,
+    HomePhone,
+    HomePhone2,
+    Isdn,
+    MobilePhone,
+    OtherFax,
+    OtherTelephone,
+    Pager,
+    PrimaryPhone,
+    RadioPhone,
+    Telex,
+    TtyTddPhone,
+}
+
+impl PhoneNumberKey {
+    /// The key as it appears in the `Key` attribute of an entry and in the
+    /// `FieldIndex` of an indexed field URI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AssistantPhone => "AssistantPhone",
+            Self::BusinessFax => "BusinessFax",
+            Self::BusinessPhone => "BusinessPhone",
+            Self::BusinessPhone2 => "BusinessPhone2",
+            Self::Callback => "Callback",
+            Self::CarPhone => "CarPhone",
+            Self::CompanyMainPhone => "CompanyMainPhone",
+            Self::HomeFax => "HomeFax",
+            Self::HomePhone => "HomePhone",
+            Self::HomePhone2 => "HomePhone2",
+            Self::Isdn => "Isdn",
+            Self::MobilePhone => "MobilePhone",
+            Self::OtherFax => "OtherFax",
+            Self::OtherTelephone => "OtherTelephone",
+            Self::Pager => "Pager",
+            Self::PrimaryPhone => "PrimaryPhone",
+            Self::RadioPhone => "RadioPhone",
+            Self::Telex => "Telex",
+            Self::TtyTddPhone => "TtyTddPhone",
+        }
+    }
+}
+
+/// A postal address of a contact.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-physicaladdress>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PhysicalAddressEntry {
+    /// The kind of address.
+    #[serde(rename = "@Key")]
+    #[xml_struct(attribute)]
+    pub key: PhysicalAddressKey,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub street: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub city: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub state: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub country_or_region: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub postal_code: Option<String>,
+}
+
+/// The kind of a contact's postal address.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-physicaladdress#attributes>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PhysicalAddressKey {
+    Home,
+    Business,
+    Other,
+}
+
+impl PhysicalAddressKey {
+    /// The key as it appears in the `Key` attribute of an entry and in the
+    /// `FieldIndex` of an indexed field URI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Home => "Home",
+            Self::Business => "Business",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// An instant messaging address of a contact.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-imaddress>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct ImAddressEntry {
+    /// The slot this address occupies.
+    #[serde(rename = "@Key")]
+    #[xml_struct(attribute)]
+    pub key: ImAddressKey,
+
+    /// The instant messaging address.
+    #[serde(rename = "$text")]
+    #[xml_struct(flatten)]
+    pub address: String,
+}
+
+/// The slot of a contact's instant messaging address.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/entry-imaddress#attributes>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ImAddressKey {
+    ImAddress1,
+    ImAddress2,
+    ImAddress3,
+}
+
+impl ImAddressKey {
+    /// The key as it appears in the `Key` attribute of an entry and in the
+    /// `FieldIndex` of an indexed field URI.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ImAddress1 => "ImAddress1",
+            Self::ImAddress2 => "ImAddress2",
+            Self::ImAddress3 => "ImAddress3",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_deserialized_content, assert_serialized_content};
+
+    #[test]
+    fn serialize_contact_dictionaries() {
+        let contact = Contact {
+            given_name: Some("Alice".to_string()),
+            email_addresses: Some(Dictionary {
+                entries: vec![EmailAddressEntry {
+                    key: EmailAddressKey::EmailAddress1,
+                    name: None,
+                    routing_type: None,
+                    mailbox_type: None,
+                    address: "alice@example.com".to_string(),
+                }],
+            }),
+            physical_addresses: Some(Dictionary {
+                entries: vec![PhysicalAddressEntry {
+                    key: PhysicalAddressKey::Business,
+                    street: Some("1 Main St".to_string()),
+                    city: Some("Springfield".to_string()),
+                    state: None,
+                    country_or_region: None,
+                    postal_code: None,
+                }],
+            }),
+            phone_numbers: Some(Dictionary {
+                entries: vec![PhoneNumberEntry {
+                    key: PhoneNumberKey::MobilePhone,
+                    number: "555-0100".to_string(),
+                }],
+            }),
+            ..Default::default()
+        };
+
+        let expected = r#"<t:Contact><t:GivenName>Alice</t:GivenName><t:EmailAddresses><t:Entry Key="EmailAddress1">alice@example.com</t:Entry></t:EmailAddresses><t:PhysicalAddresses><t:Entry Key="Business"><t:Street>1 Main St</t:Street><t:City>Springfield</t:City></t:Entry></t:PhysicalAddresses><t:PhoneNumbers><t:Entry Key="MobilePhone">555-0100</t:Entry></t:PhoneNumbers></t:Contact>"#;
+
+        assert_serialized_content(&contact, "t:Contact", expected);
+    }
+
+    #[test]
+    fn deserialize_contact() {
+        let content = r#"<t:Contact xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <t:ItemId Id="AAMkADk=" ChangeKey="EQAAABYA" />
+                <t:Subject>Alice Test</t:Subject>
+                <t:DisplayName>Alice Test</t:DisplayName>
+                <t:GivenName>Alice</t:GivenName>
+                <t:CompleteName>
+                    <t:FirstName>Alice</t:FirstName>
+                    <t:LastName>Test</t:LastName>
+                    <t:FullName>Alice Test</t:FullName>
+                </t:CompleteName>
+                <t:CompanyName>Example Corp</t:CompanyName>
+                <t:EmailAddresses>
+                    <t:Entry Key="EmailAddress1" Name="Alice Test" RoutingType="SMTP" MailboxType="Mailbox">alice@example.com</t:Entry>
+                    <t:Entry Key="EmailAddress2">alice@example.org</t:Entry>
+                </t:EmailAddresses>
+                <t:PhysicalAddresses>
+                    <t:Entry Key="Home">
+                        <t:Street>1 Main St</t:Street>
+                        <t:City>Springfield</t:City>
+                        <t:PostalCode>12345</t:PostalCode>
+                    </t:Entry>
+                </t:PhysicalAddresses>
+                <t:PhoneNumbers>
+                    <t:Entry Key="BusinessPhone">555-0100</t:Entry>
+                    <t:Entry Key="MobilePhone">555-0101</t:Entry>
+                </t:PhoneNumbers>
+                <t:ImAddresses>
+                    <t:Entry Key="ImAddress1">alice@im.example.com</t:Entry>
+                </t:ImAddresses>
+                <t:JobTitle>Engineer</t:JobTitle>
+                <t:Surname>Test</t:Surname>
+            </t:Contact>"#;
+
+        let expected = Contact {
+            item_id: Some(ItemId {
+                id: "AAMkADk=".to_string(),
+                change_key: Some("EQAAABYA".to_string()),
+            }),
+            subject: Some("Alice Test".to_string()),
+            display_name: Some("Alice Test".to_string()),
+            given_name: Some("Alice".to_string()),
+            complete_name: Some(CompleteName {
+                first_name: Some("Alice".to_string()),
+                last_name: Some("Test".to_string()),
+                full_name: Some("Alice Test".to_string()),
+                ..Default::default()
+            }),
+            company_name: Some("Example Corp".to_string()),
+            email_addresses: Some(Dictionary {
+                entries: vec![
+                    EmailAddressEntry {
+                        key: EmailAddressKey::EmailAddress1,
+                        name: Some("Alice Test".to_string()),
+                        routing_type: Some("SMTP".to_string()),
+                        mailbox_type: Some(MailboxType::Mailbox),
+                        address: "alice@example.com".to_string(),
+                    },
+                    EmailAddressEntry {
+                        key: EmailAddressKey::EmailAddress2,
+                        name: None,
+                        routing_type: None,
+                        mailbox_type: None,
+                        address: "alice@example.org".to_string(),
+                    },
+                ],
+            }),
+            physical_addresses: Some(Dictionary {
+                entries: vec![PhysicalAddressEntry {
+                    key: PhysicalAddressKey::Home,
+                    street: Some("1 Main St".to_string()),
+                    city: Some("Springfield".to_string()),
+                    state: None,
+                    country_or_region: None,
+                    postal_code: Some("12345".to_string()),
+                }],
+            }),
+            phone_numbers: Some(Dictionary {
+                entries: vec![
+                    PhoneNumberEntry {
+                        key: PhoneNumberKey::BusinessPhone,
+                        number: "555-0100".to_string(),
+                    },
+                    PhoneNumberEntry {
+                        key: PhoneNumberKey::MobilePhone,
+                        number: "555-0101".to_string(),
+                    },
+                ],
+            }),
+            im_addresses: Some(Dictionary {
+                entries: vec![ImAddressEntry {
+                    key: ImAddressKey::ImAddress1,
+                    address: "alice@im.example.com".to_string(),
+                }],
+            }),
+            job_title: Some("Engineer".to_string()),
+            surname: Some("Test".to_string()),
+            ..Default::default()
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
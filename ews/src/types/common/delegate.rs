@@ -0,0 +1,179 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Delegates, which are users allowed to act on behalf of a mailbox, e.g. to
+//! manage its calendar.
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ResponseMessages, UserId};
+
+/// A delegate of a mailbox and the permissions it was granted.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateuser>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateUser {
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub delegate_permissions: Option<DelegatePermissions>,
+
+    /// Whether the delegate receives copies of meeting-related messages sent
+    /// to the mailbox.
+    #[xml_struct(ns_prefix = "t")]
+    pub receive_copies_of_meeting_messages: Option<bool>,
+
+    /// Whether the delegate can view items marked as private.
+    #[xml_struct(ns_prefix = "t")]
+    pub view_private_items: Option<bool>,
+}
+
+impl DelegateUser {
+    /// Creates a delegate with the given permissions and default options.
+    pub fn new(user_id: UserId, delegate_permissions: DelegatePermissions) -> Self {
+        Self {
+            user_id,
+            delegate_permissions: Some(delegate_permissions),
+            receive_copies_of_meeting_messages: None,
+            view_private_items: None,
+        }
+    }
+}
+
+/// The permissions of a delegate on each of the default folders of a mailbox.
+///
+/// Folders which aren't set are left unchanged when updating a delegate.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegatepermissions>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegatePermissions {
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub tasks_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub inbox_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contacts_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub notes_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub journal_folder_permission_level: Option<DelegateFolderPermissionLevel>,
+}
+
+/// The access a delegate has to a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarfolderpermissionlevel>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DelegateFolderPermissionLevel {
+    None,
+
+    /// Can read, create and modify items.
+    Editor,
+
+    /// Can read items.
+    Reviewer,
+
+    /// Can read and create items.
+    Author,
+
+    /// Permissions which don't match any of the other levels, e.g. set by a
+    /// client. Can't be used in a request.
+    Custom,
+}
+
+/// Who receives meeting requests sent to a mailbox with delegates.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delivermeetingrequests>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum DeliverMeetingRequests {
+    DelegatesOnly,
+    DelegatesAndMe,
+
+    /// The delegates receive meeting requests, and the owner of the mailbox
+    /// receives a notice for each.
+    DelegatesAndSendInformationToMe,
+
+    /// Only meaningful when the mailbox has no delegates.
+    NoForward,
+}
+
+/// A list of delegates to add or update.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateusers>
+#[derive(Clone, Debug, Default)]
+pub struct DelegateUsers(pub Vec<DelegateUser>);
+
+impl XmlSerialize for DelegateUsers {
+    /// Serializes each delegate as a `<t:DelegateUser>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for delegate_user in &self.0 {
+            delegate_user.serialize_as_element(writer, "t:DelegateUser")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of users to look up or remove as delegates.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userids>
+#[derive(Clone, Debug, Default)]
+pub struct UserIds(pub Vec<UserId>);
+
+impl XmlSerialize for UserIds {
+    /// Serializes each user as a `<t:UserId>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for user_id in &self.0 {
+            user_id.serialize_as_element(writer, "t:UserId")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The common result of the delegate operations, with one response message
+/// per delegate in the same order as in the request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/adddelegateresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateResponseMessage {
+    #[serde(default)]
+    pub response_messages: ResponseMessages<DelegateUserResponseMessage>,
+}
+
+/// The result of a delegate operation for a single delegate.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/delegateuserresponsemessagetype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DelegateUserResponseMessage {
+    /// The delegate, as stored by the server. Not included in responses to
+    /// `RemoveDelegate`.
+    pub delegate_user: Option<DelegateUser>,
+}
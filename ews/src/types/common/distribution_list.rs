@@ -0,0 +1,273 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use super::deserialize_categories;
+
+use crate::{Body, DateTime, ExtendedProperty, FolderId, ItemId, Mailbox, StringElement};
+
+/// A personal distribution list, also known as a contact group.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/distributionlist>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DistributionList {
+    /// The item's Exchange identifier.
+    #[xml_struct(ns_prefix = "t")]
+    pub item_id: Option<ItemId>,
+
+    /// The identifier for the containing folder.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/parentfolderid>
+    #[xml_struct(ns_prefix = "t")]
+    pub parent_folder_id: Option<FolderId>,
+
+    /// The Exchange class value of the item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemclass>
+    #[xml_struct(ns_prefix = "t")]
+    pub item_class: Option<String>,
+
+    /// The subject of the item.
+    #[xml_struct(ns_prefix = "t")]
+    pub subject: Option<String>,
+
+    /// The notes attached to the list.
+    #[xml_struct(ns_prefix = "t")]
+    pub body: Option<Body>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_received: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub size: Option<usize>,
+
+    /// A list of categories describing an item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/categories-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    #[serde(default, deserialize_with = "deserialize_categories")]
+    pub categories: Option<Vec<StringElement>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub date_time_created: Option<DateTime>,
+
+    // Extended MAPI properties of the list.
+    #[xml_struct(ns_prefix = "t")]
+    pub extended_property: Option<Vec<ExtendedProperty>>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_name: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub last_modified_time: Option<DateTime>,
+
+    /// The name displayed for the list.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/displayname-string>
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: Option<String>,
+
+    /// The name under which the list is filed.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fileas>
+    #[xml_struct(ns_prefix = "t")]
+    pub file_as: Option<String>,
+
+    /// The members of the list.
+    ///
+    /// Members are only returned when requesting this item with `GetItem`, and
+    /// are omitted from `FindItem` responses.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/members-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    pub members: Option<Members>,
+}
+
+/// A collection of members of a distribution list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/members-ex15websvcsotherref>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Members {
+    #[serde(rename = "Member", default)]
+    pub inner: Vec<Member>,
+}
+
+impl XmlSerialize for Members {
+    /// Serializes each member as a `<t:Member>` element, so that its `Key`
+    /// attribute is preserved.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for member in &self.inner {
+            member.serialize_as_element(writer, "t:Member")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single member of a distribution list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/member-ex15websvcsotherref>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Member {
+    /// An opaque identifier for the member within the list.
+    ///
+    /// This is set by Exchange and is used to remove a member from the list.
+    #[serde(rename = "@Key")]
+    #[xml_struct(attribute)]
+    pub key: Option<String>,
+
+    /// The mailbox of the member.
+    ///
+    /// Nested lists are represented by a mailbox with a [`MailboxType`] of
+    /// [`PrivateDL`] or [`PublicDL`].
+    ///
+    /// [`MailboxType`]: crate::MailboxType
+    /// [`PrivateDL`]: crate::MailboxType::PrivateDL
+    /// [`PublicDL`]: crate::MailboxType::PublicDL
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: Mailbox,
+
+    /// The status of the member.
+    #[xml_struct(ns_prefix = "t")]
+    pub status: Option<MemberStatus>,
+}
+
+/// The status of a member of a distribution list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/status-member>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum MemberStatus {
+    /// The member's status could not be determined.
+    Unrecognized,
+
+    /// The member is a valid member of the list.
+    Normal,
+
+    /// The member has been demoted, e.g. because its contact was deleted.
+    Demoted,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        MailboxType,
+    };
+
+    #[test]
+    fn serialize_distribution_list() {
+        let list = DistributionList {
+            display_name: Some("Team".to_string()),
+            members: Some(Members {
+                inner: vec![
+                    Member {
+                        key: None,
+                        mailbox: Mailbox {
+                            email_address: Some("alice@example.com".to_string()),
+                            ..Default::default()
+                        },
+                        status: None,
+                    },
+                    Member {
+                        key: None,
+                        mailbox: Mailbox {
+                            mailbox_type: Some(MailboxType::PrivateDL),
+                            item_id: Some(ItemId {
+                                id: "AAMkADl=".to_string(),
+                                change_key: None,
+                            }),
+                            ..Default::default()
+                        },
+                        status: None,
+                    },
+                ],
+            }),
+            ..Default::default()
+        };
+
+        let expected = r#"<t:DistributionList><t:DisplayName>Team</t:DisplayName><t:Members><t:Member><t:Mailbox><t:EmailAddress>alice@example.com</t:EmailAddress></t:Mailbox></t:Member><t:Member><t:Mailbox><t:MailboxType>PrivateDL</t:MailboxType><t:ItemId Id="AAMkADl="/></t:Mailbox></t:Member></t:Members></t:DistributionList>"#;
+
+        assert_serialized_content(&list, "t:DistributionList", expected);
+    }
+
+    #[test]
+    fn deserialize_distribution_list() {
+        let content = r#"<t:DistributionList xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <t:ItemId Id="AAMkADl=" ChangeKey="EgAAABYA" />
+                <t:ItemClass>IPM.DistList</t:ItemClass>
+                <t:DisplayName>Team</t:DisplayName>
+                <t:FileAs>Team</t:FileAs>
+                <t:Members>
+                    <t:Member Key="AAAAAIErH6S+">
+                        <t:Mailbox>
+                            <t:Name>Alice Test</t:Name>
+                            <t:EmailAddress>alice@example.com</t:EmailAddress>
+                            <t:RoutingType>SMTP</t:RoutingType>
+                            <t:MailboxType>OneOff</t:MailboxType>
+                        </t:Mailbox>
+                        <t:Status>Normal</t:Status>
+                    </t:Member>
+                    <t:Member Key="AAAAAIErH6T+">
+                        <t:Mailbox>
+                            <t:Name>Sub Team</t:Name>
+                            <t:MailboxType>PublicDL</t:MailboxType>
+                            <t:EmailAddress>subteam@example.com</t:EmailAddress>
+                        </t:Mailbox>
+                        <t:Status>Demoted</t:Status>
+                    </t:Member>
+                </t:Members>
+            </t:DistributionList>"#;
+
+        let expected = DistributionList {
+            item_id: Some(ItemId {
+                id: "AAMkADl=".to_string(),
+                change_key: Some("EgAAABYA".to_string()),
+            }),
+            item_class: Some("IPM.DistList".to_string()),
+            display_name: Some("Team".to_string()),
+            file_as: Some("Team".to_string()),
+            members: Some(Members {
+                inner: vec![
+                    Member {
+                        key: Some("AAAAAIErH6S+".to_string()),
+                        mailbox: Mailbox {
+                            name: Some("Alice Test".to_string()),
+                            email_address: Some("alice@example.com".to_string()),
+                            routing_type: Some("SMTP".to_string()),
+                            mailbox_type: Some(MailboxType::OneOff),
+                            item_id: None,
+                        },
+                        status: Some(MemberStatus::Normal),
+                    },
+                    Member {
+                        key: Some("AAAAAIErH6T+".to_string()),
+                        mailbox: Mailbox {
+                            name: Some("Sub Team".to_string()),
+                            email_address: Some("subteam@example.com".to_string()),
+                            routing_type: None,
+                            mailbox_type: Some(MailboxType::PublicDL),
+                            item_id: None,
+                        },
+                        status: Some(MemberStatus::Demoted),
+                    },
+                ],
+            }),
+            ..Default::default()
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
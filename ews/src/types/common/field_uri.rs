@@ -0,0 +1,418 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Well-known identifiers for properties of Exchange entities.
+
+use super::string_enum::string_enum;
+
+string_enum! {
+    /// A property identified by a well-known string, for use in
+    /// [`PathToElement::FieldURI`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fielduri>
+    ///
+    /// [`PathToElement::FieldURI`]: crate::PathToElement::FieldURI
+    pub enum UnindexedFieldURI {
+        // folder:*
+        FolderFolderId => "folder:FolderId",
+        FolderParentFolderId => "folder:ParentFolderId",
+        FolderDisplayName => "folder:DisplayName",
+        FolderUnreadCount => "folder:UnreadCount",
+        FolderTotalCount => "folder:TotalCount",
+        FolderChildFolderCount => "folder:ChildFolderCount",
+        FolderFolderClass => "folder:FolderClass",
+        FolderSearchParameters => "folder:SearchParameters",
+        FolderManagedFolderInformation => "folder:ManagedFolderInformation",
+        FolderPermissionSet => "folder:PermissionSet",
+        FolderEffectiveRights => "folder:EffectiveRights",
+        FolderSharingEffectiveRights => "folder:SharingEffectiveRights",
+        FolderDistinguishedFolderId => "folder:DistinguishedFolderId",
+        FolderPolicyTag => "folder:PolicyTag",
+        FolderArchiveTag => "folder:ArchiveTag",
+        FolderReplicaList => "folder:ReplicaList",
+        // item:*
+        ItemItemId => "item:ItemId",
+        ItemParentFolderId => "item:ParentFolderId",
+        ItemItemClass => "item:ItemClass",
+        ItemMimeContent => "item:MimeContent",
+        ItemMimeContentUTF8 => "item:MimeContentUTF8",
+        ItemAttachments => "item:Attachments",
+        ItemSubject => "item:Subject",
+        ItemDateTimeReceived => "item:DateTimeReceived",
+        ItemSize => "item:Size",
+        ItemCategories => "item:Categories",
+        ItemHasAttachments => "item:HasAttachments",
+        ItemImportance => "item:Importance",
+        ItemInReplyTo => "item:InReplyTo",
+        ItemInternetMessageHeaders => "item:InternetMessageHeaders",
+        ItemIsAssociated => "item:IsAssociated",
+        ItemIsDraft => "item:IsDraft",
+        ItemIsFromMe => "item:IsFromMe",
+        ItemIsResend => "item:IsResend",
+        ItemIsSubmitted => "item:IsSubmitted",
+        ItemIsUnmodified => "item:IsUnmodified",
+        ItemDateTimeSent => "item:DateTimeSent",
+        ItemDateTimeCreated => "item:DateTimeCreated",
+        ItemBody => "item:Body",
+        ItemResponseObjects => "item:ResponseObjects",
+        ItemSensitivity => "item:Sensitivity",
+        ItemReminderDueBy => "item:ReminderDueBy",
+        ItemReminderIsSet => "item:ReminderIsSet",
+        ItemReminderNextTime => "item:ReminderNextTime",
+        ItemReminderMinutesBeforeStart => "item:ReminderMinutesBeforeStart",
+        ItemDisplayTo => "item:DisplayTo",
+        ItemDisplayCc => "item:DisplayCc",
+        ItemCulture => "item:Culture",
+        ItemEffectiveRights => "item:EffectiveRights",
+        ItemLastModifiedName => "item:LastModifiedName",
+        ItemLastModifiedTime => "item:LastModifiedTime",
+        ItemConversationId => "item:ConversationId",
+        ItemUniqueBody => "item:UniqueBody",
+        ItemFlag => "item:Flag",
+        ItemStoreEntryId => "item:StoreEntryId",
+        ItemInstanceKey => "item:InstanceKey",
+        ItemNormalizedBody => "item:NormalizedBody",
+        ItemEntityExtractionResult => "item:EntityExtractionResult",
+        ItemPolicyTag => "item:PolicyTag",
+        ItemArchiveTag => "item:ArchiveTag",
+        ItemRetentionDate => "item:RetentionDate",
+        ItemPreview => "item:Preview",
+        ItemNextPredictedAction => "item:NextPredictedAction",
+        ItemGroupingAction => "item:GroupingAction",
+        ItemPredictedActionReasons => "item:PredictedActionReasons",
+        ItemIsClutter => "item:IsClutter",
+        ItemRightsManagementLicenseData => "item:RightsManagementLicenseData",
+        ItemBlockStatus => "item:BlockStatus",
+        ItemHasBlockedImages => "item:HasBlockedImages",
+        ItemWebClientReadFormQueryString => "item:WebClientReadFormQueryString",
+        ItemWebClientEditFormQueryString => "item:WebClientEditFormQueryString",
+        ItemTextBody => "item:TextBody",
+        ItemIconIndex => "item:IconIndex",
+        // message:*
+        MessageConversationIndex => "message:ConversationIndex",
+        MessageConversationTopic => "message:ConversationTopic",
+        MessageInternetMessageId => "message:InternetMessageId",
+        MessageIsRead => "message:IsRead",
+        MessageIsResponseRequested => "message:IsResponseRequested",
+        MessageIsReadReceiptRequested => "message:IsReadReceiptRequested",
+        MessageIsDeliveryReceiptRequested => "message:IsDeliveryReceiptRequested",
+        MessageReceivedBy => "message:ReceivedBy",
+        MessageReceivedRepresenting => "message:ReceivedRepresenting",
+        MessageReferences => "message:References",
+        MessageReplyTo => "message:ReplyTo",
+        MessageFrom => "message:From",
+        MessageSender => "message:Sender",
+        MessageToRecipients => "message:ToRecipients",
+        MessageCcRecipients => "message:CcRecipients",
+        MessageBccRecipients => "message:BccRecipients",
+        MessageApprovalRequestData => "message:ApprovalRequestData",
+        MessageVotingInformation => "message:VotingInformation",
+        MessageReminderMessageData => "message:ReminderMessageData",
+        // meeting:*
+        MeetingAssociatedCalendarItemId => "meeting:AssociatedCalendarItemId",
+        MeetingIsDelegated => "meeting:IsDelegated",
+        MeetingIsOutOfDate => "meeting:IsOutOfDate",
+        MeetingHasBeenProcessed => "meeting:HasBeenProcessed",
+        MeetingResponseType => "meeting:ResponseType",
+        MeetingProposedStart => "meeting:ProposedStart",
+        MeetingProposedEnd => "meeting:ProposedEnd",
+        // meetingRequest:*
+        MeetingRequestMeetingRequestType => "meetingRequest:MeetingRequestType",
+        MeetingRequestIntendedFreeBusyStatus => "meetingRequest:IntendedFreeBusyStatus",
+        MeetingRequestChangeHighlights => "meetingRequest:ChangeHighlights",
+        // calendar:*
+        CalendarStart => "calendar:Start",
+        CalendarEnd => "calendar:End",
+        CalendarOriginalStart => "calendar:OriginalStart",
+        CalendarStartWallClock => "calendar:StartWallClock",
+        CalendarEndWallClock => "calendar:EndWallClock",
+        CalendarStartTimeZoneId => "calendar:StartTimeZoneId",
+        CalendarEndTimeZoneId => "calendar:EndTimeZoneId",
+        CalendarIsAllDayEvent => "calendar:IsAllDayEvent",
+        CalendarLegacyFreeBusyStatus => "calendar:LegacyFreeBusyStatus",
+        CalendarLocation => "calendar:Location",
+        CalendarEnhancedLocation => "calendar:EnhancedLocation",
+        CalendarWhen => "calendar:When",
+        CalendarIsMeeting => "calendar:IsMeeting",
+        CalendarIsCancelled => "calendar:IsCancelled",
+        CalendarIsRecurring => "calendar:IsRecurring",
+        CalendarMeetingRequestWasSent => "calendar:MeetingRequestWasSent",
+        CalendarIsResponseRequested => "calendar:IsResponseRequested",
+        CalendarCalendarItemType => "calendar:CalendarItemType",
+        CalendarMyResponseType => "calendar:MyResponseType",
+        CalendarOrganizer => "calendar:Organizer",
+        CalendarRequiredAttendees => "calendar:RequiredAttendees",
+        CalendarOptionalAttendees => "calendar:OptionalAttendees",
+        CalendarResources => "calendar:Resources",
+        CalendarConflictingMeetingCount => "calendar:ConflictingMeetingCount",
+        CalendarAdjacentMeetingCount => "calendar:AdjacentMeetingCount",
+        CalendarConflictingMeetings => "calendar:ConflictingMeetings",
+        CalendarAdjacentMeetings => "calendar:AdjacentMeetings",
+        CalendarDuration => "calendar:Duration",
+        CalendarTimeZone => "calendar:TimeZone",
+        CalendarAppointmentReplyTime => "calendar:AppointmentReplyTime",
+        CalendarAppointmentSequenceNumber => "calendar:AppointmentSequenceNumber",
+        CalendarAppointmentState => "calendar:AppointmentState",
+        CalendarRecurrence => "calendar:Recurrence",
+        CalendarFirstOccurrence => "calendar:FirstOccurrence",
+        CalendarLastOccurrence => "calendar:LastOccurrence",
+        CalendarModifiedOccurrences => "calendar:ModifiedOccurrences",
+        CalendarDeletedOccurrences => "calendar:DeletedOccurrences",
+        CalendarMeetingTimeZone => "calendar:MeetingTimeZone",
+        CalendarStartTimeZone => "calendar:StartTimeZone",
+        CalendarEndTimeZone => "calendar:EndTimeZone",
+        CalendarConferenceType => "calendar:ConferenceType",
+        CalendarAllowNewTimeProposal => "calendar:AllowNewTimeProposal",
+        CalendarIsOnlineMeeting => "calendar:IsOnlineMeeting",
+        CalendarMeetingWorkspaceUrl => "calendar:MeetingWorkspaceUrl",
+        CalendarNetShowUrl => "calendar:NetShowUrl",
+        CalendarUID => "calendar:UID",
+        CalendarRecurrenceId => "calendar:RecurrenceId",
+        CalendarDateTimeStamp => "calendar:DateTimeStamp",
+        CalendarIsOrganizer => "calendar:IsOrganizer",
+        // task:*
+        TaskActualWork => "task:ActualWork",
+        TaskAssignedTime => "task:AssignedTime",
+        TaskBillingInformation => "task:BillingInformation",
+        TaskChangeCount => "task:ChangeCount",
+        TaskCompanies => "task:Companies",
+        TaskCompleteDate => "task:CompleteDate",
+        TaskContacts => "task:Contacts",
+        TaskDelegationState => "task:DelegationState",
+        TaskDelegator => "task:Delegator",
+        TaskDueDate => "task:DueDate",
+        TaskIsAssignmentEditable => "task:IsAssignmentEditable",
+        TaskIsComplete => "task:IsComplete",
+        TaskIsRecurring => "task:IsRecurring",
+        TaskIsTeamTask => "task:IsTeamTask",
+        TaskMileage => "task:Mileage",
+        TaskOwner => "task:Owner",
+        TaskPercentComplete => "task:PercentComplete",
+        TaskRecurrence => "task:Recurrence",
+        TaskStartDate => "task:StartDate",
+        TaskStatus => "task:Status",
+        TaskStatusDescription => "task:StatusDescription",
+        TaskTotalWork => "task:TotalWork",
+        // contacts:*
+        ContactsAlias => "contacts:Alias",
+        ContactsAssistantName => "contacts:AssistantName",
+        ContactsBirthday => "contacts:Birthday",
+        ContactsBusinessHomePage => "contacts:BusinessHomePage",
+        ContactsChildren => "contacts:Children",
+        ContactsCompanies => "contacts:Companies",
+        ContactsCompanyName => "contacts:CompanyName",
+        ContactsCompleteName => "contacts:CompleteName",
+        ContactsContactSource => "contacts:ContactSource",
+        ContactsCulture => "contacts:Culture",
+        ContactsDepartment => "contacts:Department",
+        ContactsDisplayName => "contacts:DisplayName",
+        ContactsDirectoryId => "contacts:DirectoryId",
+        ContactsDirectReports => "contacts:DirectReports",
+        ContactsEmailAddresses => "contacts:EmailAddresses",
+        ContactsFileAs => "contacts:FileAs",
+        ContactsFileAsMapping => "contacts:FileAsMapping",
+        ContactsGeneration => "contacts:Generation",
+        ContactsGivenName => "contacts:GivenName",
+        ContactsImAddresses => "contacts:ImAddresses",
+        ContactsInitials => "contacts:Initials",
+        ContactsJobTitle => "contacts:JobTitle",
+        ContactsManager => "contacts:Manager",
+        ContactsManagerMailbox => "contacts:ManagerMailbox",
+        ContactsMiddleName => "contacts:MiddleName",
+        ContactsMileage => "contacts:Mileage",
+        ContactsMSExchangeCertificate => "contacts:MSExchangeCertificate",
+        ContactsNickname => "contacts:Nickname",
+        ContactsNotes => "contacts:Notes",
+        ContactsOfficeLocation => "contacts:OfficeLocation",
+        ContactsPhoneNumbers => "contacts:PhoneNumbers",
+        ContactsPhoneticFullName => "contacts:PhoneticFullName",
+        ContactsPhoneticFirstName => "contacts:PhoneticFirstName",
+        ContactsPhoneticLastName => "contacts:PhoneticLastName",
+        ContactsPhoto => "contacts:Photo",
+        ContactsPhysicalAddresses => "contacts:PhysicalAddresses",
+        ContactsPostalAddressIndex => "contacts:PostalAddressIndex",
+        ContactsProfession => "contacts:Profession",
+        ContactsSpouseName => "contacts:SpouseName",
+        ContactsSurname => "contacts:Surname",
+        ContactsWeddingAnniversary => "contacts:WeddingAnniversary",
+        ContactsUserSMIMECertificate => "contacts:UserSMIMECertificate",
+        ContactsHasPicture => "contacts:HasPicture",
+        // distributionlist:*
+        DistributionListMembers => "distributionlist:Members",
+        // postitem:*
+        PostItemPostedTime => "postitem:PostedTime",
+        // conversation:*
+        ConversationConversationId => "conversation:ConversationId",
+        ConversationConversationTopic => "conversation:ConversationTopic",
+        ConversationUniqueRecipients => "conversation:UniqueRecipients",
+        ConversationGlobalUniqueRecipients => "conversation:GlobalUniqueRecipients",
+        ConversationUniqueUnreadSenders => "conversation:UniqueUnreadSenders",
+        ConversationGlobalUniqueUnreadSenders => "conversation:GlobalUniqueUnreadSenders",
+        ConversationUniqueSenders => "conversation:UniqueSenders",
+        ConversationGlobalUniqueSenders => "conversation:GlobalUniqueSenders",
+        ConversationLastDeliveryTime => "conversation:LastDeliveryTime",
+        ConversationGlobalLastDeliveryTime => "conversation:GlobalLastDeliveryTime",
+        ConversationCategories => "conversation:Categories",
+        ConversationGlobalCategories => "conversation:GlobalCategories",
+        ConversationFlagStatus => "conversation:FlagStatus",
+        ConversationGlobalFlagStatus => "conversation:GlobalFlagStatus",
+        ConversationHasAttachments => "conversation:HasAttachments",
+        ConversationGlobalHasAttachments => "conversation:GlobalHasAttachments",
+        ConversationHasIrm => "conversation:HasIrm",
+        ConversationGlobalHasIrm => "conversation:GlobalHasIrm",
+        ConversationMessageCount => "conversation:MessageCount",
+        ConversationGlobalMessageCount => "conversation:GlobalMessageCount",
+        ConversationUnreadCount => "conversation:UnreadCount",
+        ConversationGlobalUnreadCount => "conversation:GlobalUnreadCount",
+        ConversationSize => "conversation:Size",
+        ConversationGlobalSize => "conversation:GlobalSize",
+        ConversationItemClasses => "conversation:ItemClasses",
+        ConversationGlobalItemClasses => "conversation:GlobalItemClasses",
+        ConversationImportance => "conversation:Importance",
+        ConversationGlobalImportance => "conversation:GlobalImportance",
+        ConversationItemIds => "conversation:ItemIds",
+        ConversationGlobalItemIds => "conversation:GlobalItemIds",
+        ConversationLastModifiedTime => "conversation:LastModifiedTime",
+        ConversationInstanceKey => "conversation:InstanceKey",
+        ConversationPreview => "conversation:Preview",
+        ConversationGlobalParentFolderId => "conversation:GlobalParentFolderId",
+        ConversationNextPredictedAction => "conversation:NextPredictedAction",
+        ConversationGroupingAction => "conversation:GroupingAction",
+        ConversationIconIndex => "conversation:IconIndex",
+        ConversationGlobalIconIndex => "conversation:GlobalIconIndex",
+        ConversationDraftItemIds => "conversation:DraftItemIds",
+        ConversationHasClutter => "conversation:HasClutter",
+        // persona:*
+        PersonaPersonaId => "persona:PersonaId",
+        PersonaPersonaType => "persona:PersonaType",
+        PersonaGivenName => "persona:GivenName",
+        PersonaCompanyName => "persona:CompanyName",
+        PersonaSurname => "persona:Surname",
+        PersonaDisplayName => "persona:DisplayName",
+        PersonaEmailAddress => "persona:EmailAddress",
+        PersonaFileAs => "persona:FileAs",
+        PersonaHomeCity => "persona:HomeCity",
+        PersonaCreationTime => "persona:CreationTime",
+        PersonaRelevanceScore => "persona:RelevanceScore",
+        PersonaWorkCity => "persona:WorkCity",
+        PersonaPersonaObjectStatus => "persona:PersonaObjectStatus",
+        PersonaFileAsId => "persona:FileAsId",
+        PersonaDisplayNamePrefix => "persona:DisplayNamePrefix",
+        PersonaYomiCompanyName => "persona:YomiCompanyName",
+        PersonaYomiFirstName => "persona:YomiFirstName",
+        PersonaYomiLastName => "persona:YomiLastName",
+        PersonaTitle => "persona:Title",
+        PersonaEmailAddresses => "persona:EmailAddresses",
+        PersonaPhoneNumber => "persona:PhoneNumber",
+        PersonaImAddress => "persona:ImAddress",
+        PersonaImAddresses => "persona:ImAddresses",
+        PersonaImAddresses2 => "persona:ImAddresses2",
+        PersonaImAddresses3 => "persona:ImAddresses3",
+        PersonaFolderIds => "persona:FolderIds",
+        PersonaAttributions => "persona:Attributions",
+        PersonaDisplayNames => "persona:DisplayNames",
+        PersonaInitials => "persona:Initials",
+        PersonaFileAses => "persona:FileAses",
+        PersonaFileAsIds => "persona:FileAsIds",
+        PersonaDisplayNamePrefixes => "persona:DisplayNamePrefixes",
+        PersonaGivenNames => "persona:GivenNames",
+        PersonaMiddleNames => "persona:MiddleNames",
+        PersonaSurnames => "persona:Surnames",
+        PersonaGenerations => "persona:Generations",
+        PersonaNicknames => "persona:Nicknames",
+        PersonaYomiCompanyNames => "persona:YomiCompanyNames",
+        PersonaYomiFirstNames => "persona:YomiFirstNames",
+        PersonaYomiLastNames => "persona:YomiLastNames",
+        PersonaBusinessPhoneNumbers => "persona:BusinessPhoneNumbers",
+        PersonaBusinessPhoneNumbers2 => "persona:BusinessPhoneNumbers2",
+        PersonaHomePhones => "persona:HomePhones",
+        PersonaHomePhones2 => "persona:HomePhones2",
+        PersonaMobilePhones => "persona:MobilePhones",
+        PersonaMobilePhones2 => "persona:MobilePhones2",
+        PersonaAssistantPhoneNumbers => "persona:AssistantPhoneNumbers",
+        PersonaCallbackPhones => "persona:CallbackPhones",
+        PersonaCarPhones => "persona:CarPhones",
+        PersonaHomeFaxes => "persona:HomeFaxes",
+        PersonaOrganizationMainPhones => "persona:OrganizationMainPhones",
+        PersonaOtherFaxes => "persona:OtherFaxes",
+        PersonaOtherTelephones => "persona:OtherTelephones",
+        PersonaOtherPhones2 => "persona:OtherPhones2",
+        PersonaPagers => "persona:Pagers",
+        PersonaRadioPhones => "persona:RadioPhones",
+        PersonaTelexNumbers => "persona:TelexNumbers",
+        PersonaWorkFaxes => "persona:WorkFaxes",
+        PersonaEmails1 => "persona:Emails1",
+        PersonaEmails2 => "persona:Emails2",
+        PersonaEmails3 => "persona:Emails3",
+        PersonaBusinessHomePages => "persona:BusinessHomePages",
+        PersonaSchool => "persona:School",
+        PersonaPersonalHomePages => "persona:PersonalHomePages",
+        PersonaOfficeLocations => "persona:OfficeLocations",
+        PersonaBusinessAddresses => "persona:BusinessAddresses",
+        PersonaHomeAddresses => "persona:HomeAddresses",
+        PersonaOtherAddresses => "persona:OtherAddresses",
+        PersonaTitles => "persona:Titles",
+        PersonaDepartments => "persona:Departments",
+        PersonaCompanyNames => "persona:CompanyNames",
+        PersonaManagers => "persona:Managers",
+        PersonaAssistantNames => "persona:AssistantNames",
+        PersonaProfessions => "persona:Professions",
+        PersonaSpouseNames => "persona:SpouseNames",
+        PersonaHobbies => "persona:Hobbies",
+        PersonaWeddingAnniversaries => "persona:WeddingAnniversaries",
+        PersonaBirthdays => "persona:Birthdays",
+        PersonaChildren => "persona:Children",
+        PersonaLocations => "persona:Locations",
+        PersonaExtendedProperties => "persona:ExtendedProperties",
+        PersonaPostalAddress => "persona:PostalAddress",
+        PersonaBodies => "persona:Bodies",
+        PersonaIsFavorite => "persona:IsFavorite",
+        PersonaUnreadCount => "persona:UnreadCount",
+        PersonaThirdPartyPhotoUrls => "persona:ThirdPartyPhotoUrls",
+    }
+}
+
+string_enum! {
+    /// A dictionary-based property identified by a well-known string, for use
+    /// in [`PathToElement::IndexedFieldURI`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/indexedfielduri>
+    ///
+    /// [`PathToElement::IndexedFieldURI`]: crate::PathToElement::IndexedFieldURI
+    pub enum DictionaryURI {
+        ItemInternetMessageHeader => "item:InternetMessageHeader",
+        ContactsImAddress => "contacts:ImAddress",
+        ContactsPhysicalAddressStreet => "contacts:PhysicalAddress:Street",
+        ContactsPhysicalAddressCity => "contacts:PhysicalAddress:City",
+        ContactsPhysicalAddressState => "contacts:PhysicalAddress:State",
+        ContactsPhysicalAddressCountryOrRegion => "contacts:PhysicalAddress:CountryOrRegion",
+        ContactsPhysicalAddressPostalCode => "contacts:PhysicalAddress:PostalCode",
+        ContactsPhoneNumber => "contacts:PhoneNumber",
+        ContactsEmailAddress => "contacts:EmailAddress",
+        DistributionListMembersMember => "distributionlist:Members:Member",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_uri_string_round_trip() {
+        for uri in ["folder:DisplayName", "message:IsRead", "calendar:UID"] {
+            let field_uri = UnindexedFieldURI::from(uri);
+            assert!(!matches!(field_uri, UnindexedFieldURI::Other(_)));
+            assert_eq!(field_uri.as_str(), uri);
+        }
+
+        assert_eq!(
+            UnindexedFieldURI::from("folder:Displayname"),
+            UnindexedFieldURI::Other("folder:Displayname".to_string())
+        );
+        assert_eq!(
+            DictionaryURI::from("contacts:PhysicalAddress:City"),
+            DictionaryURI::ContactsPhysicalAddressCity
+        );
+    }
+}
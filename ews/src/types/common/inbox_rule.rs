@@ -0,0 +1,508 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Server-side mail rules, which are applied to messages as they are
+//! delivered to the Inbox.
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ArrayOfStrings, BaseFolderId, DateTime, Importance, ItemId, Mailbox, Sensitivity};
+
+/// A rule applied to incoming messages.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rule>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Rule {
+    /// The identifier of the rule, which must be omitted when creating it.
+    #[xml_struct(ns_prefix = "t")]
+    pub rule_id: Option<String>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub display_name: String,
+
+    /// The order in which the rule is applied, starting at 1.
+    #[xml_struct(ns_prefix = "t")]
+    pub priority: u32,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_enabled: bool,
+
+    /// Whether the rule was created by a client and can't be modified through
+    /// EWS. Read-only.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_not_supported: Option<bool>,
+
+    /// Whether the rule is in an error condition. Read-only.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_in_error: Option<bool>,
+
+    /// The conditions a message must meet for the rule to apply.
+    #[xml_struct(ns_prefix = "t")]
+    pub conditions: Option<RulePredicates>,
+
+    /// The conditions which prevent the rule from applying to a message.
+    #[xml_struct(ns_prefix = "t")]
+    pub exceptions: Option<RulePredicates>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub actions: Option<RuleActions>,
+}
+
+/// The conditions or exceptions of a [`Rule`]. A message matches if it
+/// satisfies every predicate which is set.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conditions>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[allow(non_snake_case)]
+pub struct RulePredicates {
+    #[xml_struct(ns_prefix = "t")]
+    pub categories: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_body_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_header_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_recipient_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_sender_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_subject_or_body_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub contains_subject_strings: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub flagged_for_action: Option<FlaggedForAction>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub from_addresses: Option<ArrayOfEmailAddresses>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub from_connected_accounts: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub has_attachments: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub importance: Option<Importance>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_approval_request: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_automatic_forward: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_automatic_reply: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_encrypted: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_meeting_request: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_meeting_response: Option<bool>,
+
+    /// Whether the message is a non-delivery report.
+    #[xml_struct(ns_prefix = "t")]
+    pub is_NDR: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_permission_controlled: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_read_receipt: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_signed: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_voicemail: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub item_classes: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub message_classifications: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub not_sent_to_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_cc_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_only_to_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_addresses: Option<ArrayOfEmailAddresses>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sent_to_or_cc_me: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub sensitivity: Option<Sensitivity>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub within_date_range: Option<RuleDateRange>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub within_size_range: Option<RuleSizeRange>,
+}
+
+/// The actions taken on a message matched by a [`Rule`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/actions>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+#[allow(non_snake_case)]
+pub struct RuleActions {
+    #[xml_struct(ns_prefix = "t")]
+    pub assign_categories: Option<ArrayOfStrings>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub copy_to_folder: Option<TargetFolderId>,
+
+    /// Moves the message to the Deleted Items folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub delete: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub forward_as_attachment_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub forward_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub mark_importance: Option<Importance>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub mark_as_read: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub move_to_folder: Option<TargetFolderId>,
+
+    /// Deletes the message without moving it to the Deleted Items folder.
+    #[xml_struct(ns_prefix = "t")]
+    pub permanent_delete: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub redirect_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub send_SMS_alert_to_recipients: Option<ArrayOfEmailAddresses>,
+
+    /// Replies to the sender with the message with the given ID.
+    #[xml_struct(ns_prefix = "t")]
+    pub server_reply_with_message: Option<ItemId>,
+
+    /// Prevents rules with a lower priority from being applied.
+    #[xml_struct(ns_prefix = "t")]
+    pub stop_processing_rules: Option<bool>,
+}
+
+/// A list of email addresses, as used by rule predicates and actions.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fromaddresses>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ArrayOfEmailAddresses {
+    #[serde(rename = "Address", default)]
+    pub inner: Vec<Mailbox>,
+}
+
+impl XmlSerialize for ArrayOfEmailAddresses {
+    /// Serializes each address as a `<t:Address>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for address in &self.inner {
+            address.serialize_as_element(writer, "t:Address")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The folder a rule action moves or copies messages to.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/movetofolder>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct TargetFolderId {
+    #[serde(rename = "$value")]
+    #[xml_struct(flatten)]
+    pub folder_id: BaseFolderId,
+}
+
+/// The follow-up action a message is flagged for.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/flaggedforaction>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum FlaggedForAction {
+    Any,
+    Call,
+    DoNotForward,
+    FollowUp,
+    FYI,
+    Forward,
+    NoResponseNecessary,
+    Read,
+    Reply,
+    ReplyToAll,
+    Review,
+}
+
+/// The range of dates a message must be received within.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/withindaterange>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleDateRange {
+    #[xml_struct(ns_prefix = "t")]
+    pub start_date_time: Option<DateTime>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub end_date_time: Option<DateTime>,
+}
+
+/// The range of sizes a message must be within, in kilobytes.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/withinsizerange>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleSizeRange {
+    #[xml_struct(ns_prefix = "t")]
+    pub minimum_size: Option<u32>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub maximum_size: Option<u32>,
+}
+
+/// A change to the rules of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/operations>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum RuleOperation {
+    /// Creates a rule, which must not have a `rule_id`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createruleoperation>
+    CreateRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule: Rule,
+    },
+
+    /// Replaces the rule with the same `rule_id`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setruleoperation>
+    SetRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule: Rule,
+    },
+
+    /// Deletes a rule.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteruleoperation>
+    DeleteRuleOperation {
+        #[xml_struct(ns_prefix = "t")]
+        rule_id: String,
+    },
+}
+
+/// The operations of an `UpdateInboxRules` request which failed validation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleoperationerrors>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct RuleOperationErrors {
+    #[serde(rename = "RuleOperationError", default)]
+    pub inner: Vec<RuleOperationError>,
+}
+
+/// The validation errors of a single rule operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/ruleoperationerror>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleOperationError {
+    /// The index of the failed operation in the request.
+    pub operation_index: usize,
+
+    pub validation_errors: RuleValidationErrors,
+}
+
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/validationerrors>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct RuleValidationErrors {
+    #[serde(rename = "Error", default)]
+    pub inner: Vec<RuleValidationError>,
+}
+
+/// A problem with a single property of a rule.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/error-rulevalidationerrortype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct RuleValidationError {
+    #[serde(rename = "FieldURI")]
+    pub field_uri: RuleFieldURI,
+
+    pub error_code: RuleValidationErrorCode,
+
+    pub error_message: String,
+
+    /// The invalid value, if any.
+    pub field_value: Option<String>,
+}
+
+/// The property of a rule a validation error applies to.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fielduri-ruletype>
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum RuleFieldURI {
+    RuleId,
+    DisplayName,
+    Priority,
+    IsNotSupported,
+    Actions,
+    ConditionCategories,
+    ConditionContainsBodyStrings,
+    ConditionContainsHeaderStrings,
+    ConditionContainsRecipientStrings,
+    ConditionContainsSenderStrings,
+    ConditionContainsSubjectOrBodyStrings,
+    ConditionContainsSubjectStrings,
+    ConditionFlaggedForAction,
+    ConditionFromAddresses,
+    ConditionFromConnectedAccounts,
+    ConditionHasAttachments,
+    ConditionImportance,
+    ConditionIsApprovalRequest,
+    ConditionIsAutomaticForward,
+    ConditionIsAutomaticReply,
+    ConditionIsEncrypted,
+    ConditionIsMeetingRequest,
+    ConditionIsMeetingResponse,
+    ConditionIsNDR,
+    ConditionIsPermissionControlled,
+    ConditionIsReadReceipt,
+    ConditionIsSigned,
+    ConditionIsVoicemail,
+    ConditionItemClasses,
+    ConditionMessageClassifications,
+    ConditionNotSentToMe,
+    ConditionSentCcMe,
+    ConditionSentOnlyToMe,
+    ConditionSentToAddresses,
+    ConditionSentToMe,
+    ConditionSentToOrCcMe,
+    ConditionSensitivity,
+    ConditionWithinDateRange,
+    ConditionWithinSizeRange,
+    ExceptionCategories,
+    ExceptionContainsBodyStrings,
+    ExceptionContainsHeaderStrings,
+    ExceptionContainsRecipientStrings,
+    ExceptionContainsSenderStrings,
+    ExceptionContainsSubjectOrBodyStrings,
+    ExceptionContainsSubjectStrings,
+    ExceptionFlaggedForAction,
+    ExceptionFromAddresses,
+    ExceptionFromConnectedAccounts,
+    ExceptionHasAttachments,
+    ExceptionImportance,
+    ExceptionIsApprovalRequest,
+    ExceptionIsAutomaticForward,
+    ExceptionIsAutomaticReply,
+    ExceptionIsEncrypted,
+    ExceptionIsMeetingRequest,
+    ExceptionIsMeetingResponse,
+    ExceptionIsNDR,
+    ExceptionIsPermissionControlled,
+    ExceptionIsReadReceipt,
+    ExceptionIsSigned,
+    ExceptionIsVoicemail,
+    ExceptionItemClasses,
+    ExceptionMessageClassifications,
+    ExceptionNotSentToMe,
+    ExceptionSentCcMe,
+    ExceptionSentOnlyToMe,
+    ExceptionSentToAddresses,
+    ExceptionSentToMe,
+    ExceptionSentToOrCcMe,
+    ExceptionSensitivity,
+    ExceptionWithinDateRange,
+    ExceptionWithinSizeRange,
+    ActionAssignCategories,
+    ActionCopyToFolder,
+    ActionDelete,
+    ActionForwardAsAttachmentToRecipients,
+    ActionForwardToRecipients,
+    ActionMarkImportance,
+    ActionMarkAsRead,
+    ActionMoveToFolder,
+    ActionPermanentDelete,
+    ActionRedirectToRecipients,
+    ActionSendSMSAlertToRecipients,
+    ActionServerReplyWithMessage,
+    ActionStopProcessingRules,
+    IsEnabled,
+    IsInError,
+    Conditions,
+    Exceptions,
+
+    /// A property not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The reason a rule failed validation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/errorcode>
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum RuleValidationErrorCode {
+    ADOperationFailure,
+    ConnectedAccountNotFound,
+    CreateWithRuleId,
+    EmptyValueFound,
+    DuplicatedPriority,
+    DuplicatedOperationOnTheSameRule,
+    FolderDoesNotExist,
+    InvalidAddress,
+    InvalidDateRange,
+    InvalidFolderId,
+    InvalidSizeRange,
+    InvalidValue,
+    MessageClassificationNotFound,
+    MissingAction,
+    MissingParameter,
+    MissingRangeValue,
+    NotSettable,
+    RecipientDoesNotExist,
+    RuleNotFound,
+    SizeLessThanZero,
+    StringValueTooBig,
+    UnsupportedAddress,
+    UnexpectedError,
+    UnsupportedRule,
+
+    /// An error not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
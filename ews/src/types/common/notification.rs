@@ -0,0 +1,314 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Notifications of changes to the items and folders of a mailbox, delivered
+//! to subscriptions created with [`Subscribe`].
+//!
+//! [`Subscribe`]: crate::subscribe::Subscribe
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{DateTime, FolderId, ItemId};
+
+/// A kind of event a subscription can be notified of.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/eventtype>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum EventType {
+    CopiedEvent,
+    CreatedEvent,
+    DeletedEvent,
+    ModifiedEvent,
+    MovedEvent,
+    NewMailEvent,
+    FreeBusyChangedEvent,
+}
+
+/// The kinds of events a subscription is notified of.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/eventtypes>
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventTypes(pub Vec<EventType>);
+
+impl XmlSerialize for EventTypes {
+    /// Serializes each event type as a `<t:EventType>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for event_type in &self.0 {
+            event_type.serialize_as_element(writer, "t:EventType")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of subscription identifiers.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/subscriptionids>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct SubscriptionIds {
+    #[serde(rename = "SubscriptionId", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for SubscriptionIds {
+    /// Serializes each identifier as a `<t:SubscriptionId>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for subscription_id in &self.inner {
+            subscription_id.serialize_as_element(writer, "t:SubscriptionId")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A set of events for a single subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/notification-ex15websvcsotherref>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "RawNotification")]
+pub struct Notification {
+    /// The subscription the events were generated for.
+    pub subscription_id: String,
+
+    /// The watermark of the last event before this notification.
+    ///
+    /// Only included in pull and push notifications.
+    pub previous_watermark: Option<String>,
+
+    /// Whether more events are available for the subscription.
+    ///
+    /// Only included in pull and push notifications.
+    pub more_events: Option<bool>,
+
+    /// The events, in the order in which they occurred.
+    pub events: Vec<NotificationEvent>,
+}
+
+/// An event reported in a [`Notification`].
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub enum NotificationEvent {
+    /// An item or folder was copied.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/copiedevent>
+    CopiedEvent(MovedOrCopiedEvent),
+
+    /// An item or folder was created.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createdevent>
+    CreatedEvent(ObjectChangedEvent),
+
+    /// An item or folder was deleted.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deletedevent>
+    DeletedEvent(ObjectChangedEvent),
+
+    /// An item or folder was modified.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/modifiedevent>
+    ModifiedEvent(ModifiedEvent),
+
+    /// An item or folder was moved.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/movedevent>
+    MovedEvent(MovedOrCopiedEvent),
+
+    /// A message was received.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/newmailevent>
+    NewMailEvent(ObjectChangedEvent),
+
+    /// The free/busy status of a calendar item changed.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/freebusychangedevent>
+    FreeBusyChangedEvent(ObjectChangedEvent),
+
+    /// No events occurred since the last notification.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/statusevent>
+    StatusEvent(StatusEvent),
+}
+
+impl NotificationEvent {
+    /// The watermark identifying this event, if any.
+    pub fn watermark(&self) -> Option<&str> {
+        let watermark = match self {
+            Self::CopiedEvent(event) | Self::MovedEvent(event) => &event.watermark,
+            Self::CreatedEvent(event)
+            | Self::DeletedEvent(event)
+            | Self::NewMailEvent(event)
+            | Self::FreeBusyChangedEvent(event) => &event.watermark,
+            Self::ModifiedEvent(event) => &event.watermark,
+            Self::StatusEvent(event) => &event.watermark,
+        };
+
+        watermark.as_deref()
+    }
+}
+
+/// An event affecting a single item or folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createdevent>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectChangedEvent {
+    /// An identifier of the event, from which a pull subscription can resume.
+    ///
+    /// Not included in streaming notifications.
+    pub watermark: Option<String>,
+
+    /// When the event occurred.
+    pub time_stamp: DateTime,
+
+    /// The affected item, if the event is about an item.
+    pub item_id: Option<ItemId>,
+
+    /// The affected folder, if the event is about a folder.
+    pub folder_id: Option<FolderId>,
+
+    /// The folder containing the affected item or folder.
+    pub parent_folder_id: FolderId,
+}
+
+/// An event reporting the modification of an item or folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/modifiedevent>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ModifiedEvent {
+    pub watermark: Option<String>,
+    pub time_stamp: DateTime,
+    pub item_id: Option<ItemId>,
+    pub folder_id: Option<FolderId>,
+    pub parent_folder_id: FolderId,
+
+    /// The number of unread items in the modified folder.
+    ///
+    /// Only included for folders.
+    pub unread_count: Option<u32>,
+}
+
+/// An event reporting the move or copy of an item or folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/movedevent>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct MovedOrCopiedEvent {
+    pub watermark: Option<String>,
+    pub time_stamp: DateTime,
+
+    /// The item at its new location, if the event is about an item.
+    pub item_id: Option<ItemId>,
+
+    /// The folder at its new location, if the event is about a folder.
+    pub folder_id: Option<FolderId>,
+
+    /// The new parent folder of the item or folder.
+    pub parent_folder_id: FolderId,
+
+    /// The item at its original location, if the event is about an item.
+    pub old_item_id: Option<ItemId>,
+
+    /// The folder at its original location, if the event is about a folder.
+    pub old_folder_id: Option<FolderId>,
+
+    /// The original parent folder of the item or folder.
+    pub old_parent_folder_id: FolderId,
+}
+
+/// An event sent when no other events occurred, to keep a subscription
+/// alive.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/statusevent>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct StatusEvent {
+    pub watermark: Option<String>,
+}
+
+/// The deserialized form of a [`Notification`].
+///
+/// `quick-xml` routes all prefixed child elements to a `$value` field when a
+/// struct has one, so each child of a notification is deserialized as an
+/// enum variant and sorted out afterwards.
+#[derive(Deserialize)]
+struct RawNotification {
+    #[serde(rename = "$value", default)]
+    children: Vec<NotificationChild>,
+}
+
+#[derive(Deserialize)]
+enum NotificationChild {
+    SubscriptionId(String),
+    PreviousWatermark(String),
+    MoreEvents(bool),
+    CopiedEvent(MovedOrCopiedEvent),
+    CreatedEvent(ObjectChangedEvent),
+    DeletedEvent(ObjectChangedEvent),
+    ModifiedEvent(ModifiedEvent),
+    MovedEvent(MovedOrCopiedEvent),
+    NewMailEvent(ObjectChangedEvent),
+    FreeBusyChangedEvent(ObjectChangedEvent),
+    StatusEvent(StatusEvent),
+}
+
+impl TryFrom<RawNotification> for Notification {
+    type Error = &'static str;
+
+    fn try_from(raw: RawNotification) -> Result<Self, Self::Error> {
+        let mut subscription_id = None;
+        let mut previous_watermark = None;
+        let mut more_events = None;
+        let mut events = Vec::new();
+
+        for child in raw.children {
+            let event = match child {
+                NotificationChild::SubscriptionId(value) => {
+                    subscription_id = Some(value);
+                    continue;
+                }
+                NotificationChild::PreviousWatermark(value) => {
+                    previous_watermark = Some(value);
+                    continue;
+                }
+                NotificationChild::MoreEvents(value) => {
+                    more_events = Some(value);
+                    continue;
+                }
+                NotificationChild::CopiedEvent(event) => NotificationEvent::CopiedEvent(event),
+                NotificationChild::CreatedEvent(event) => NotificationEvent::CreatedEvent(event),
+                NotificationChild::DeletedEvent(event) => NotificationEvent::DeletedEvent(event),
+                NotificationChild::ModifiedEvent(event) => NotificationEvent::ModifiedEvent(event),
+                NotificationChild::MovedEvent(event) => NotificationEvent::MovedEvent(event),
+                NotificationChild::NewMailEvent(event) => NotificationEvent::NewMailEvent(event),
+                NotificationChild::FreeBusyChangedEvent(event) => {
+                    NotificationEvent::FreeBusyChangedEvent(event)
+                }
+                NotificationChild::StatusEvent(event) => NotificationEvent::StatusEvent(event),
+            };
+
+            events.push(event);
+        }
+
+        Ok(Self {
+            subscription_id: subscription_id.ok_or("missing field `SubscriptionId`")?,
+            previous_watermark,
+            more_events,
+            events,
+        })
+    }
+}
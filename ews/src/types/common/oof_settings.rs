@@ -0,0 +1,135 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Out-of-office (OOF) settings, which control the automatic replies sent on
+//! behalf of a mailbox.
+
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::DateTime;
+
+/// The out-of-office settings of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/useroofsettings>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserOofSettings {
+    #[xml_struct(ns_prefix = "t")]
+    pub oof_state: OofState,
+
+    /// Which senders outside of the organization receive the external
+    /// reply.
+    #[xml_struct(ns_prefix = "t")]
+    pub external_audience: ExternalAudience,
+
+    /// When automatic replies are sent, if `oof_state` is
+    /// [`OofState::Scheduled`].
+    #[xml_struct(ns_prefix = "t")]
+    pub duration: Option<Duration>,
+
+    /// The reply sent to senders inside the organization.
+    #[xml_struct(ns_prefix = "t")]
+    pub internal_reply: Option<ReplyBody>,
+
+    /// The reply sent to senders outside of the organization.
+    #[xml_struct(ns_prefix = "t")]
+    pub external_reply: Option<ReplyBody>,
+}
+
+/// Whether automatic replies are sent.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/oofstate>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum OofState {
+    Enabled,
+    Disabled,
+
+    /// Automatic replies are only sent during the configured
+    /// [`Duration`].
+    Scheduled,
+}
+
+/// Which senders outside of the organization receive automatic replies.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/externalaudience>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum ExternalAudience {
+    None,
+
+    /// Only senders in the mailbox's contacts.
+    Known,
+
+    All,
+}
+
+/// A period of time.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/duration>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Duration {
+    #[xml_struct(ns_prefix = "t")]
+    pub start_time: DateTime,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub end_time: DateTime,
+}
+
+/// The message of an automatic reply.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/internalreply>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ReplyBody {
+    /// The language of the message, as an RFC 3066 tag such as `en-US`.
+    #[serde(rename = "@lang")]
+    pub lang: Option<String>,
+
+    /// The message, which may be HTML.
+    pub message: Option<String>,
+}
+
+impl XmlSerialize for ReplyBody {
+    /// Serializes the reply, with its language as an `xml:lang` attribute.
+    ///
+    /// The derive macro doesn't support attribute names which don't follow
+    /// the usual naming scheme.
+    fn serialize_as_element<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+        name: &str,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        let mut start = BytesStart::new(name);
+        if let Some(lang) = &self.lang {
+            start.push_attribute(("xml:lang", lang.as_str()));
+        }
+
+        writer.write_event(Event::Start(start))?;
+        self.serialize_child_nodes(writer)?;
+        writer.write_event(Event::End(BytesEnd::new(name)))?;
+
+        Ok(())
+    }
+
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        if let Some(message) = &self.message {
+            message.serialize_as_element(writer, "t:Message")?;
+        }
+
+        Ok(())
+    }
+}
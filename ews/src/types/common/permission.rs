@@ -0,0 +1,285 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Folder permissions, which control the access other users have to a
+//! folder, e.g. to share a calendar.
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::UserId;
+
+/// The permissions of a folder.
+///
+/// Calendar folders use `calendar_permissions`, while other folders use
+/// `permissions`. Setting the permissions of a folder replaces all existing
+/// entries.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionset-permissionsettype>
+#[derive(Clone, Debug, Default, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PermissionSet {
+    #[xml_struct(ns_prefix = "t")]
+    pub permissions: Option<Permissions>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_permissions: Option<CalendarPermissions>,
+}
+
+/// A list of folder permission entries.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissions>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Permissions {
+    #[serde(rename = "Permission", default)]
+    pub inner: Vec<Permission>,
+}
+
+impl XmlSerialize for Permissions {
+    /// Serializes each entry as a `<t:Permission>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for permission in &self.inner {
+            permission.serialize_as_element(writer, "t:Permission")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A list of calendar folder permission entries.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermissions>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct CalendarPermissions {
+    #[serde(rename = "CalendarPermission", default)]
+    pub inner: Vec<CalendarPermission>,
+}
+
+impl XmlSerialize for CalendarPermissions {
+    /// Serializes each entry as a `<t:CalendarPermission>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for permission in &self.inner {
+            permission.serialize_as_element(writer, "t:CalendarPermission")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The access a user has to a folder.
+///
+/// The individual rights only need to be set when `permission_level` is
+/// [`PermissionLevel::Custom`]; they are implied by the other levels.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permission>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Permission {
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_items: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_sub_folders: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_owner: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_visible: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_contact: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub edit_items: Option<PermissionAction>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub delete_items: Option<PermissionAction>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub read_items: Option<PermissionReadAccess>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub permission_level: PermissionLevel,
+}
+
+impl Permission {
+    /// Creates an entry granting the given level of access to a user.
+    pub fn new(user_id: UserId, permission_level: PermissionLevel) -> Self {
+        Self {
+            user_id,
+            can_create_items: None,
+            can_create_sub_folders: None,
+            is_folder_owner: None,
+            is_folder_visible: None,
+            is_folder_contact: None,
+            edit_items: None,
+            delete_items: None,
+            read_items: None,
+            permission_level,
+        }
+    }
+}
+
+/// The access a user has to a calendar folder.
+///
+/// The individual rights only need to be set when
+/// `calendar_permission_level` is [`CalendarPermissionLevel::Custom`]; they
+/// are implied by the other levels.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermission>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CalendarPermission {
+    #[xml_struct(ns_prefix = "t")]
+    pub user_id: UserId,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_items: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub can_create_sub_folders: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_owner: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_visible: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_folder_contact: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub edit_items: Option<PermissionAction>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub delete_items: Option<PermissionAction>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub read_items: Option<CalendarPermissionReadAccess>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub calendar_permission_level: CalendarPermissionLevel,
+}
+
+impl CalendarPermission {
+    /// Creates an entry granting the given level of access to a user.
+    pub fn new(user_id: UserId, calendar_permission_level: CalendarPermissionLevel) -> Self {
+        Self {
+            user_id,
+            can_create_items: None,
+            can_create_sub_folders: None,
+            is_folder_owner: None,
+            is_folder_visible: None,
+            is_folder_contact: None,
+            edit_items: None,
+            delete_items: None,
+            read_items: None,
+            calendar_permission_level,
+        }
+    }
+}
+
+/// Which items a user can edit or delete.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/edititems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionAction {
+    None,
+
+    /// Only items created by the user.
+    Owned,
+
+    All,
+}
+
+/// Which items a user can read.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/readitems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionReadAccess {
+    None,
+    FullDetails,
+}
+
+/// What a user can read of the items of a calendar.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/readitems>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CalendarPermissionReadAccess {
+    None,
+
+    /// Only the free/busy time of items.
+    TimeOnly,
+
+    TimeAndSubjectAndLocation,
+    FullDetails,
+}
+
+/// A predefined set of folder rights.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/permissionlevel>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum PermissionLevel {
+    None,
+    Owner,
+    PublishingEditor,
+    Editor,
+    PublishingAuthor,
+    Author,
+    NoneditingAuthor,
+    Reviewer,
+    Contributor,
+
+    /// Rights which don't match any of the other levels, as given by the
+    /// individual fields of the entry.
+    Custom,
+}
+
+/// A predefined set of calendar folder rights.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/calendarpermissionlevel>
+#[derive(Clone, Copy, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(text)]
+pub enum CalendarPermissionLevel {
+    None,
+    Owner,
+    PublishingEditor,
+    Editor,
+    PublishingAuthor,
+    Author,
+    NoneditingAuthor,
+    Reviewer,
+    Contributor,
+
+    /// Can only see when the owner is busy.
+    FreeBusyTimeOnly,
+
+    /// Can see when the owner is busy, and the subject and location of
+    /// their appointments.
+    FreeBusyTimeAndSubjectAndLocation,
+
+    /// Rights which don't match any of the other levels, as given by the
+    /// individual fields of the entry.
+    Custom,
+}
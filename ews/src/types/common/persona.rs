@@ -0,0 +1,185 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseShape, DateTime, FolderId, ItemId, Mailbox, PathToElement};
+
+/// The persona properties which should be included in the response.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personashape>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+pub struct PersonaShape {
+    /// The base set of properties to include, which may be extended by other
+    /// fields.
+    #[xml_struct(ns_prefix = "t")]
+    pub base_shape: BaseShape,
+
+    /// A list of properties which should be included in addition to those
+    /// implied by other fields.
+    #[xml_struct(ns_prefix = "t")]
+    pub additional_properties: Option<Vec<PathToElement>>,
+}
+
+/// A person, aggregated from the contacts and directory entries which
+/// describe them.
+///
+/// Properties which may differ between sources are available both as a single
+/// value and as a list of [`AttributedValue`]s, which identify the sources each
+/// value comes from by referring to an entry of [`attributions`].
+///
+/// [`attributions`]: Self::attributions
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/persona>
+// N.B.: Only a subset of the properties of a persona are implemented.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Persona {
+    /// The persona's Exchange identifier.
+    pub persona_id: PersonaId,
+
+    /// The kind of entity the persona represents, e.g. `Person` or
+    /// `DistributionList`.
+    pub persona_type: Option<String>,
+
+    pub creation_time: Option<DateTime>,
+
+    /// The name displayed for the persona.
+    pub display_name: Option<String>,
+
+    pub display_name_first_last: Option<String>,
+
+    pub display_name_last_first: Option<String>,
+
+    /// The name under which the persona is filed.
+    pub file_as: Option<String>,
+
+    pub given_name: Option<String>,
+
+    pub surname: Option<String>,
+
+    pub company_name: Option<String>,
+
+    /// The primary email address of the persona.
+    pub email_address: Option<Mailbox>,
+
+    /// How relevant the persona is to the query which returned it.
+    pub relevance_score: Option<i64>,
+
+    /// The sources from which the persona was aggregated.
+    pub attributions: Option<Attributions>,
+
+    /// The names displayed for the persona by each source.
+    pub display_names: Option<AttributedValues<String>>,
+
+    /// The email addresses of the persona, by source.
+    pub email_addresses: Option<AttributedValues<Mailbox>>,
+
+    /// The phone numbers of the persona, by source.
+    pub phone_numbers: Option<AttributedValues<PersonaPhoneNumber>>,
+}
+
+impl Persona {
+    /// Look up a source of this persona by the identifier used to refer to it
+    /// in [`AttributedValue::attributions`].
+    pub fn attribution(&self, id: &str) -> Option<&Attribution> {
+        self.attributions
+            .as_ref()?
+            .inner
+            .iter()
+            .find(|attribution| attribution.id == id)
+    }
+}
+
+/// The unique identifier of a persona.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/personaid>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct PersonaId {
+    #[xml_struct(attribute)]
+    #[serde(rename = "@Id")]
+    pub id: String,
+}
+
+/// A list of the sources from which a persona was aggregated.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attributions>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct Attributions {
+    #[serde(rename = "Attribution", default)]
+    pub inner: Vec<Attribution>,
+}
+
+/// A source from which a persona was aggregated, such as a contact in a
+/// mailbox folder or a directory entry.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attribution>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Attribution {
+    /// The identifier by which attributed values refer to this source.
+    pub id: String,
+
+    /// The identifier of the item backing this source.
+    pub source_id: Option<ItemId>,
+
+    /// The name of this source.
+    pub display_name: String,
+
+    /// Whether the source can be modified.
+    pub is_writable: bool,
+
+    pub is_quick_contact: bool,
+
+    pub is_hidden: bool,
+
+    /// The folder containing the item backing this source.
+    pub folder_id: Option<FolderId>,
+}
+
+/// A list of the values of a persona property, as provided by each source.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/emailaddresses-arrayofemailaddressattributedvalue>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct AttributedValues<T> {
+    #[serde(rename = "$value", default = "Vec::new")]
+    pub inner: Vec<AttributedValue<T>>,
+}
+
+/// A value of a persona property, along with the sources which provide it.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/emailaddressattributedvalue>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct AttributedValue<T> {
+    pub value: T,
+
+    /// The identifiers of the sources providing this value.
+    ///
+    /// See [`Persona::attribution`] to retrieve details of each source.
+    pub attributions: AttributionIds,
+}
+
+/// A list of identifiers of persona sources.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct AttributionIds {
+    #[serde(rename = "Attribution", default)]
+    pub inner: Vec<String>,
+}
+
+/// A phone number of a persona.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/phonenumber-personaphonenumbertype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PersonaPhoneNumber {
+    pub number: String,
+
+    /// The kind of phone number, e.g. `Business` or `Mobile`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/type-personaphonenumbertype>
+    #[serde(rename = "Type")]
+    pub phone_number_type: String,
+}
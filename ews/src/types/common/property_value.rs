@@ -0,0 +1,690 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Values of extended MAPI properties.
+//!
+//! Exchange represents every extended property value as text, with
+//! multi-valued (`*Array`) properties sent as a list of `<t:Value>` elements
+//! within `<t:Values>`. [`ExtendedPropertyValue`] models this wire format,
+//! while [`PropertyValue`] holds the value interpreted according to the
+//! [`PropertyType`] of the property.
+
+use std::{fmt::Display, str::FromStr};
+
+use serde::Deserialize;
+use time::{format_description::well_known::Iso8601, OffsetDateTime};
+use xml_struct::XmlSerialize;
+
+use crate::{
+    content::{decode_base64, encode_base64},
+    DateTime, Error, PropertyType,
+};
+
+/// The value of an extended property, as sent to or received from Exchange.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/extendedproperty>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum ExtendedPropertyValue {
+    /// The value of a single-valued property.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/value-extendedproperty>
+    Value(String),
+
+    /// The values of a multi-valued property.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/values>
+    Values(ExtendedPropertyValues),
+}
+
+impl ExtendedPropertyValue {
+    fn multiple(values: impl IntoIterator<Item = String>) -> Self {
+        Self::Values(ExtendedPropertyValues {
+            inner: values.into_iter().collect(),
+        })
+    }
+}
+
+/// The values of a multi-valued extended property.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/values>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ExtendedPropertyValues {
+    #[serde(rename = "Value", default)]
+    pub inner: Vec<String>,
+}
+
+impl XmlSerialize for ExtendedPropertyValues {
+    /// Serializes each value as a `<t:Value>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for value in &self.inner {
+            value.serialize_as_element(writer, "t:Value")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The value of an extended property, interpreted according to its type.
+///
+/// There is one variant for each [`PropertyType`], holding the same kind of
+/// value.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/extendedfielduri#propertytype-attribute>
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropertyValue {
+    /// A date and time, as the number of days since 30 December 1899, with
+    /// the time of day as the fractional part.
+    ApplicationTime(f64),
+    ApplicationTimeArray(Vec<f64>),
+    Binary(Vec<u8>),
+    BinaryArray(Vec<Vec<u8>>),
+    Boolean(bool),
+
+    /// A GUID.
+    // TODO: This could use a strong type for representing a GUID.
+    CLSID(String),
+    CLSIDArray(Vec<String>),
+
+    /// A fixed-point amount, scaled by 10,000.
+    Currency(i64),
+    CurrencyArray(Vec<i64>),
+    Double(f64),
+    DoubleArray(Vec<f64>),
+    Float(f32),
+    FloatArray(Vec<f32>),
+    Integer(i32),
+    IntegerArray(Vec<i32>),
+    Long(i64),
+    LongArray(Vec<i64>),
+    Short(i16),
+    ShortArray(Vec<i16>),
+    SystemTime(DateTime),
+    SystemTimeArray(Vec<DateTime>),
+    String(String),
+    StringArray(Vec<String>),
+}
+
+impl PropertyValue {
+    /// The type of the property this value belongs to.
+    pub fn property_type(&self) -> PropertyType {
+        match self {
+            Self::ApplicationTime(_) => PropertyType::ApplicationTime,
+            Self::ApplicationTimeArray(_) => PropertyType::ApplicationTimeArray,
+            Self::Binary(_) => PropertyType::Binary,
+            Self::BinaryArray(_) => PropertyType::BinaryArray,
+            Self::Boolean(_) => PropertyType::Boolean,
+            Self::CLSID(_) => PropertyType::CLSID,
+            Self::CLSIDArray(_) => PropertyType::CLSIDArray,
+            Self::Currency(_) => PropertyType::Currency,
+            Self::CurrencyArray(_) => PropertyType::CurrencyArray,
+            Self::Double(_) => PropertyType::Double,
+            Self::DoubleArray(_) => PropertyType::DoubleArray,
+            Self::Float(_) => PropertyType::Float,
+            Self::FloatArray(_) => PropertyType::FloatArray,
+            Self::Integer(_) => PropertyType::Integer,
+            Self::IntegerArray(_) => PropertyType::IntegerArray,
+            Self::Long(_) => PropertyType::Long,
+            Self::LongArray(_) => PropertyType::LongArray,
+            Self::Short(_) => PropertyType::Short,
+            Self::ShortArray(_) => PropertyType::ShortArray,
+            Self::SystemTime(_) => PropertyType::SystemTime,
+            Self::SystemTimeArray(_) => PropertyType::SystemTimeArray,
+            Self::String(_) => PropertyType::String,
+            Self::StringArray(_) => PropertyType::StringArray,
+        }
+    }
+
+    /// Interprets the raw value of a property of the given type.
+    pub fn from_raw(
+        property_type: PropertyType,
+        raw: &ExtendedPropertyValue,
+    ) -> Result<Self, Error> {
+        match raw {
+            ExtendedPropertyValue::Value(value) => Self::from_single(property_type, value),
+            ExtendedPropertyValue::Values(values) => {
+                Self::from_multiple(property_type, &values.inner)
+            }
+        }
+    }
+
+    fn from_single(property_type: PropertyType, value: &str) -> Result<Self, Error> {
+        let value = match property_type {
+            PropertyType::ApplicationTime => Self::ApplicationTime(parse(property_type, value)?),
+            PropertyType::Binary => Self::Binary(decode_base64(value)?),
+            PropertyType::Boolean => Self::Boolean(parse_bool(property_type, value)?),
+            PropertyType::CLSID => Self::CLSID(value.to_string()),
+            PropertyType::Currency => Self::Currency(parse(property_type, value)?),
+            PropertyType::Double => Self::Double(parse(property_type, value)?),
+            PropertyType::Float => Self::Float(parse(property_type, value)?),
+            PropertyType::Integer => Self::Integer(parse(property_type, value)?),
+            PropertyType::Long => Self::Long(parse(property_type, value)?),
+            PropertyType::Short => Self::Short(parse(property_type, value)?),
+            PropertyType::SystemTime => Self::SystemTime(parse_time(property_type, value)?),
+            PropertyType::String => Self::String(value.to_string()),
+
+            PropertyType::ApplicationTimeArray
+            | PropertyType::BinaryArray
+            | PropertyType::CLSIDArray
+            | PropertyType::CurrencyArray
+            | PropertyType::DoubleArray
+            | PropertyType::FloatArray
+            | PropertyType::IntegerArray
+            | PropertyType::LongArray
+            | PropertyType::ShortArray
+            | PropertyType::SystemTimeArray
+            | PropertyType::StringArray => {
+                return Err(Error::UnexpectedPropertyValueCount(property_type))
+            }
+        };
+
+        Ok(value)
+    }
+
+    fn from_multiple(property_type: PropertyType, values: &[String]) -> Result<Self, Error> {
+        let value = match property_type {
+            PropertyType::ApplicationTimeArray => {
+                Self::ApplicationTimeArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::BinaryArray => Self::BinaryArray(parse_each(values, |v| {
+                decode_base64(v).map_err(Error::from)
+            })?),
+            PropertyType::CLSIDArray => Self::CLSIDArray(values.to_vec()),
+            PropertyType::CurrencyArray => {
+                Self::CurrencyArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::DoubleArray => {
+                Self::DoubleArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::FloatArray => {
+                Self::FloatArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::IntegerArray => {
+                Self::IntegerArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::LongArray => {
+                Self::LongArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::ShortArray => {
+                Self::ShortArray(parse_each(values, |v| parse(property_type, v))?)
+            }
+            PropertyType::SystemTimeArray => {
+                Self::SystemTimeArray(parse_each(values, |v| parse_time(property_type, v))?)
+            }
+            PropertyType::StringArray => Self::StringArray(values.to_vec()),
+
+            PropertyType::ApplicationTime
+            | PropertyType::Binary
+            | PropertyType::Boolean
+            | PropertyType::CLSID
+            | PropertyType::Currency
+            | PropertyType::Double
+            | PropertyType::Float
+            | PropertyType::Integer
+            | PropertyType::Long
+            | PropertyType::Short
+            | PropertyType::SystemTime
+            | PropertyType::String => {
+                return Err(Error::UnexpectedPropertyValueCount(property_type))
+            }
+        };
+
+        Ok(value)
+    }
+
+    /// Formats this value as it is sent to Exchange.
+    pub fn to_raw(&self) -> Result<ExtendedPropertyValue, Error> {
+        use ExtendedPropertyValue::Value;
+
+        let raw = match self {
+            Self::ApplicationTime(value) | Self::Double(value) => Value(format_float(*value)),
+            Self::ApplicationTimeArray(values) | Self::DoubleArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(|value| format_float(*value)))
+            }
+            Self::Binary(value) => Value(encode_base64(value)),
+            Self::BinaryArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(|value| encode_base64(value)))
+            }
+            Self::Boolean(value) => Value(value.to_string()),
+            Self::CLSID(value) | Self::String(value) => Value(value.clone()),
+            Self::CLSIDArray(values) | Self::StringArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().cloned())
+            }
+            Self::Currency(value) | Self::Long(value) => Value(value.to_string()),
+            Self::CurrencyArray(values) | Self::LongArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(i64::to_string))
+            }
+            Self::Float(value) => Value(format_float(*value)),
+            Self::FloatArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(|value| format_float(*value)))
+            }
+            Self::Integer(value) => Value(value.to_string()),
+            Self::IntegerArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(i32::to_string))
+            }
+            Self::Short(value) => Value(value.to_string()),
+            Self::ShortArray(values) => {
+                ExtendedPropertyValue::multiple(values.iter().map(i16::to_string))
+            }
+            Self::SystemTime(value) => Value(format_time(value)?),
+            Self::SystemTimeArray(values) => ExtendedPropertyValue::multiple(
+                values
+                    .iter()
+                    .map(format_time)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ),
+        };
+
+        Ok(raw)
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<i16> for PropertyValue {
+    fn from(value: i16) -> Self {
+        Self::Short(value)
+    }
+}
+
+impl From<i32> for PropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<i64> for PropertyValue {
+    fn from(value: i64) -> Self {
+        Self::Long(value)
+    }
+}
+
+impl From<f32> for PropertyValue {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Double(value)
+    }
+}
+
+impl From<DateTime> for PropertyValue {
+    fn from(value: DateTime) -> Self {
+        Self::SystemTime(value)
+    }
+}
+
+impl From<Vec<u8>> for PropertyValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Binary(value)
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for PropertyValue {
+    fn from(value: Vec<String>) -> Self {
+        Self::StringArray(value)
+    }
+}
+
+fn parse<T: FromStr>(property_type: PropertyType, value: &str) -> Result<T, Error> {
+    // `xs:double` and `xs:float` spell infinity differently from Rust.
+    let normalized = match value.trim() {
+        "INF" => "inf",
+        "-INF" => "-inf",
+        trimmed => trimmed,
+    };
+
+    normalized
+        .parse()
+        .map_err(|_| invalid_value(property_type, value))
+}
+
+fn parse_bool(property_type: PropertyType, value: &str) -> Result<bool, Error> {
+    // `xs:boolean` allows numeric representations as well as keywords.
+    match value.trim() {
+        "true" | "1" => Ok(true),
+        "false" | "0" => Ok(false),
+        _ => Err(invalid_value(property_type, value)),
+    }
+}
+
+fn parse_time(property_type: PropertyType, value: &str) -> Result<DateTime, Error> {
+    OffsetDateTime::parse(value.trim(), &Iso8601::PARSING)
+        .map(DateTime)
+        .map_err(|_| invalid_value(property_type, value))
+}
+
+fn parse_each<T>(
+    values: &[String],
+    parse: impl Fn(&str) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    values.iter().map(|value| parse(value)).collect()
+}
+
+fn format_float<T: Display + Into<f64> + Copy>(value: T) -> String {
+    let as_f64: f64 = value.into();
+    if as_f64.is_infinite() {
+        if as_f64.is_sign_positive() {
+            "INF"
+        } else {
+            "-INF"
+        }
+        .to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+fn format_time(value: &DateTime) -> Result<String, Error> {
+    value
+        .0
+        .format(&Iso8601::DEFAULT)
+        .map_err(|err| Error::Serialize(xml_struct::Error::Value(err.into())))
+}
+
+fn invalid_value(property_type: PropertyType, value: &str) -> Error {
+    Error::InvalidPropertyValue {
+        property_type,
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ExtendedFieldURI, ExtendedProperty,
+    };
+
+    fn field_uri(property_type: PropertyType) -> ExtendedFieldURI {
+        ExtendedFieldURI {
+            distinguished_property_set_id: None,
+            property_set_id: None,
+            property_tag: Some("0x6800".to_string()),
+            property_name: None,
+            property_id: None,
+            property_type,
+        }
+    }
+
+    fn time(timestamp: i64) -> DateTime {
+        DateTime(OffsetDateTime::from_unix_timestamp(timestamp).unwrap())
+    }
+
+    /// A value of the given type, and its representation.
+    ///
+    /// This match is exhaustive so that a new property type can't be added
+    /// without a test case. Add it to `PROPERTY_TYPES` as well.
+    fn sample_value(property_type: PropertyType) -> (PropertyValue, &'static str) {
+        match property_type {
+            PropertyType::ApplicationTime => (
+                PropertyValue::ApplicationTime(45414.5),
+                "<t:Value>45414.5</t:Value>",
+            ),
+            PropertyType::ApplicationTimeArray => (
+                PropertyValue::ApplicationTimeArray(vec![45414.5, 45415.25]),
+                "<t:Values><t:Value>45414.5</t:Value><t:Value>45415.25</t:Value></t:Values>",
+            ),
+            PropertyType::Binary => (
+                PropertyValue::Binary(b"hello".to_vec()),
+                "<t:Value>aGVsbG8=</t:Value>",
+            ),
+            PropertyType::BinaryArray => (
+                PropertyValue::BinaryArray(vec![b"hello".to_vec(), b"world".to_vec()]),
+                "<t:Values><t:Value>aGVsbG8=</t:Value><t:Value>d29ybGQ=</t:Value></t:Values>",
+            ),
+            PropertyType::Boolean => (PropertyValue::Boolean(true), "<t:Value>true</t:Value>"),
+            PropertyType::CLSID => (
+                PropertyValue::CLSID("00062008-0000-0000-c000-000000000046".to_string()),
+                "<t:Value>00062008-0000-0000-c000-000000000046</t:Value>",
+            ),
+            PropertyType::CLSIDArray => (
+                PropertyValue::CLSIDArray(vec![
+                    "00062008-0000-0000-c000-000000000046".to_string(),
+                    "00020329-0000-0000-c000-000000000046".to_string(),
+                ]),
+                "<t:Values><t:Value>00062008-0000-0000-c000-000000000046</t:Value><t:Value>00020329-0000-0000-c000-000000000046</t:Value></t:Values>",
+            ),
+            PropertyType::Currency => (
+                PropertyValue::Currency(123_4500),
+                "<t:Value>1234500</t:Value>",
+            ),
+            PropertyType::CurrencyArray => (
+                PropertyValue::CurrencyArray(vec![10000, -25000]),
+                "<t:Values><t:Value>10000</t:Value><t:Value>-25000</t:Value></t:Values>",
+            ),
+            PropertyType::Double => (PropertyValue::Double(-0.125), "<t:Value>-0.125</t:Value>"),
+            PropertyType::DoubleArray => (
+                PropertyValue::DoubleArray(vec![1.5, f64::INFINITY]),
+                "<t:Values><t:Value>1.5</t:Value><t:Value>INF</t:Value></t:Values>",
+            ),
+            PropertyType::Float => (PropertyValue::Float(2.75), "<t:Value>2.75</t:Value>"),
+            PropertyType::FloatArray => (
+                PropertyValue::FloatArray(vec![0.5, -1.0]),
+                "<t:Values><t:Value>0.5</t:Value><t:Value>-1</t:Value></t:Values>",
+            ),
+            PropertyType::Integer => (PropertyValue::Integer(-42), "<t:Value>-42</t:Value>"),
+            PropertyType::IntegerArray => (
+                PropertyValue::IntegerArray(vec![1, 2, 3]),
+                "<t:Values><t:Value>1</t:Value><t:Value>2</t:Value><t:Value>3</t:Value></t:Values>",
+            ),
+            PropertyType::Long => (
+                PropertyValue::Long(9_007_199_254_740_993),
+                "<t:Value>9007199254740993</t:Value>",
+            ),
+            PropertyType::LongArray => (
+                PropertyValue::LongArray(vec![i64::MIN, i64::MAX]),
+                "<t:Values><t:Value>-9223372036854775808</t:Value><t:Value>9223372036854775807</t:Value></t:Values>",
+            ),
+            PropertyType::Short => (PropertyValue::Short(-7), "<t:Value>-7</t:Value>"),
+            PropertyType::ShortArray => (
+                PropertyValue::ShortArray(vec![i16::MIN, 0]),
+                "<t:Values><t:Value>-32768</t:Value><t:Value>0</t:Value></t:Values>",
+            ),
+            PropertyType::SystemTime => (
+                PropertyValue::SystemTime(time(1714641164)),
+                "<t:Value>2024-05-02T09:12:44.000000000Z</t:Value>",
+            ),
+            PropertyType::SystemTimeArray => (
+                PropertyValue::SystemTimeArray(vec![time(1714641164), time(0)]),
+                "<t:Values><t:Value>2024-05-02T09:12:44.000000000Z</t:Value><t:Value>1970-01-01T00:00:00.000000000Z</t:Value></t:Values>",
+            ),
+            PropertyType::String => (
+                PropertyValue::String("Follow up".to_string()),
+                "<t:Value>Follow up</t:Value>",
+            ),
+            PropertyType::StringArray => (
+                PropertyValue::StringArray(vec!["Red".to_string(), "Blue".to_string()]),
+                "<t:Values><t:Value>Red</t:Value><t:Value>Blue</t:Value></t:Values>",
+            ),
+        }
+    }
+
+    const PROPERTY_TYPES: [PropertyType; 23] = [
+        PropertyType::ApplicationTime,
+        PropertyType::ApplicationTimeArray,
+        PropertyType::Binary,
+        PropertyType::BinaryArray,
+        PropertyType::Boolean,
+        PropertyType::CLSID,
+        PropertyType::CLSIDArray,
+        PropertyType::Currency,
+        PropertyType::CurrencyArray,
+        PropertyType::Double,
+        PropertyType::DoubleArray,
+        PropertyType::Float,
+        PropertyType::FloatArray,
+        PropertyType::Integer,
+        PropertyType::IntegerArray,
+        PropertyType::Long,
+        PropertyType::LongArray,
+        PropertyType::Short,
+        PropertyType::ShortArray,
+        PropertyType::SystemTime,
+        PropertyType::SystemTimeArray,
+        PropertyType::String,
+        PropertyType::StringArray,
+    ];
+
+    #[test]
+    fn round_trip_all_property_types() {
+        for property_type in PROPERTY_TYPES {
+            let (value, expected_value_xml) = sample_value(property_type);
+            assert_eq!(value.property_type(), property_type);
+
+            let property = ExtendedProperty::new(field_uri(property_type), value.clone())
+                .unwrap_or_else(|err| panic!("{property_type:?}: {err:?}"));
+
+            let expected = format!(
+                r#"<t:ExtendedProperty><t:ExtendedFieldURI PropertyTag="0x6800" PropertyType="{property_type:?}"/>{expected_value_xml}</t:ExtendedProperty>"#
+            );
+            assert_serialized_content(&property, "t:ExtendedProperty", &expected);
+
+            let content = format!(
+                r#"<t:ExtendedProperty xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><t:ExtendedFieldURI PropertyTag="0x6800" PropertyType="{property_type:?}"/>{expected_value_xml}</t:ExtendedProperty>"#
+            );
+            assert_deserialized_content(&content, property.clone());
+
+            assert_eq!(property.typed_value().unwrap(), value, "{property_type:?}");
+        }
+    }
+
+    #[test]
+    fn parse_lenient_representations() {
+        let cases = [
+            (PropertyType::Boolean, "1", PropertyValue::Boolean(true)),
+            (
+                PropertyType::Boolean,
+                "false",
+                PropertyValue::Boolean(false),
+            ),
+            (PropertyType::Double, "1.5E3", PropertyValue::Double(1500.0)),
+            (
+                PropertyType::Float,
+                "-INF",
+                PropertyValue::Float(f32::NEG_INFINITY),
+            ),
+            (
+                PropertyType::SystemTime,
+                "2024-05-02T09:12:44Z",
+                PropertyValue::SystemTime(time(1714641164)),
+            ),
+        ];
+
+        for (property_type, raw, expected) in cases {
+            let value = PropertyValue::from_raw(
+                property_type,
+                &ExtendedPropertyValue::Value(raw.to_string()),
+            )
+            .unwrap();
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[test]
+    fn reject_invalid_values() {
+        let err = PropertyValue::from_raw(
+            PropertyType::Integer,
+            &ExtendedPropertyValue::Value("forty-two".to_string()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::InvalidPropertyValue {
+                    property_type: PropertyType::Integer,
+                    ref value,
+                } if value == "forty-two"
+            ),
+            "got {err:?}"
+        );
+
+        let err = PropertyValue::from_raw(
+            PropertyType::Short,
+            &ExtendedPropertyValue::Value("40000".to_string()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, Error::InvalidPropertyValue { .. }),
+            "got {err:?}"
+        );
+
+        let err = PropertyValue::from_raw(
+            PropertyType::Binary,
+            &ExtendedPropertyValue::Value("not base64!".to_string()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::InvalidBase64(_)), "got {err:?}");
+    }
+
+    #[test]
+    fn reject_mismatched_value_count() {
+        let err = PropertyValue::from_raw(
+            PropertyType::StringArray,
+            &ExtendedPropertyValue::Value("Red".to_string()),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::UnexpectedPropertyValueCount(PropertyType::StringArray)
+            ),
+            "got {err:?}"
+        );
+
+        let err = PropertyValue::from_raw(
+            PropertyType::String,
+            &ExtendedPropertyValue::multiple(["Red".to_string()]),
+        )
+        .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::UnexpectedPropertyValueCount(PropertyType::String)
+            ),
+            "got {err:?}"
+        );
+    }
+
+    #[test]
+    fn reject_mismatched_property_type() {
+        let err = ExtendedProperty::new(field_uri(PropertyType::Integer), true).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::UnexpectedPropertyType {
+                    expected: PropertyType::Boolean,
+                    actual: PropertyType::Integer,
+                }
+            ),
+            "got {err:?}"
+        );
+    }
+}
@@ -12,12 +12,12 @@ use std::marker::PhantomData;
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/responsemessages>
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
 #[serde(default)]
-pub struct ResponseMessages<T> {
+pub struct ResponseMessages<T, E = ResponseError> {
     #[serde(rename = "$value")]
-    pub response_messages: Vec<ResponseClass<T>>,
+    pub response_messages: Vec<ResponseClass<T, E>>,
 }
 
-impl<T> Default for ResponseMessages<T> {
+impl<T, E> Default for ResponseMessages<T, E> {
     fn default() -> Self {
         Self {
             response_messages: vec![],
@@ -30,12 +30,15 @@ impl<T> Default for ResponseMessages<T> {
 /// For a list of upstream types that use this sort of structuring, you can see [`ResponseCode`]'s
 /// [list of parent
 /// types](https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/responsecode#parent-elements).
+///
+/// Errors are usually a [`ResponseError`], but operations whose errors carry additional details
+/// use their own error type.
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub enum ResponseClass<T> {
+pub enum ResponseClass<T, E = ResponseError> {
     /// The operation was successful and returned the requested object.
     Success(T),
     /// The operation failed and returned an error.
-    Error(ResponseError),
+    Error(E),
     /// The operation was only partially successful, and may have returned a usable object.  This
     /// should also include `ResponseError` information, but currently does not due to library
     /// limitations.
@@ -43,18 +46,28 @@ pub enum ResponseClass<T> {
     Warning(T),
 }
 
+impl<T, E> ResponseClass<T, E> {
+    /// Converts the response into a [`Result`], treating warnings as success.
+    pub fn into_result(self) -> Result<T, E> {
+        match self {
+            Self::Success(message) | Self::Warning(message) => Ok(message),
+            Self::Error(err) => Err(err),
+        }
+    }
+}
+
 // Manually implemented because quick_xml's impl_deserialize_for_internally_tagged_enum doesn't
 // currently support generics.
-impl<'de, T: Deserialize<'de>> Deserialize<'de> for ResponseClass<T> {
+impl<'de, T: Deserialize<'de>, E: Deserialize<'de>> Deserialize<'de> for ResponseClass<T, E> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct ResponseClassVisitor<T> {
-            marker: PhantomData<fn() -> ResponseClass<T>>,
+        struct ResponseClassVisitor<T, E> {
+            marker: PhantomData<fn() -> ResponseClass<T, E>>,
         }
-        impl<'de, T: Deserialize<'de>> Visitor<'de> for ResponseClassVisitor<T> {
-            type Value = ResponseClass<T>;
+        impl<'de, T: Deserialize<'de>, E: Deserialize<'de>> Visitor<'de> for ResponseClassVisitor<T, E> {
+            type Value = ResponseClass<T, E>;
 
             fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 f.write_str("a map with a ResponseClass attribute")
@@ -69,7 +82,7 @@ impl<'de, T: Deserialize<'de>> Deserialize<'de> for ResponseClass<T> {
                         let de = de::value::MapAccessDeserializer::new(map);
                         return match value.as_str() {
                             "Success" => Ok(Self::Value::Success(T::deserialize(de)?)),
-                            "Error" => Ok(Self::Value::Error(ResponseError::deserialize(de)?)),
+                            "Error" => Ok(Self::Value::Error(E::deserialize(de)?)),
                             "Warning" => Ok(Self::Value::Warning(T::deserialize(de)?)),
                             var => Err(A::Error::unknown_variant(
                                 var,
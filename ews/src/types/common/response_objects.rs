@@ -0,0 +1,272 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Response objects, which create a new item in response to an existing one.
+//!
+//! These are created with a [`CreateItem`] request, and allow Exchange to
+//! maintain the threading headers of the original item and to quote its body.
+//!
+//! [`CreateItem`]: crate::create_item::CreateItem
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{ArrayOfRecipients, BodyType, ItemId, Recipient};
+
+/// A response object, which may only appear in a [`CreateItem`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/items>
+///
+/// [`CreateItem`]: crate::create_item::CreateItem
+#[derive(Clone, Debug, XmlSerialize, PartialEq, Eq)]
+#[xml_struct(variant_ns_prefix = "t")]
+#[non_exhaustive]
+pub enum ResponseObject {
+    ReplyToItem(SmartResponse),
+    ReplyAllToItem(SmartResponse),
+    ForwardItem(SmartResponse),
+    PostReplyItem(PostReplyItem),
+    SuppressReadReceipt(SuppressReadReceipt),
+}
+
+/// A reply to, or a forward of, an existing item.
+///
+/// This is the content of the `ReplyToItem`, `ReplyAllToItem` and
+/// `ForwardItem` variants of [`ResponseObject`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/replytoitem>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SmartResponse {
+    /// The subject of the response.
+    ///
+    /// If omitted, Exchange derives it from the subject of the original item.
+    #[xml_struct(ns_prefix = "t")]
+    pub subject: Option<String>,
+
+    /// The recipients of the response.
+    ///
+    /// If omitted from a reply, Exchange derives them from the original item.
+    /// Required for forwards.
+    #[xml_struct(ns_prefix = "t")]
+    pub to_recipients: Option<ArrayOfRecipients>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub cc_recipients: Option<ArrayOfRecipients>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub bcc_recipients: Option<ArrayOfRecipients>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_read_receipt_requested: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub is_delivery_receipt_requested: Option<bool>,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub from: Option<Recipient>,
+
+    /// The item being responded to.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/referenceitemid>
+    #[xml_struct(ns_prefix = "t")]
+    pub reference_item_id: ItemId,
+
+    /// Content to add above the quoted body of the original item.
+    #[xml_struct(ns_prefix = "t")]
+    pub new_body_content: Option<NewBodyContent>,
+}
+
+impl SmartResponse {
+    /// Creates a response to the given item, with its subject and, for
+    /// replies, recipients derived from the item.
+    pub fn new(reference_item_id: ItemId) -> Self {
+        Self {
+            subject: None,
+            to_recipients: None,
+            cc_recipients: None,
+            bcc_recipients: None,
+            is_read_receipt_requested: None,
+            is_delivery_receipt_requested: None,
+            from: None,
+            reference_item_id,
+            new_body_content: None,
+        }
+    }
+}
+
+/// A reply to an item in a public folder, posted to the same folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/postreplyitem>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct PostReplyItem {
+    #[xml_struct(ns_prefix = "t")]
+    pub subject: Option<String>,
+
+    /// The item being replied to.
+    #[xml_struct(ns_prefix = "t")]
+    pub reference_item_id: ItemId,
+
+    /// Content to add above the quoted body of the original item.
+    #[xml_struct(ns_prefix = "t")]
+    pub new_body_content: Option<NewBodyContent>,
+}
+
+/// A request to not send the read receipt requested by an item's sender.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/suppressreadreceipt>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SuppressReadReceipt {
+    /// The item whose read receipt is suppressed.
+    #[xml_struct(ns_prefix = "t")]
+    pub reference_item_id: ItemId,
+}
+
+/// Content added to a response, above the quoted body of the original item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/newbodycontent>
+#[derive(Clone, Debug, Deserialize, XmlSerialize, PartialEq, Eq)]
+pub struct NewBodyContent {
+    /// The content type of the new content.
+    #[serde(rename = "@BodyType")]
+    #[xml_struct(attribute)]
+    pub body_type: BodyType,
+
+    #[serde(rename = "$text")]
+    #[xml_struct(flatten)]
+    pub content: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        create_item::CreateItem, test_utils::assert_serialized_content, BaseFolderId,
+        DistinguishedFolderName, Mailbox, MessageDisposition,
+    };
+
+    fn reference_item_id() -> ItemId {
+        ItemId {
+            id: "AAMkADk=".to_string(),
+            change_key: Some("CQAAABYA".to_string()),
+        }
+    }
+
+    fn recipients(address: &str) -> Option<ArrayOfRecipients> {
+        Some(ArrayOfRecipients(vec![Recipient {
+            mailbox: Mailbox {
+                email_address: Some(address.to_string()),
+                ..Default::default()
+            },
+        }]))
+    }
+
+    #[test]
+    fn serialize_reply() {
+        let create_item = CreateItem {
+            message_disposition: Some(MessageDisposition::SaveOnly),
+            saved_item_folder_id: None,
+            items: vec![ResponseObject::ReplyToItem(SmartResponse {
+                new_body_content: Some(NewBodyContent {
+                    body_type: BodyType::HTML,
+                    content: "<p>Thanks!</p>".to_string(),
+                }),
+                ..SmartResponse::new(reference_item_id())
+            })
+            .into()],
+        };
+
+        let expected = r#"<CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly"><Items><t:ReplyToItem><t:ReferenceItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/><t:NewBodyContent BodyType="HTML">&lt;p&gt;Thanks!&lt;/p&gt;</t:NewBodyContent></t:ReplyToItem></Items></CreateItem>"#;
+
+        assert_serialized_content(&create_item, "CreateItem", expected);
+    }
+
+    #[test]
+    fn serialize_reply_all() {
+        let create_item = CreateItem {
+            message_disposition: Some(MessageDisposition::SendAndSaveCopy),
+            saved_item_folder_id: Some(BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::SentItems,
+                change_key: None,
+                mailbox: None,
+            }),
+            items: vec![ResponseObject::ReplyAllToItem(SmartResponse {
+                cc_recipients: recipients("carol@example.com"),
+                new_body_content: Some(NewBodyContent {
+                    body_type: BodyType::Text,
+                    content: "Sounds good.".to_string(),
+                }),
+                ..SmartResponse::new(reference_item_id())
+            })
+            .into()],
+        };
+
+        let expected = r#"<CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SendAndSaveCopy"><SavedItemFolderId><t:DistinguishedFolderId Id="sentitems"/></SavedItemFolderId><Items><t:ReplyAllToItem><t:CcRecipients><t:Mailbox><t:EmailAddress>carol@example.com</t:EmailAddress></t:Mailbox></t:CcRecipients><t:ReferenceItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/><t:NewBodyContent BodyType="Text">Sounds good.</t:NewBodyContent></t:ReplyAllToItem></Items></CreateItem>"#;
+
+        assert_serialized_content(&create_item, "CreateItem", expected);
+    }
+
+    #[test]
+    fn serialize_forward() {
+        let create_item = CreateItem {
+            message_disposition: Some(MessageDisposition::SendOnly),
+            saved_item_folder_id: None,
+            items: vec![ResponseObject::ForwardItem(SmartResponse {
+                subject: Some("FW: Quarterly report".to_string()),
+                to_recipients: recipients("dave@example.com"),
+                ..SmartResponse::new(reference_item_id())
+            })
+            .into()],
+        };
+
+        let expected = r#"<CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SendOnly"><Items><t:ForwardItem><t:Subject>FW: Quarterly report</t:Subject><t:ToRecipients><t:Mailbox><t:EmailAddress>dave@example.com</t:EmailAddress></t:Mailbox></t:ToRecipients><t:ReferenceItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/></t:ForwardItem></Items></CreateItem>"#;
+
+        assert_serialized_content(&create_item, "CreateItem", expected);
+    }
+
+    #[test]
+    fn serialize_post_reply() {
+        let create_item = CreateItem {
+            message_disposition: None,
+            saved_item_folder_id: Some(BaseFolderId::FolderId {
+                id: "AAMkADp=".to_string(),
+                change_key: None,
+            }),
+            items: vec![ResponseObject::PostReplyItem(PostReplyItem {
+                subject: Some("Re: Lunch".to_string()),
+                reference_item_id: reference_item_id(),
+                new_body_content: Some(NewBodyContent {
+                    body_type: BodyType::Text,
+                    content: "Count me in.".to_string(),
+                }),
+            })
+            .into()],
+        };
+
+        let expected = r#"<CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><SavedItemFolderId><t:FolderId Id="AAMkADp="/></SavedItemFolderId><Items><t:PostReplyItem><t:Subject>Re: Lunch</t:Subject><t:ReferenceItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/><t:NewBodyContent BodyType="Text">Count me in.</t:NewBodyContent></t:PostReplyItem></Items></CreateItem>"#;
+
+        assert_serialized_content(&create_item, "CreateItem", expected);
+    }
+
+    #[test]
+    fn serialize_suppress_read_receipt() {
+        let create_item = CreateItem {
+            message_disposition: Some(MessageDisposition::SendOnly),
+            saved_item_folder_id: None,
+            items: vec![ResponseObject::SuppressReadReceipt(SuppressReadReceipt {
+                reference_item_id: ItemId {
+                    id: "AAMkADk=".to_string(),
+                    change_key: None,
+                },
+            })
+            .into()],
+        };
+
+        let expected = r#"<CreateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SendOnly"><Items><t:SuppressReadReceipt><t:ReferenceItemId Id="AAMkADk="/></t:SuppressReadReceipt></Items></CreateItem>"#;
+
+        assert_serialized_content(&create_item, "CreateItem", expected);
+    }
+}
@@ -0,0 +1,262 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use xml_struct::XmlSerialize;
+
+use crate::PathToElement;
+
+/// A set of conditions which must be met for an entity to be included in the
+/// results of a search.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/restriction>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct Restriction {
+    /// The condition to apply.
+    #[xml_struct(flatten)]
+    pub expression: SearchExpression,
+}
+
+/// A condition on the properties of an entity.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/searchexpression>
+// N.B.: `Excludes` is not yet implemented.
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(variant_ns_prefix = "t")]
+pub enum SearchExpression {
+    /// Whether the given property is set.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/exists>
+    Exists {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+    },
+
+    /// Whether the given property is equal to a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isequalto>
+    #[allow(non_snake_case)]
+    IsEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given property is not equal to a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isnotequalto>
+    #[allow(non_snake_case)]
+    IsNotEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given property is greater than a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isgreaterthan>
+    #[allow(non_snake_case)]
+    IsGreaterThan {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given property is greater than or equal to a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/isgreaterthanorequalto>
+    #[allow(non_snake_case)]
+    IsGreaterThanOrEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given property is less than a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/islessthan>
+    #[allow(non_snake_case)]
+    IsLessThan {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given property is less than or equal to a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/islessthanorequalto>
+    #[allow(non_snake_case)]
+    IsLessThanOrEqualTo {
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        field_URI_or_constant: FieldURIOrConstant,
+    },
+
+    /// Whether the given string property contains a value.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains>
+    Contains {
+        /// Which part of the property must match the value.
+        #[xml_struct(attribute)]
+        containment_mode: Option<ContainmentMode>,
+
+        /// How strictly the property and the value are compared.
+        #[xml_struct(attribute)]
+        containment_comparison: Option<ContainmentComparison>,
+
+        #[xml_struct(flatten)]
+        path: PathToElement,
+
+        #[xml_struct(ns_prefix = "t")]
+        constant: Constant,
+    },
+
+    /// The negation of a condition.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/not>
+    Not(Not),
+
+    /// Whether all of the given conditions are met.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/and>
+    And(Vec<SearchExpression>),
+
+    /// Whether any of the given conditions is met.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/or>
+    Or(Vec<SearchExpression>),
+}
+
+/// The negated condition of a [`SearchExpression::Not`].
+#[derive(Clone, Debug)]
+pub struct Not(pub Box<SearchExpression>);
+
+impl XmlSerialize for Not {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.0.serialize_child_nodes(writer)
+    }
+}
+
+/// The value against which a property is compared.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/fielduriorconstant>
+#[derive(Clone, Debug)]
+pub enum FieldURIOrConstant {
+    /// Another property of the same entity.
+    Path(PathToElement),
+
+    /// A constant value.
+    Constant(Constant),
+}
+
+impl XmlSerialize for FieldURIOrConstant {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        match self {
+            // `PathToElement` writes its own element, named after the kind of
+            // path.
+            Self::Path(path) => path.serialize_child_nodes(writer),
+            Self::Constant(constant) => constant.serialize_as_element(writer, "t:Constant"),
+        }
+    }
+}
+
+/// A constant value in a search expression.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/constant>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct Constant {
+    /// The value, in the string representation of the property's type.
+    #[xml_struct(attribute)]
+    pub value: String,
+}
+
+/// Which part of a string property must match a [`SearchExpression::Contains`]
+/// value.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains#containmentmode-attribute>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum ContainmentMode {
+    FullString,
+    Prefixed,
+    Substring,
+    PrefixOnWords,
+    ExactPhrase,
+}
+
+/// How strictly a string property is compared to a
+/// [`SearchExpression::Contains`] value.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/contains#containmentcomparison-attribute>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum ContainmentComparison {
+    Exact,
+    IgnoreCase,
+    IgnoreNonSpacingCharacters,
+    Loose,
+    IgnoreCaseAndNonSpacingCharacters,
+    LooseAndIgnoreCase,
+    LooseAndIgnoreNonSpace,
+    LooseAndIgnoreCaseAndIgnoreNonSpace,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_serialized_content, UnindexedFieldURI};
+
+    #[test]
+    fn serialize_restriction() {
+        let restriction = Restriction {
+            expression: SearchExpression::And(vec![
+                SearchExpression::Contains {
+                    containment_mode: Some(ContainmentMode::Substring),
+                    containment_comparison: Some(ContainmentComparison::IgnoreCase),
+                    path: PathToElement::FieldURI {
+                        field_URI: UnindexedFieldURI::PersonaDisplayName,
+                    },
+                    constant: Constant {
+                        value: "ali".to_string(),
+                    },
+                },
+                SearchExpression::Not(Not(Box::new(SearchExpression::IsEqualTo {
+                    path: PathToElement::FieldURI {
+                        field_URI: UnindexedFieldURI::PersonaCompanyName,
+                    },
+                    field_URI_or_constant: FieldURIOrConstant::Constant(Constant {
+                        value: "Example".to_string(),
+                    }),
+                }))),
+            ]),
+        };
+
+        let expected = r#"<Restriction><t:And><t:Contains ContainmentMode="Substring" ContainmentComparison="IgnoreCase"><t:FieldURI FieldURI="persona:DisplayName"/><t:Constant Value="ali"/></t:Contains><t:Not><t:IsEqualTo><t:FieldURI FieldURI="persona:CompanyName"/><t:FieldURIOrConstant><t:Constant Value="Example"/></t:FieldURIOrConstant></t:IsEqualTo></t:Not></t:And></Restriction>"#;
+
+        assert_serialized_content(&restriction, "Restriction", expected);
+    }
+}
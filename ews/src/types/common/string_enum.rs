@@ -0,0 +1,67 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! A macro for enums of well-known strings.
+
+/// Defines an enum of well-known identifiers, such as property or folder names,
+/// with an `Other` variant for identifiers not (yet) listed, and conversions to
+/// and from strings.
+macro_rules! string_enum {
+    (
+        $(#[$attr:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $uri:literal,)*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $uri, "`")]
+                $variant,
+            )*
+
+            /// An identifier not otherwise listed, such as one introduced in a
+            /// newer version of Exchange.
+            Other(String),
+        }
+
+        impl $name {
+            /// The string representation of the identifier.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $uri,)*
+                    Self::Other(uri) => uri,
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($uri => Self::$variant,)*
+                    _ => Self::Other(value.to_string()),
+                }
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::xml_struct::XmlSerializeAttr for $name {
+            fn serialize_as_attribute(
+                &self,
+                start_tag: &mut ::quick_xml::events::BytesStart,
+                name: &str,
+            ) {
+                start_tag.push_attribute((name, self.as_str()));
+            }
+        }
+    };
+}
+
+pub(crate) use string_enum;
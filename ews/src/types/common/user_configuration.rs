@@ -0,0 +1,98 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! User configuration objects, which store application settings as hidden
+//! items of a folder.
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    content::{decode_base64, encode_base64},
+    BaseFolderId, Error, ItemId,
+};
+
+/// The name and folder of a user configuration object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfigurationname>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct UserConfigurationName {
+    #[xml_struct(attribute)]
+    pub name: String,
+
+    /// The folder the object is stored in.
+    #[xml_struct(flatten)]
+    pub folder_id: BaseFolderId,
+}
+
+/// The parts of a user configuration object to include in a response.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfigurationproperties>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum UserConfigurationProperties {
+    Id,
+    Dictionary,
+    XmlData,
+    BinaryData,
+    All,
+}
+
+/// A user configuration object to create or update.
+///
+/// The dictionary of the object is not currently supported.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/userconfiguration>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct UserConfiguration {
+    #[xml_struct(ns_prefix = "t")]
+    pub user_configuration_name: UserConfigurationName,
+
+    /// The base64-encoded XML data of the object.
+    #[xml_struct(ns_prefix = "t")]
+    pub xml_data: Option<String>,
+
+    /// The base64-encoded binary data of the object.
+    #[xml_struct(ns_prefix = "t")]
+    pub binary_data: Option<String>,
+}
+
+impl UserConfiguration {
+    /// Creates an object with the given XML data.
+    pub fn with_xml_data(user_configuration_name: UserConfigurationName, xml_data: &[u8]) -> Self {
+        Self {
+            user_configuration_name,
+            xml_data: Some(encode_base64(xml_data)),
+            binary_data: None,
+        }
+    }
+}
+
+/// The contents of a user configuration object, as returned by
+/// [`GetUserConfiguration`].
+///
+/// [`GetUserConfiguration`]: crate::get_user_configuration::GetUserConfiguration
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UserConfigurationData {
+    pub item_id: Option<ItemId>,
+
+    /// The base64-encoded XML data of the object.
+    pub xml_data: Option<String>,
+
+    /// The base64-encoded binary data of the object.
+    pub binary_data: Option<String>,
+}
+
+impl UserConfigurationData {
+    /// Decodes the XML data of the object, if any.
+    pub fn decoded_xml_data(&self) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.xml_data.as_deref().map(decode_base64).transpose()?)
+    }
+
+    /// Decodes the binary data of the object, if any.
+    pub fn decoded_binary_data(&self) -> Result<Option<Vec<u8>>, Error> {
+        Ok(self.binary_data.as_deref().map(decode_base64).transpose()?)
+    }
+}
@@ -0,0 +1,371 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Commonly used extended MAPI properties.
+//!
+//! Tagged properties are identified by a 16-bit property ID, while named
+//! properties are identified by a property set and either a numeric ID or a
+//! name. Each constant documents the canonical name of the property in
+//! [MS-OXPROPS], and can be turned into an [`ExtendedFieldURI`] (or a
+//! [`PathToElement`]) with `into()`.
+//!
+//! [MS-OXPROPS]: https://learn.microsoft.com/en-us/openspecs/exchange_server_protocols/ms-oxprops/f6ab1613-aefe-447d-a49c-18217230b148
+
+use crate::{DistinguishedPropertySet, ExtendedFieldURI, PathToElement, PropertyType};
+
+/// A MAPI property identified by its property tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TaggedProperty {
+    /// The property ID, i.e. the upper 16 bits of the property tag.
+    pub id: u16,
+    pub property_type: PropertyType,
+}
+
+impl TaggedProperty {
+    pub const fn new(id: u16, property_type: PropertyType) -> Self {
+        Self { id, property_type }
+    }
+}
+
+impl From<TaggedProperty> for ExtendedFieldURI {
+    fn from(value: TaggedProperty) -> Self {
+        Self {
+            distinguished_property_set_id: None,
+            property_set_id: None,
+            property_tag: Some(format!("0x{:04X}", value.id)),
+            property_name: None,
+            property_id: None,
+            property_type: value.property_type,
+        }
+    }
+}
+
+impl From<TaggedProperty> for PathToElement {
+    fn from(value: TaggedProperty) -> Self {
+        ExtendedFieldURI::from(value).into()
+    }
+}
+
+/// The property set a named MAPI property belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PropertySet {
+    /// A property set with a well-known identifier.
+    Distinguished(DistinguishedPropertySet),
+
+    /// A property set identified by a GUID.
+    Guid(&'static str),
+}
+
+/// The identifier of a named MAPI property within its property set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NamedPropertyKey {
+    /// A numeric identifier, i.e. a dispatch ID.
+    Id(u32),
+
+    /// A string identifier.
+    Name(&'static str),
+}
+
+/// A MAPI property identified by its property set and name or ID.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NamedProperty {
+    pub property_set: PropertySet,
+    pub key: NamedPropertyKey,
+    pub property_type: PropertyType,
+}
+
+impl NamedProperty {
+    /// Creates a property identified by a numeric ID in a well-known property
+    /// set.
+    pub const fn with_id(
+        property_set: DistinguishedPropertySet,
+        id: u32,
+        property_type: PropertyType,
+    ) -> Self {
+        Self {
+            property_set: PropertySet::Distinguished(property_set),
+            key: NamedPropertyKey::Id(id),
+            property_type,
+        }
+    }
+
+    /// Creates a property identified by name in a well-known property set.
+    pub const fn with_name(
+        property_set: DistinguishedPropertySet,
+        name: &'static str,
+        property_type: PropertyType,
+    ) -> Self {
+        Self {
+            property_set: PropertySet::Distinguished(property_set),
+            key: NamedPropertyKey::Name(name),
+            property_type,
+        }
+    }
+}
+
+impl From<NamedProperty> for ExtendedFieldURI {
+    fn from(value: NamedProperty) -> Self {
+        let (distinguished_property_set_id, property_set_id) = match value.property_set {
+            PropertySet::Distinguished(set) => (Some(set), None),
+            PropertySet::Guid(guid) => (None, Some(guid.to_string())),
+        };
+
+        let (property_id, property_name) = match value.key {
+            NamedPropertyKey::Id(id) => (Some(id.to_string()), None),
+            NamedPropertyKey::Name(name) => (None, Some(name.to_string())),
+        };
+
+        Self {
+            distinguished_property_set_id,
+            property_set_id,
+            property_tag: None,
+            property_name,
+            property_id,
+            property_type: value.property_type,
+        }
+    }
+}
+
+impl From<NamedProperty> for PathToElement {
+    fn from(value: NamedProperty) -> Self {
+        ExtendedFieldURI::from(value).into()
+    }
+}
+
+/// Identifies an Internet message header of an item, such as `X-Mailer`.
+///
+/// Header values are always strings, and header names are case-insensitive.
+pub fn internet_header(name: &str) -> ExtendedFieldURI {
+    ExtendedFieldURI {
+        distinguished_property_set_id: Some(DistinguishedPropertySet::InternetHeaders),
+        property_set_id: None,
+        property_tag: None,
+        property_name: Some(name.to_string()),
+        property_id: None,
+        property_type: PropertyType::String,
+    }
+}
+
+/// `PidTagSubject` (`PR_SUBJECT`): the subject of an item.
+pub const SUBJECT: TaggedProperty = TaggedProperty::new(0x0037, PropertyType::String);
+
+/// `PidTagConversationIndex` (`PR_CONVERSATION_INDEX`): the position of a
+/// message within its conversation thread.
+pub const CONVERSATION_INDEX: TaggedProperty = TaggedProperty::new(0x0071, PropertyType::Binary);
+
+/// `PidTagTransportMessageHeaders` (`PR_TRANSPORT_MESSAGE_HEADERS`): the raw
+/// Internet headers of a received message.
+pub const TRANSPORT_MESSAGE_HEADERS: TaggedProperty =
+    TaggedProperty::new(0x007D, PropertyType::String);
+
+/// `PidTagMessageFlags` (`PR_MESSAGE_FLAGS`): a bitmask of the status of a
+/// message, such as whether it has been read or sent.
+///
+/// See <https://learn.microsoft.com/en-us/office/client-developer/outlook/mapi/pidtagmessageflags-canonical-property>
+pub const MESSAGE_FLAGS: TaggedProperty = TaggedProperty::new(0x0E07, PropertyType::Integer);
+
+/// `PidTagMessageSize` (`PR_MESSAGE_SIZE`): the size in bytes of an item.
+pub const MESSAGE_SIZE: TaggedProperty = TaggedProperty::new(0x0E08, PropertyType::Integer);
+
+/// `PidTagParentEntryId` (`PR_PARENT_ENTRYID`): the entry ID of the folder
+/// containing an item.
+pub const PARENT_ENTRYID: TaggedProperty = TaggedProperty::new(0x0E09, PropertyType::Binary);
+
+/// `PidTagRecordKey` (`PR_RECORD_KEY`): a binary identifier of an object,
+/// used for comparisons.
+pub const RECORD_KEY: TaggedProperty = TaggedProperty::new(0x0FF9, PropertyType::Binary);
+
+/// `PidTagEntryId` (`PR_ENTRYID`): the MAPI identifier of an item or folder.
+pub const ENTRYID: TaggedProperty = TaggedProperty::new(0x0FFF, PropertyType::Binary);
+
+/// `PidTagInternetMessageId` (`PR_INTERNET_MESSAGE_ID`): the `Message-ID`
+/// header of a message.
+pub const INTERNET_MESSAGE_ID: TaggedProperty = TaggedProperty::new(0x1035, PropertyType::String);
+
+/// `PidTagInternetReferences` (`PR_INTERNET_REFERENCES`): the `References`
+/// header of a message.
+pub const INTERNET_REFERENCES: TaggedProperty = TaggedProperty::new(0x1039, PropertyType::String);
+
+/// `PidTagInReplyToId` (`PR_IN_REPLY_TO_ID`): the `In-Reply-To` header of a
+/// message.
+pub const IN_REPLY_TO_ID: TaggedProperty = TaggedProperty::new(0x1042, PropertyType::String);
+
+/// `PidTagIconIndex` (`PR_ICON_INDEX`): the icon displayed for an item.
+pub const ICON_INDEX: TaggedProperty = TaggedProperty::new(0x1080, PropertyType::Integer);
+
+/// `PidTagLastVerbExecuted` (`PR_LAST_VERB_EXECUTED`): the last action taken
+/// on a message, such as replying to or forwarding it.
+///
+/// See <https://learn.microsoft.com/en-us/office/client-developer/outlook/mapi/pidtaglastverbexecuted-canonical-property>
+pub const LAST_VERB_EXECUTED: TaggedProperty = TaggedProperty::new(0x1081, PropertyType::Integer);
+
+/// `PidTagLastVerbExecutionTime` (`PR_LAST_VERB_EXECUTION_TIME`): when
+/// [`LAST_VERB_EXECUTED`] was last set.
+pub const LAST_VERB_EXECUTION_TIME: TaggedProperty =
+    TaggedProperty::new(0x1082, PropertyType::SystemTime);
+
+/// `PidTagFlagStatus` (`PR_FLAG_STATUS`): the follow-up flag status of an
+/// item, i.e. unflagged (0), complete (1) or flagged (2).
+///
+/// See <https://learn.microsoft.com/en-us/office/client-developer/outlook/mapi/pidtagflagstatus-canonical-property>
+pub const FLAG_STATUS: TaggedProperty = TaggedProperty::new(0x1090, PropertyType::Integer);
+
+/// `PidTagFlagCompleteTime` (`PR_FLAG_COMPLETE_TIME`): when a follow-up flag
+/// was marked complete.
+pub const FLAG_COMPLETE_TIME: TaggedProperty =
+    TaggedProperty::new(0x1091, PropertyType::SystemTime);
+
+/// `PidTagFollowupIcon` (`PR_FOLLOWUP_ICON`): the color of the follow-up flag
+/// of an item.
+pub const FOLLOWUP_ICON: TaggedProperty = TaggedProperty::new(0x1095, PropertyType::Integer);
+
+/// `PidTagAttributeHidden` (`PR_ATTR_HIDDEN`): whether an item or folder is
+/// hidden from users.
+pub const ATTR_HIDDEN: TaggedProperty = TaggedProperty::new(0x10F4, PropertyType::Boolean);
+
+/// `PidTagSearchKey` (`PR_SEARCH_KEY`): a binary key used to find related
+/// copies of a message.
+pub const SEARCH_KEY: TaggedProperty = TaggedProperty::new(0x300B, PropertyType::Binary);
+
+/// `PidTagContainerClass` (`PR_CONTAINER_CLASS`): the kind of items a folder
+/// contains, such as `IPF.Note`.
+pub const CONTAINER_CLASS: TaggedProperty = TaggedProperty::new(0x3613, PropertyType::String);
+
+/// `PidTagSourceKey` (`PR_SOURCE_KEY`): an identifier of an object which is
+/// stable across synchronization.
+pub const SOURCE_KEY: TaggedProperty = TaggedProperty::new(0x65E0, PropertyType::Binary);
+
+/// `PidTagChangeKey` (`PR_CHANGE_KEY`): an identifier of the last change to
+/// an object.
+pub const CHANGE_KEY: TaggedProperty = TaggedProperty::new(0x65E2, PropertyType::Binary);
+
+/// `PidLidReminderTime`: when the event a reminder is for happens.
+pub const REMINDER_TIME: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Common,
+    0x8502,
+    PropertyType::SystemTime,
+);
+
+/// `PidLidReminderSet`: whether a reminder is set on an item.
+///
+/// See <https://learn.microsoft.com/en-us/office/client-developer/outlook/mapi/pidlidreminderset-canonical-property>
+pub const REMINDER_SET: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Common,
+    0x8503,
+    PropertyType::Boolean,
+);
+
+/// `PidLidFlagRequest`: the requested follow-up action of a flagged item,
+/// such as "Follow up".
+pub const FLAG_REQUEST: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Common,
+    0x8530,
+    PropertyType::String,
+);
+
+/// `PidLidReminderSignalTime`: when a reminder is displayed.
+pub const REMINDER_SIGNAL_TIME: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Common,
+    0x8560,
+    PropertyType::SystemTime,
+);
+
+/// `PidLidTaskStatus`: the status of a task or of a flagged item.
+pub const TASK_STATUS: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Task,
+    0x8101,
+    PropertyType::Integer,
+);
+
+/// `PidLidPercentComplete`: how much of a task is complete, between 0.0 and
+/// 1.0.
+pub const PERCENT_COMPLETE: NamedProperty =
+    NamedProperty::with_id(DistinguishedPropertySet::Task, 0x8102, PropertyType::Double);
+
+/// `PidLidTaskStartDate`: when work on a task or flagged item should start.
+pub const TASK_START_DATE: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Task,
+    0x8104,
+    PropertyType::SystemTime,
+);
+
+/// `PidLidTaskDueDate`: when a task or flagged item is due.
+pub const TASK_DUE_DATE: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Task,
+    0x8105,
+    PropertyType::SystemTime,
+);
+
+/// `PidLidTaskComplete`: whether a task or flagged item is complete.
+pub const TASK_COMPLETE: NamedProperty = NamedProperty::with_id(
+    DistinguishedPropertySet::Task,
+    0x811C,
+    PropertyType::Boolean,
+);
+
+/// `PidNameKeywords`: the categories of an item.
+///
+/// See <https://learn.microsoft.com/en-us/office/client-developer/outlook/mapi/pidnamekeywords-canonical-property>
+pub const KEYWORDS: NamedProperty = NamedProperty::with_name(
+    DistinguishedPropertySet::PublicStrings,
+    "Keywords",
+    PropertyType::StringArray,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_serialized_content;
+
+    #[test]
+    fn tagged_property_field_uri() {
+        let uri: ExtendedFieldURI = LAST_VERB_EXECUTED.into();
+        assert_serialized_content(
+            &uri,
+            "t:ExtendedFieldURI",
+            r#"<t:ExtendedFieldURI PropertyTag="0x1081" PropertyType="Integer"/>"#,
+        );
+
+        let uri: ExtendedFieldURI = SUBJECT.into();
+        assert_eq!(uri.property_tag.as_deref(), Some("0x0037"));
+    }
+
+    #[test]
+    fn named_property_field_uri() {
+        let uri: ExtendedFieldURI = REMINDER_SET.into();
+        assert_serialized_content(
+            &uri,
+            "t:ExtendedFieldURI",
+            r#"<t:ExtendedFieldURI DistinguishedPropertySetId="Common" PropertyId="34051" PropertyType="Boolean"/>"#,
+        );
+
+        let uri: ExtendedFieldURI = KEYWORDS.into();
+        assert_serialized_content(
+            &uri,
+            "t:ExtendedFieldURI",
+            r#"<t:ExtendedFieldURI DistinguishedPropertySetId="PublicStrings" PropertyName="Keywords" PropertyType="StringArray"/>"#,
+        );
+
+        let uri: ExtendedFieldURI = NamedProperty {
+            property_set: PropertySet::Guid("00020386-0000-0000-C000-000000000046"),
+            key: NamedPropertyKey::Name("x-custom"),
+            property_type: PropertyType::String,
+        }
+        .into();
+        assert_serialized_content(
+            &uri,
+            "t:ExtendedFieldURI",
+            r#"<t:ExtendedFieldURI PropertySetId="00020386-0000-0000-C000-000000000046" PropertyName="x-custom" PropertyType="String"/>"#,
+        );
+    }
+
+    #[test]
+    fn internet_header_field_uri() {
+        assert_serialized_content(
+            &internet_header("X-Mailer"),
+            "t:ExtendedFieldURI",
+            r#"<t:ExtendedFieldURI DistinguishedPropertySetId="InternetHeaders" PropertyName="X-Mailer" PropertyType="String"/>"#,
+        );
+    }
+}
@@ -23,8 +23,8 @@ mod test {
     use crate::{
         copy_folder::{CopyFolder, CopyFolderResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content},
-        BaseFolderId, CopyMoveFolderData, Folder, FolderId, FolderResponseMessage, Folders,
-        ResponseClass,
+        BaseFolderId, CopyMoveFolderData, DistinguishedFolderName, Folder, FolderId,
+        FolderResponseMessage, Folders, ResponseClass,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let copy_folder = CopyFolder {
             inner: CopyMoveFolderData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "inbox".to_string(),
+                    id: DistinguishedFolderName::Inbox,
                     change_key: None,
+                    mailbox: None,
                 },
                 folder_ids: vec![
                     BaseFolderId::FolderId {
@@ -85,6 +86,7 @@ mod test {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
@@ -23,8 +23,8 @@ mod test {
     use crate::{
         copy_item::{CopyItem, CopyItemResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content},
-        BaseFolderId, BaseItemId, CopyMoveItemData, ItemId, ItemResponseMessage, Items, Message,
-        RealItem, ResponseClass, ResponseMessages,
+        BaseFolderId, BaseItemId, CopyMoveItemData, DistinguishedFolderName, ItemId,
+        ItemResponseMessage, Items, Message, RealItem, ResponseClass, ResponseMessages,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let request = CopyItem {
             inner: CopyMoveItemData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "inbox".to_string(),
+                    id: DistinguishedFolderName::Inbox,
                     change_key: None,
+                    mailbox: None,
                 },
                 item_ids: vec![BaseItemId::ItemId {
                     id: "AS4AUnV=".to_string(),
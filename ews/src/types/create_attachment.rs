@@ -0,0 +1,178 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Attachment, Attachments, BaseItemId, MESSAGES_NS_URI};
+
+/// A request to add one or more attachments to an existing item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createattachment>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(CreateAttachmentResponseMessage)]
+pub struct CreateAttachment {
+    /// The item to which the attachments are added.
+    pub parent_item_id: BaseItemId,
+
+    /// The attachments to create.
+    ///
+    /// Attachment IDs must be omitted.
+    pub attachments: Vec<Attachment>,
+}
+
+/// A response to a request for the creation of a single attachment.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/createattachmentresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateAttachmentResponseMessage {
+    /// The created attachment.
+    ///
+    /// Only the [`AttachmentId`] of the attachment is included, which
+    /// contains the new change key of the parent item in
+    /// [`root_item_change_key`].
+    ///
+    /// [`AttachmentId`]: crate::AttachmentId
+    /// [`root_item_change_key`]: crate::AttachmentId::root_item_change_key
+    pub attachments: Attachments,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        AttachmentId, AttachmentItem, Message, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_create_attachment() {
+        let create_attachment = CreateAttachment {
+            parent_item_id: BaseItemId::ItemId {
+                id: "AAMkADk=".to_string(),
+                change_key: Some("CQAAABYA".to_string()),
+            },
+            attachments: vec![Attachment::FileAttachment {
+                attachment_id: None,
+                name: "notes.txt".to_string(),
+                content_type: "text/plain".to_string(),
+                content_id: None,
+                content_location: None,
+                size: None,
+                last_modified_time: None,
+                is_inline: Some(false),
+                is_contact_photo: None,
+                content: Some("aGVsbG8=".to_string()),
+            }],
+        };
+
+        let expected = r#"<CreateAttachment xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><ParentItemId><t:ItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/></ParentItemId><Attachments><t:FileAttachment><t:Name>notes.txt</t:Name><t:ContentType>text/plain</t:ContentType><t:IsInline>false</t:IsInline><t:Content>aGVsbG8=</t:Content></t:FileAttachment></Attachments></CreateAttachment>"#;
+
+        assert_serialized_content(&create_attachment, "CreateAttachment", expected);
+    }
+
+    #[test]
+    fn serialize_create_item_attachment() {
+        let create_attachment = CreateAttachment {
+            parent_item_id: BaseItemId::ItemId {
+                id: "AAMkADk=".to_string(),
+                change_key: None,
+            },
+            attachments: vec![Attachment::ItemAttachment {
+                attachment_id: None,
+                name: "Forwarded message".to_string(),
+                content_type: "message/rfc822".to_string(),
+                content_id: None,
+                content_location: None,
+                size: None,
+                last_modified_time: None,
+                is_inline: None,
+                content: Some(AttachmentItem::Message(Message {
+                    subject: Some("Hello".to_string()),
+                    ..Default::default()
+                })),
+            }],
+        };
+
+        let expected = r#"<CreateAttachment xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><ParentItemId><t:ItemId Id="AAMkADk="/></ParentItemId><Attachments><t:ItemAttachment><t:Name>Forwarded message</t:Name><t:ContentType>message/rfc822</t:ContentType><t:Message><t:Subject>Hello</t:Subject></t:Message></t:ItemAttachment></Attachments></CreateAttachment>"#;
+
+        assert_serialized_content(&create_attachment, "CreateAttachment", expected);
+    }
+
+    #[test]
+    fn deserialize_create_attachment_response() {
+        let content = r#"<m:CreateAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:CreateAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:FileAttachment>
+                                <t:AttachmentId Id="AAMkADk=" RootItemId="AAMkADl=" RootItemChangeKey="CQAAABYB" />
+                            </t:FileAttachment>
+                        </m:Attachments>
+                    </m:CreateAttachmentResponseMessage>
+                    <m:CreateAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:ItemAttachment>
+                                <t:AttachmentId Id="AAMkADm=" RootItemId="AAMkADl=" RootItemChangeKey="CQAAABYC" />
+                            </t:ItemAttachment>
+                        </m:Attachments>
+                    </m:CreateAttachmentResponseMessage>
+                </m:ResponseMessages>
+            </m:CreateAttachmentResponse>"#;
+
+        let expected = CreateAttachmentResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![
+                    ResponseClass::Success(CreateAttachmentResponseMessage {
+                        attachments: Attachments {
+                            inner: vec![Attachment::FileAttachment {
+                                attachment_id: Some(AttachmentId {
+                                    id: "AAMkADk=".to_string(),
+                                    root_item_id: Some("AAMkADl=".to_string()),
+                                    root_item_change_key: Some("CQAAABYB".to_string()),
+                                }),
+                                name: String::new(),
+                                content_type: String::new(),
+                                content_id: None,
+                                content_location: None,
+                                size: None,
+                                last_modified_time: None,
+                                is_inline: None,
+                                is_contact_photo: None,
+                                content: None,
+                            }],
+                        },
+                    }),
+                    ResponseClass::Success(CreateAttachmentResponseMessage {
+                        attachments: Attachments {
+                            inner: vec![Attachment::ItemAttachment {
+                                attachment_id: Some(AttachmentId {
+                                    id: "AAMkADm=".to_string(),
+                                    root_item_id: Some("AAMkADl=".to_string()),
+                                    root_item_change_key: Some("CQAAABYC".to_string()),
+                                }),
+                                name: String::new(),
+                                content_type: String::new(),
+                                content_id: None,
+                                content_location: None,
+                                size: None,
+                                last_modified_time: None,
+                                is_inline: None,
+                                content: None,
+                            }],
+                        },
+                    }),
+                ],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -5,7 +5,10 @@
 use ews_proc_macros::operation_response;
 use xml_struct::XmlSerialize;
 
-use crate::{BaseFolderId, ItemResponseMessage, MessageDisposition, RealItem, MESSAGES_NS_URI};
+use crate::{
+    BaseFolderId, ItemResponseMessage, MessageDisposition, RealItem, ResponseObject,
+    MESSAGES_NS_URI,
+};
 
 /// A request to create (and optionally send) one or more Exchange items.
 ///
@@ -34,7 +37,49 @@ pub struct CreateItem {
     pub saved_item_folder_id: Option<BaseFolderId>,
 
     /// The item or items to create.
-    pub items: Vec<RealItem>,
+    pub items: Vec<ItemToCreate>,
+}
+
+/// An item to create with a [`CreateItem`] request.
+// Items are held unboxed everywhere else, and boxing them here would only
+// make requests more awkward to build.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ItemToCreate {
+    /// A new item.
+    Item(RealItem),
+
+    /// An item created in response to an existing one, such as a reply.
+    ResponseObject(ResponseObject),
+}
+
+impl From<RealItem> for ItemToCreate {
+    fn from(value: RealItem) -> Self {
+        Self::Item(value)
+    }
+}
+
+impl From<ResponseObject> for ItemToCreate {
+    fn from(value: ResponseObject) -> Self {
+        Self::ResponseObject(value)
+    }
+}
+
+impl XmlSerialize for ItemToCreate {
+    /// Serializes the item as the element named after its type, with no
+    /// element of its own.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        match self {
+            Self::Item(item) => item.serialize_child_nodes(writer),
+            Self::ResponseObject(response_object) => response_object.serialize_child_nodes(writer),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,92 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{RequestAttachmentId, MESSAGES_NS_URI};
+
+/// A request to delete one or more attachments.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteattachment>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(DeleteAttachmentResponseMessage)]
+pub struct DeleteAttachment {
+    /// The attachments to delete.
+    pub attachment_ids: Vec<RequestAttachmentId>,
+}
+
+/// A response to a request for the deletion of a single attachment.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteattachmentresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteAttachmentResponseMessage {
+    /// The item from which the attachment was deleted.
+    pub root_item_id: RootItemId,
+}
+
+/// The identifier of an item from which an attachment was deleted.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/rootitemid>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct RootItemId {
+    /// The unique identifier of the item.
+    #[serde(rename = "@RootItemId")]
+    pub root_item_id: String,
+
+    /// The new change key of the item.
+    #[serde(rename = "@RootItemChangeKey")]
+    pub root_item_change_key: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_delete_attachment() {
+        let delete_attachment = DeleteAttachment {
+            attachment_ids: vec![RequestAttachmentId::AttachmentId {
+                id: "AAMkADk=".to_string(),
+            }],
+        };
+
+        let expected = r#"<DeleteAttachment xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><AttachmentIds><t:AttachmentId Id="AAMkADk="/></AttachmentIds></DeleteAttachment>"#;
+
+        assert_serialized_content(&delete_attachment, "DeleteAttachment", expected);
+    }
+
+    #[test]
+    fn deserialize_delete_attachment_response() {
+        let content = r#"<m:DeleteAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:DeleteAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:RootItemId RootItemId="AAMkADl=" RootItemChangeKey="CQAAABYD" />
+                    </m:DeleteAttachmentResponseMessage>
+                </m:ResponseMessages>
+            </m:DeleteAttachmentResponse>"#;
+
+        let expected = DeleteAttachmentResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(DeleteAttachmentResponseMessage {
+                    root_item_id: RootItemId {
+                        root_item_id: "AAMkADl=".to_string(),
+                        root_item_change_key: "CQAAABYD".to_string(),
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,263 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use std::collections::HashSet;
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Mailbox, MailboxType, MESSAGES_NS_URI};
+
+/// A request to list the members of a distribution list.
+///
+/// Public distribution lists are identified by their email address, while
+/// private distribution lists (contact groups) are identified by the
+/// [`ItemId`] of the list in the mailbox.
+///
+/// Only direct members are returned by Exchange; nested lists must be expanded
+/// with further requests. See [`expand_recursively`] for a helper to do so.
+///
+/// [`ItemId`]: crate::ItemId
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/expanddl>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ExpandDLResponseMessage)]
+pub struct ExpandDL {
+    /// The distribution list to expand.
+    pub mailbox: Mailbox,
+}
+
+/// A response to a request for the expansion of a single distribution list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/expanddlresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ExpandDLResponseMessage {
+    /// The members of the distribution list.
+    #[serde(rename = "DLExpansion")]
+    pub dl_expansion: DLExpansion,
+}
+
+/// The direct members of an expanded distribution list.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/dlexpansion>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct DLExpansion {
+    /// The total number of members in the list.
+    #[serde(rename = "@TotalItemsInView")]
+    pub total_items_in_view: Option<usize>,
+
+    /// Whether the response includes the last member of the list.
+    #[serde(rename = "@IncludesLastItemInRange")]
+    pub includes_last_item_in_range: Option<bool>,
+
+    /// The members of the list.
+    #[serde(rename = "Mailbox", default)]
+    pub mailboxes: Vec<Mailbox>,
+}
+
+/// Whether the given mailbox represents a distribution list which can be
+/// expanded with [`ExpandDL`].
+pub fn is_distribution_list(mailbox: &Mailbox) -> bool {
+    matches!(
+        mailbox.mailbox_type,
+        Some(MailboxType::PublicDL | MailboxType::PrivateDL)
+    )
+}
+
+/// Recursively expands a distribution list into the mailboxes of its members.
+///
+/// `expand` is called once for each distribution list encountered, starting
+/// with `list`, and should return the direct members of that list, typically
+/// by performing an [`ExpandDL`] request.
+///
+/// Each list is only expanded once, so lists which contain themselves (either
+/// directly or through other lists) do not cause infinite recursion. Mailboxes
+/// which are members of several lists are only included once in the result.
+pub fn expand_recursively<F, E>(list: &Mailbox, mut expand: F) -> Result<Vec<Mailbox>, E>
+where
+    F: FnMut(&Mailbox) -> Result<Vec<Mailbox>, E>,
+{
+    let mut seen = HashSet::new();
+    if let Some(key) = mailbox_key(list) {
+        seen.insert(key);
+    }
+
+    let mut members = Vec::new();
+    expand_into(list, &mut expand, &mut seen, &mut members)?;
+
+    Ok(members)
+}
+
+fn expand_into<F, E>(
+    list: &Mailbox,
+    expand: &mut F,
+    seen: &mut HashSet<String>,
+    members: &mut Vec<Mailbox>,
+) -> Result<(), E>
+where
+    F: FnMut(&Mailbox) -> Result<Vec<Mailbox>, E>,
+{
+    for mailbox in expand(list)? {
+        // Mailboxes with neither an ID nor an address can't be compared with
+        // one another, so we always keep them.
+        let is_new = match mailbox_key(&mailbox) {
+            Some(key) => seen.insert(key),
+            None => true,
+        };
+
+        if !is_new {
+            continue;
+        }
+
+        if is_distribution_list(&mailbox) {
+            expand_into(&mailbox, expand, seen, members)?;
+        } else {
+            members.push(mailbox);
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a value identifying the given mailbox, for the purpose of detecting
+/// duplicates and cycles.
+fn mailbox_key(mailbox: &Mailbox) -> Option<String> {
+    // Private distribution lists and contacts are identified by their item ID,
+    // everything else by address.
+    mailbox
+        .item_id
+        .as_ref()
+        .map(|item_id| item_id.id.clone())
+        .or_else(|| {
+            mailbox
+                .email_address
+                .as_ref()
+                .map(|address| address.to_ascii_lowercase())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ItemId, ResponseClass, ResponseMessages,
+    };
+
+    fn mailbox(address: &str, mailbox_type: MailboxType) -> Mailbox {
+        Mailbox {
+            email_address: Some(address.to_string()),
+            mailbox_type: Some(mailbox_type),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn serialize_expand_dl() {
+        let expand_dl = ExpandDL {
+            mailbox: Mailbox {
+                mailbox_type: Some(MailboxType::PrivateDL),
+                item_id: Some(ItemId {
+                    id: "AAMkADl=".to_string(),
+                    change_key: None,
+                }),
+                ..Default::default()
+            },
+        };
+
+        let expected = r#"<ExpandDL xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><Mailbox><t:MailboxType>PrivateDL</t:MailboxType><t:ItemId Id="AAMkADl="/></Mailbox></ExpandDL>"#;
+
+        assert_serialized_content(&expand_dl, "ExpandDL", expected);
+    }
+
+    #[test]
+    fn deserialize_expand_dl_response() {
+        let content = r#"<m:ExpandDLResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:ExpandDLResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:DLExpansion TotalItemsInView="2" IncludesLastItemInRange="true">
+                            <t:Mailbox>
+                                <t:Name>Alice Test</t:Name>
+                                <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                <t:RoutingType>SMTP</t:RoutingType>
+                                <t:MailboxType>Mailbox</t:MailboxType>
+                            </t:Mailbox>
+                            <t:Mailbox>
+                                <t:Name>Sub Team</t:Name>
+                                <t:EmailAddress>subteam@example.com</t:EmailAddress>
+                                <t:RoutingType>SMTP</t:RoutingType>
+                                <t:MailboxType>PublicDL</t:MailboxType>
+                            </t:Mailbox>
+                        </m:DLExpansion>
+                    </m:ExpandDLResponseMessage>
+                </m:ResponseMessages>
+            </m:ExpandDLResponse>"#;
+
+        let expected = ExpandDLResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(ExpandDLResponseMessage {
+                    dl_expansion: DLExpansion {
+                        total_items_in_view: Some(2),
+                        includes_last_item_in_range: Some(true),
+                        mailboxes: vec![
+                            Mailbox {
+                                name: Some("Alice Test".to_string()),
+                                routing_type: Some("SMTP".to_string()),
+                                ..mailbox("alice@example.com", MailboxType::Mailbox)
+                            },
+                            Mailbox {
+                                name: Some("Sub Team".to_string()),
+                                routing_type: Some("SMTP".to_string()),
+                                ..mailbox("subteam@example.com", MailboxType::PublicDL)
+                            },
+                        ],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn expand_recursively_handles_cycles() {
+        let team = mailbox("team@example.com", MailboxType::PublicDL);
+        let sub_team = mailbox("subteam@example.com", MailboxType::PublicDL);
+        let alice = mailbox("alice@example.com", MailboxType::Mailbox);
+        let bob = mailbox("bob@example.com", MailboxType::Mailbox);
+
+        let mut expanded = vec![];
+        let members = expand_recursively(&team, |list| {
+            let address = list.email_address.clone().unwrap();
+            expanded.push(address.clone());
+
+            // `team` and `sub_team` both contain each other, and share a
+            // member.
+            let members = match address.as_str() {
+                "team@example.com" => vec![alice.clone(), sub_team.clone()],
+                "subteam@example.com" => vec![team.clone(), bob.clone(), alice.clone()],
+                _ => panic!("unexpected list {address}"),
+            };
+
+            Ok::<_, Infallible>(members)
+        })
+        .unwrap();
+
+        assert_eq!(members, vec![alice, bob]);
+        assert_eq!(
+            expanded,
+            vec![
+                "team@example.com".to_string(),
+                "subteam@example.com".to_string()
+            ]
+        );
+    }
+}
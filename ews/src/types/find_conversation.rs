@@ -0,0 +1,260 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    ArrayOfStrings, BaseFolderId, DateTime, FlagStatus, Importance, ItemId, SortOrder, View,
+    MESSAGES_NS_URI,
+};
+
+/// A request to list the conversations in a folder.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findconversation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(FindConversationResponseMessage, single_message)]
+pub struct FindConversation {
+    /// The page of conversations to return.
+    ///
+    /// Only [`View::IndexedPageItemView`] is supported by this operation.
+    #[xml_struct(flatten)]
+    pub view: Option<View>,
+
+    /// The order in which conversations are returned.
+    pub sort_order: Option<SortOrder>,
+
+    /// The folder to list the conversations of.
+    pub parent_folder_id: BaseFolderId,
+
+    /// A search query, in Advanced Query Syntax, conversations must match to
+    /// be included in the results.
+    ///
+    /// This was introduced in Exchange 2013.
+    pub query_string: Option<String>,
+}
+
+/// The result of a [`FindConversation`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findconversationresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FindConversationResponseMessage {
+    /// The conversations in the requested page.
+    pub conversations: Conversations,
+
+    /// The total number of conversations matching the request.
+    pub total_conversations_in_view: Option<usize>,
+
+    /// The offset to request the next page at.
+    pub indexed_offset: Option<usize>,
+}
+
+/// A list of conversations.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversations-ex15websvcsotherref>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Conversations {
+    #[serde(rename = "Conversation", default)]
+    pub inner: Vec<Conversation>,
+}
+
+/// A summary of a conversation.
+///
+/// Properties without a `global_` prefix only take the items in the folder
+/// being listed into account, while those with it cover the whole mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversation-ex15websvcsotherref>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Conversation {
+    /// The identifier of the conversation, as found in the `conversation_id`
+    /// of its messages.
+    pub conversation_id: ItemId,
+
+    pub conversation_topic: Option<String>,
+
+    /// The display names of the recipients of the conversation's messages.
+    pub unique_recipients: Option<ArrayOfStrings>,
+    pub global_unique_recipients: Option<ArrayOfStrings>,
+
+    /// The display names of the senders of the conversation's unread
+    /// messages.
+    pub unique_unread_senders: Option<ArrayOfStrings>,
+    pub global_unique_unread_senders: Option<ArrayOfStrings>,
+
+    /// The display names of the senders of the conversation's messages.
+    pub unique_senders: Option<ArrayOfStrings>,
+    pub global_unique_senders: Option<ArrayOfStrings>,
+
+    /// When the most recent message of the conversation was received.
+    pub last_delivery_time: Option<DateTime>,
+    pub global_last_delivery_time: Option<DateTime>,
+
+    pub categories: Option<ArrayOfStrings>,
+    pub global_categories: Option<ArrayOfStrings>,
+
+    pub flag_status: Option<FlagStatus>,
+    pub global_flag_status: Option<FlagStatus>,
+
+    pub has_attachments: Option<bool>,
+    pub global_has_attachments: Option<bool>,
+
+    pub message_count: Option<u32>,
+    pub global_message_count: Option<u32>,
+
+    pub unread_count: Option<u32>,
+    pub global_unread_count: Option<u32>,
+
+    /// The total size of the conversation's messages, in bytes.
+    pub size: Option<usize>,
+    pub global_size: Option<usize>,
+
+    pub importance: Option<Importance>,
+    pub global_importance: Option<Importance>,
+
+    /// The conversation's messages.
+    pub item_ids: Option<ItemIds>,
+    pub global_item_ids: Option<ItemIds>,
+}
+
+/// A list of item identifiers.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ItemIds {
+    #[serde(rename = "ItemId", default)]
+    pub inner: Vec<ItemId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BasePoint, DistinguishedFolderName, FieldOrder, PathToElement, ResponseClass,
+        SortDirection, UnindexedFieldURI,
+    };
+
+    #[test]
+    fn serialize_find_conversation() {
+        let find_conversation = FindConversation {
+            view: Some(View::IndexedPageItemView {
+                max_entries_returned: Some(10),
+                base_point: BasePoint::Beginning,
+                offset: 0,
+            }),
+            sort_order: Some(SortOrder(vec![FieldOrder {
+                order: SortDirection::Descending,
+                path: PathToElement::FieldURI {
+                    field_URI: UnindexedFieldURI::ConversationLastDeliveryTime,
+                },
+            }])),
+            parent_folder_id: BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Inbox,
+                change_key: None,
+                mailbox: None,
+            },
+            query_string: Some("subject:quarterly".to_string()),
+        };
+
+        let expected = r#"<FindConversation xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><IndexedPageItemView MaxEntriesReturned="10" BasePoint="Beginning" Offset="0"/><SortOrder><t:FieldOrder Order="Descending"><t:FieldURI FieldURI="conversation:LastDeliveryTime"/></t:FieldOrder></SortOrder><ParentFolderId><t:DistinguishedFolderId Id="inbox"/></ParentFolderId><QueryString>subject:quarterly</QueryString></FindConversation>"#;
+
+        assert_serialized_content(&find_conversation, "FindConversation", expected);
+    }
+
+    #[test]
+    fn deserialize_find_conversation_response() {
+        let content = r#"<FindConversationResponse ResponseClass="Success"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <ResponseCode>NoError</ResponseCode>
+                <Conversations>
+                    <t:Conversation>
+                        <t:ConversationId Id="AAQkADk=" />
+                        <t:ConversationTopic>Quarterly report</t:ConversationTopic>
+                        <t:UniqueRecipients>
+                            <t:String>Alice Test</t:String>
+                            <t:String>Bob Test</t:String>
+                        </t:UniqueRecipients>
+                        <t:UniqueSenders>
+                            <t:String>Carol Test</t:String>
+                        </t:UniqueSenders>
+                        <t:LastDeliveryTime>2024-05-02T09:12:44Z</t:LastDeliveryTime>
+                        <t:FlagStatus>Flagged</t:FlagStatus>
+                        <t:HasAttachments>false</t:HasAttachments>
+                        <t:MessageCount>2</t:MessageCount>
+                        <t:GlobalMessageCount>3</t:GlobalMessageCount>
+                        <t:UnreadCount>1</t:UnreadCount>
+                        <t:Importance>Normal</t:Importance>
+                        <t:ItemIds>
+                            <t:ItemId Id="AAMkADk=" ChangeKey="CQAAAA==" />
+                            <t:ItemId Id="AAMkADl=" ChangeKey="CQAAAB==" />
+                        </t:ItemIds>
+                    </t:Conversation>
+                </Conversations>
+                <TotalConversationsInView>1</TotalConversationsInView>
+                <IndexedOffset>1</IndexedOffset>
+            </FindConversationResponse>"#;
+
+        let expected = FindConversationResponse {
+            response_message: ResponseClass::Success(FindConversationResponseMessage {
+                conversations: Conversations {
+                    inner: vec![Conversation {
+                        conversation_id: ItemId {
+                            id: "AAQkADk=".to_string(),
+                            change_key: None,
+                        },
+                        conversation_topic: Some("Quarterly report".to_string()),
+                        unique_recipients: Some(ArrayOfStrings {
+                            inner: vec!["Alice Test".to_string(), "Bob Test".to_string()],
+                        }),
+                        global_unique_recipients: None,
+                        unique_unread_senders: None,
+                        global_unique_unread_senders: None,
+                        unique_senders: Some(ArrayOfStrings {
+                            inner: vec!["Carol Test".to_string()],
+                        }),
+                        global_unique_senders: None,
+                        last_delivery_time: Some(DateTime(
+                            time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap(),
+                        )),
+                        global_last_delivery_time: None,
+                        categories: None,
+                        global_categories: None,
+                        flag_status: Some(FlagStatus::Flagged),
+                        global_flag_status: None,
+                        has_attachments: Some(false),
+                        global_has_attachments: None,
+                        message_count: Some(2),
+                        global_message_count: Some(3),
+                        unread_count: Some(1),
+                        global_unread_count: None,
+                        size: None,
+                        global_size: None,
+                        importance: Some(Importance::Normal),
+                        global_importance: None,
+                        item_ids: Some(ItemIds {
+                            inner: vec![
+                                ItemId {
+                                    id: "AAMkADk=".to_string(),
+                                    change_key: Some("CQAAAA==".to_string()),
+                                },
+                                ItemId {
+                                    id: "AAMkADl=".to_string(),
+                                    change_key: Some("CQAAAB==".to_string()),
+                                },
+                            ],
+                        }),
+                        global_item_ids: None,
+                    }],
+                },
+                total_conversations_in_view: Some(1),
+                indexed_offset: Some(1),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -84,8 +84,8 @@ pub struct RootFolder {
 mod tests {
     use crate::{
         test_utils::{assert_deserialized_content, assert_serialized_content},
-        BasePoint, BaseShape, Groups, ItemId, Items, Message, RealItem, ResponseClass,
-        ResponseMessages,
+        BasePoint, BaseShape, DistinguishedFolderName, Groups, ItemId, Items, Message, RealItem,
+        ResponseClass, ResponseMessages,
     };
 
     use super::*;
@@ -100,8 +100,9 @@ mod tests {
                 additional_properties: None,
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "deleteditems".to_string(),
+                id: DistinguishedFolderName::DeletedItems,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::IndexedPageItemView {
                 max_entries_returned: Some(6),
@@ -125,8 +126,9 @@ mod tests {
                 additional_properties: None,
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "inbox".to_string(),
+                id: DistinguishedFolderName::Inbox,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::FractionalPageItemView {
                 max_entries_returned: Some(12),
@@ -149,8 +151,9 @@ mod tests {
                 additional_properties: None,
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "calendar".to_string(),
+                id: DistinguishedFolderName::Calendar,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::CalendarView {
                 max_entries_returned: Some(2),
@@ -174,8 +177,9 @@ mod tests {
                 additional_properties: None,
             },
             parent_folder_ids: vec![BaseFolderId::DistinguishedFolderId {
-                id: "contacts".to_string(),
+                id: DistinguishedFolderName::Contacts,
                 change_key: None,
+                mailbox: None,
             }],
             view: Some(View::ContactsView {
                 max_entries_returned: Some(3),
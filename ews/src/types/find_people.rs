@@ -0,0 +1,200 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseFolderId, BasePoint, Persona, PersonaShape, Restriction, MESSAGES_NS_URI};
+
+/// A request to search for people across the contacts in a mailbox and the
+/// directory.
+///
+/// Unlike [`ResolveNames`], results from different sources describing the
+/// same person are merged into a single [`Persona`].
+///
+/// This operation was introduced in Exchange 2013.
+///
+/// [`ResolveNames`]: crate::resolve_names::ResolveNames
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findpeople-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(FindPeopleResponseMessage, single_message)]
+pub struct FindPeople {
+    /// The persona properties to include in the response.
+    pub persona_shape: Option<PersonaShape>,
+
+    /// The page of results to return.
+    pub indexed_page_item_view: IndexedPageItemView,
+
+    /// A condition personas must meet to be included in the results.
+    pub restriction: Option<Restriction>,
+
+    /// The folder to search.
+    ///
+    /// If omitted, the default contacts folder of the mailbox is searched.
+    pub parent_folder_id: Option<BaseFolderId>,
+
+    /// A name or address to search for.
+    ///
+    /// Including the directory in the search, e.g. by setting
+    /// `parent_folder_id` to the `directory` distinguished folder, requires
+    /// that this is set.
+    pub query_string: Option<String>,
+}
+
+/// Describes the page of results to return for a [`FindPeople`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/indexedpageitemview>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct IndexedPageItemView {
+    /// The maximum number of results to return.
+    #[xml_struct(attribute)]
+    pub max_entries_returned: Option<usize>,
+
+    #[xml_struct(attribute)]
+    pub base_point: BasePoint,
+
+    #[xml_struct(attribute)]
+    pub offset: usize,
+}
+
+/// The result of a [`FindPeople`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/findpeopleresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct FindPeopleResponseMessage {
+    /// The personas matching the request.
+    pub people: People,
+
+    /// The total number of personas matching the request.
+    pub total_number_of_people_in_view: Option<usize>,
+
+    /// The offset of the first persona matching the query string, if any.
+    pub first_matching_row_index: Option<usize>,
+
+    /// The offset of the first persona in `people`.
+    pub first_loaded_row_index: Option<usize>,
+}
+
+/// A list of personas.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/people>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct People {
+    #[serde(rename = "Persona", default)]
+    pub inner: Vec<Persona>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BaseShape, Constant, ContainmentComparison, ContainmentMode, DistinguishedFolderName,
+        Mailbox, PathToElement, PersonaId, ResponseClass, SearchExpression, UnindexedFieldURI,
+    };
+
+    #[test]
+    fn serialize_find_people() {
+        let find_people = FindPeople {
+            persona_shape: Some(PersonaShape {
+                base_shape: BaseShape::IdOnly,
+                additional_properties: Some(vec![PathToElement::FieldURI {
+                    field_URI: UnindexedFieldURI::PersonaDisplayName,
+                }]),
+            }),
+            indexed_page_item_view: IndexedPageItemView {
+                max_entries_returned: Some(20),
+                base_point: BasePoint::Beginning,
+                offset: 0,
+            },
+            restriction: Some(Restriction {
+                expression: SearchExpression::Contains {
+                    containment_mode: Some(ContainmentMode::Prefixed),
+                    containment_comparison: Some(ContainmentComparison::IgnoreCase),
+                    path: PathToElement::FieldURI {
+                        field_URI: UnindexedFieldURI::PersonaCompanyName,
+                    },
+                    constant: Constant {
+                        value: "Example".to_string(),
+                    },
+                },
+            }),
+            parent_folder_id: Some(BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Directory,
+                change_key: None,
+                mailbox: None,
+            }),
+            query_string: Some("ali".to_string()),
+        };
+
+        let expected = r#"<FindPeople xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><PersonaShape><t:BaseShape>IdOnly</t:BaseShape><t:AdditionalProperties><t:FieldURI FieldURI="persona:DisplayName"/></t:AdditionalProperties></PersonaShape><IndexedPageItemView MaxEntriesReturned="20" BasePoint="Beginning" Offset="0"/><Restriction><t:Contains ContainmentMode="Prefixed" ContainmentComparison="IgnoreCase"><t:FieldURI FieldURI="persona:CompanyName"/><t:Constant Value="Example"/></t:Contains></Restriction><ParentFolderId><t:DistinguishedFolderId Id="directory"/></ParentFolderId><QueryString>ali</QueryString></FindPeople>"#;
+
+        assert_serialized_content(&find_people, "FindPeople", expected);
+    }
+
+    #[test]
+    fn deserialize_find_people_response() {
+        let content = r#"<FindPeopleResponse ResponseClass="Success"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <ResponseCode>NoError</ResponseCode>
+                <People>
+                    <t:Persona>
+                        <t:PersonaId Id="AAUQAMsIQGQ=" />
+                        <t:DisplayName>Alice Test</t:DisplayName>
+                        <t:EmailAddress>
+                            <t:Name>Alice Test</t:Name>
+                            <t:EmailAddress>alice@example.com</t:EmailAddress>
+                            <t:RoutingType>SMTP</t:RoutingType>
+                        </t:EmailAddress>
+                        <t:RelevanceScore>2147483647</t:RelevanceScore>
+                    </t:Persona>
+                </People>
+                <TotalNumberOfPeopleInView>1</TotalNumberOfPeopleInView>
+                <FirstMatchingRowIndex>0</FirstMatchingRowIndex>
+                <FirstLoadedRowIndex>0</FirstLoadedRowIndex>
+            </FindPeopleResponse>"#;
+
+        let expected = FindPeopleResponse {
+            response_message: ResponseClass::Success(FindPeopleResponseMessage {
+                people: People {
+                    inner: vec![Persona {
+                        persona_id: PersonaId {
+                            id: "AAUQAMsIQGQ=".to_string(),
+                        },
+                        persona_type: None,
+                        creation_time: None,
+                        display_name: Some("Alice Test".to_string()),
+                        display_name_first_last: None,
+                        display_name_last_first: None,
+                        file_as: None,
+                        given_name: None,
+                        surname: None,
+                        company_name: None,
+                        email_address: Some(Mailbox {
+                            name: Some("Alice Test".to_string()),
+                            email_address: Some("alice@example.com".to_string()),
+                            routing_type: Some("SMTP".to_string()),
+                            ..Default::default()
+                        }),
+                        relevance_score: Some(2147483647),
+                        attributions: None,
+                        display_names: None,
+                        email_addresses: None,
+                        phone_numbers: None,
+                    }],
+                },
+                total_number_of_people_in_view: Some(1),
+                first_matching_row_index: Some(0),
+                first_loaded_row_index: Some(0),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,615 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Attachments, PathToElement, RequestAttachmentId, ResponseBodyType, MESSAGES_NS_URI};
+
+/// A request to get one or more attachments, including their content.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getattachment>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetAttachmentResponseMessage)]
+pub struct GetAttachment {
+    /// The properties to include for item attachments.
+    ///
+    /// This has no effect on file attachments.
+    pub attachment_shape: Option<AttachmentShape>,
+
+    /// The attachments to get.
+    pub attachment_ids: Vec<RequestAttachmentId>,
+}
+
+/// The properties of an attached item which should be included in the
+/// response.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/attachmentshape>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+pub struct AttachmentShape {
+    /// Whether the MIME content of an attached item should be included.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/includemimecontent>
+    #[xml_struct(ns_prefix = "t")]
+    pub include_mime_content: Option<bool>,
+
+    /// The content type in which the body of an attached item should be
+    /// returned.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/bodytype>
+    #[xml_struct(ns_prefix = "t")]
+    pub body_type: Option<ResponseBodyType>,
+
+    /// A list of properties of an attached item which should be included.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/additionalproperties>
+    #[xml_struct(ns_prefix = "t")]
+    pub additional_properties: Option<Vec<PathToElement>>,
+}
+
+/// A response to a request for a single attachment.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getattachmentresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetAttachmentResponseMessage {
+    /// The requested attachment.
+    pub attachments: Attachments,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ArrayOfAttendees, ArrayOfRecipients, Attachment, AttachmentId, AttachmentItem, Attendee,
+        Body, BodyType, CalendarItem, CalendarItemType, CompleteName, Contact, DateTime,
+        Dictionary, EmailAddressEntry, EmailAddressKey, Importance, LegacyFreeBusyStatus, Mailbox,
+        MailboxType, MeetingResponseType, Message, PhoneNumberEntry, PhoneNumberKey, Recipient,
+        ResponseClass, ResponseMessages, Sensitivity,
+    };
+
+    fn attachment_id(id: &str) -> AttachmentId {
+        AttachmentId {
+            id: id.to_string(),
+            root_item_id: None,
+            root_item_change_key: None,
+        }
+    }
+
+    fn date_time(timestamp: i64) -> DateTime {
+        DateTime(time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap())
+    }
+
+    #[test]
+    fn serialize_get_attachment() {
+        let get_attachment = GetAttachment {
+            attachment_shape: Some(AttachmentShape {
+                include_mime_content: Some(true),
+                body_type: Some(ResponseBodyType::Best),
+                additional_properties: None,
+            }),
+            attachment_ids: vec![RequestAttachmentId::AttachmentId {
+                id: "AAMkADk=".to_string(),
+            }],
+        };
+
+        let expected = r#"<GetAttachment xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><AttachmentShape><t:IncludeMimeContent>true</t:IncludeMimeContent><t:BodyType>Best</t:BodyType></AttachmentShape><AttachmentIds><t:AttachmentId Id="AAMkADk="/></AttachmentIds></GetAttachment>"#;
+
+        assert_serialized_content(&get_attachment, "GetAttachment", expected);
+    }
+
+    #[test]
+    fn deserialize_get_attachment_response() {
+        let content = r#"<m:GetAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:FileAttachment>
+                                <t:AttachmentId Id="AAMkADk=" />
+                                <t:Name>notes.txt</t:Name>
+                                <t:ContentType>text/plain</t:ContentType>
+                                <t:Content>aGVsbG8=</t:Content>
+                            </t:FileAttachment>
+                        </m:Attachments>
+                    </m:GetAttachmentResponseMessage>
+                    <m:GetAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:ItemAttachment>
+                                <t:AttachmentId Id="AAMkADl=" />
+                                <t:Name>Forwarded message</t:Name>
+                                <t:ContentType>message/rfc822</t:ContentType>
+                                <t:IsInline>false</t:IsInline>
+                            </t:ItemAttachment>
+                        </m:Attachments>
+                    </m:GetAttachmentResponseMessage>
+                </m:ResponseMessages>
+            </m:GetAttachmentResponse>"#;
+
+        let expected = GetAttachmentResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![
+                    ResponseClass::Success(GetAttachmentResponseMessage {
+                        attachments: Attachments {
+                            inner: vec![Attachment::FileAttachment {
+                                attachment_id: Some(AttachmentId {
+                                    id: "AAMkADk=".to_string(),
+                                    root_item_id: None,
+                                    root_item_change_key: None,
+                                }),
+                                name: "notes.txt".to_string(),
+                                content_type: "text/plain".to_string(),
+                                content_id: None,
+                                content_location: None,
+                                size: None,
+                                last_modified_time: None,
+                                is_inline: None,
+                                is_contact_photo: None,
+                                content: Some("aGVsbG8=".to_string()),
+                            }],
+                        },
+                    }),
+                    ResponseClass::Success(GetAttachmentResponseMessage {
+                        attachments: Attachments {
+                            inner: vec![Attachment::ItemAttachment {
+                                attachment_id: Some(AttachmentId {
+                                    id: "AAMkADl=".to_string(),
+                                    root_item_id: None,
+                                    root_item_change_key: None,
+                                }),
+                                name: "Forwarded message".to_string(),
+                                content_type: "message/rfc822".to_string(),
+                                content_id: None,
+                                content_location: None,
+                                size: None,
+                                last_modified_time: None,
+                                is_inline: Some(false),
+                                content: None,
+                            }],
+                        },
+                    }),
+                ],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    /// A message forwarded as an attachment. The forwarded message has both a
+    /// file and an item attachment of its own; nested attachments are listed
+    /// without their content.
+    ///
+    /// The response is synthetic, but follows the layout Exchange uses for
+    /// item attachments, including elements this crate does not model.
+    #[test]
+    fn deserialize_item_attachment_with_message() {
+        let content = r#"<m:GetAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:ItemAttachment>
+                                <t:AttachmentId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbP" />
+                                <t:Name>Quarterly report</t:Name>
+                                <t:Size>48213</t:Size>
+                                <t:LastModifiedTime>2024-05-02T09:12:44Z</t:LastModifiedTime>
+                                <t:IsInline>false</t:IsInline>
+                                <t:Message>
+                                    <t:ItemClass>IPM.Note</t:ItemClass>
+                                    <t:Subject>Quarterly report</t:Subject>
+                                    <t:Sensitivity>Normal</t:Sensitivity>
+                                    <t:Body BodyType="HTML" IsTruncated="false">&lt;html&gt;&lt;head&gt;&lt;meta http-equiv="Content-Type" content="text/html; charset=utf-8"&gt;&lt;/head&gt;&lt;body&gt;&lt;p&gt;Report attached, and Bob's numbers below.&lt;/p&gt;&lt;/body&gt;&lt;/html&gt;</t:Body>
+                                    <t:Attachments>
+                                        <t:FileAttachment>
+                                            <t:AttachmentId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbPAAESABAANzXLbrpeMVSk0Th7CkNWSw==" />
+                                            <t:Name>Q2 report.pdf</t:Name>
+                                            <t:ContentType>application/pdf</t:ContentType>
+                                            <t:Size>41876</t:Size>
+                                            <t:LastModifiedTime>2024-05-02T08:57:31Z</t:LastModifiedTime>
+                                            <t:IsInline>false</t:IsInline>
+                                            <t:IsContactPhoto>false</t:IsContactPhoto>
+                                        </t:FileAttachment>
+                                        <t:ItemAttachment>
+                                            <t:AttachmentId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbPAAESABAAVZJle6Wm1lD2Nvm//hjKxQ==" />
+                                            <t:Name>Re: Q2 numbers</t:Name>
+                                            <t:Size>5124</t:Size>
+                                            <t:LastModifiedTime>2024-05-02T08:57:48Z</t:LastModifiedTime>
+                                            <t:IsInline>false</t:IsInline>
+                                        </t:ItemAttachment>
+                                    </t:Attachments>
+                                    <t:Size>48213</t:Size>
+                                    <t:Importance>Normal</t:Importance>
+                                    <t:IsSubmitted>false</t:IsSubmitted>
+                                    <t:IsDraft>false</t:IsDraft>
+                                    <t:IsFromMe>false</t:IsFromMe>
+                                    <t:IsResend>false</t:IsResend>
+                                    <t:IsUnmodified>true</t:IsUnmodified>
+                                    <t:DateTimeSent>2024-05-02T08:58:10Z</t:DateTimeSent>
+                                    <t:DateTimeCreated>2024-05-02T09:12:44Z</t:DateTimeCreated>
+                                    <t:DisplayCc />
+                                    <t:DisplayTo>Bob Example</t:DisplayTo>
+                                    <t:HasAttachments>true</t:HasAttachments>
+                                    <t:Culture>en-US</t:Culture>
+                                    <t:EffectiveRights>
+                                        <t:CreateAssociated>false</t:CreateAssociated>
+                                        <t:CreateContents>false</t:CreateContents>
+                                        <t:CreateHierarchy>false</t:CreateHierarchy>
+                                        <t:Delete>true</t:Delete>
+                                        <t:Modify>true</t:Modify>
+                                        <t:Read>true</t:Read>
+                                    </t:EffectiveRights>
+                                    <t:Sender>
+                                        <t:Mailbox>
+                                            <t:Name>Alice Test</t:Name>
+                                            <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                            <t:RoutingType>SMTP</t:RoutingType>
+                                            <t:MailboxType>OneOff</t:MailboxType>
+                                        </t:Mailbox>
+                                    </t:Sender>
+                                    <t:ToRecipients>
+                                        <t:Mailbox>
+                                            <t:Name>Bob Example</t:Name>
+                                            <t:EmailAddress>bob@example.com</t:EmailAddress>
+                                            <t:RoutingType>SMTP</t:RoutingType>
+                                            <t:MailboxType>OneOff</t:MailboxType>
+                                        </t:Mailbox>
+                                    </t:ToRecipients>
+                                    <t:IsReadReceiptRequested>false</t:IsReadReceiptRequested>
+                                    <t:From>
+                                        <t:Mailbox>
+                                            <t:Name>Alice Test</t:Name>
+                                            <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                            <t:RoutingType>SMTP</t:RoutingType>
+                                            <t:MailboxType>OneOff</t:MailboxType>
+                                        </t:Mailbox>
+                                    </t:From>
+                                    <t:InternetMessageId>&lt;DB9PR04MB8461C1F7A0E2B3D4@DB9PR04MB8461.eurprd04.prod.outlook.com&gt;</t:InternetMessageId>
+                                    <t:IsRead>true</t:IsRead>
+                                </t:Message>
+                            </t:ItemAttachment>
+                        </m:Attachments>
+                    </m:GetAttachmentResponseMessage>
+                </m:ResponseMessages>
+            </m:GetAttachmentResponse>"#;
+
+        let mailbox = |name: &str, email_address: &str| Mailbox {
+            name: Some(name.to_string()),
+            email_address: Some(email_address.to_string()),
+            routing_type: Some("SMTP".to_string()),
+            mailbox_type: Some(MailboxType::OneOff),
+            item_id: None,
+        };
+
+        let expected = GetAttachmentResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetAttachmentResponseMessage {
+                    attachments: Attachments {
+                        inner: vec![Attachment::ItemAttachment {
+                            attachment_id: Some(attachment_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbP")),
+                            name: "Quarterly report".to_string(),
+                            content_type: String::new(),
+                            content_id: None,
+                            content_location: None,
+                            size: Some(48213),
+                            last_modified_time: Some(date_time(1714641164)),
+                            is_inline: Some(false),
+                            content: Some(AttachmentItem::Message(Message {
+                                item_class: Some("IPM.Note".to_string()),
+                                subject: Some("Quarterly report".to_string()),
+                                sensitivity: Some(Sensitivity::Normal),
+                                body: Some(Body {
+                                    body_type: BodyType::HTML,
+                                    is_truncated: Some(false),
+                                    content: Some(
+                                        r#"<html><head><meta http-equiv="Content-Type" content="text/html; charset=utf-8"></head><body><p>Report attached, and Bob's numbers below.</p></body></html>"#
+                                            .to_string(),
+                                    ),
+                                }),
+                                attachments: Some(Attachments {
+                                    inner: vec![
+                                        Attachment::FileAttachment {
+                                            attachment_id: Some(attachment_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbPAAESABAANzXLbrpeMVSk0Th7CkNWSw==")),
+                                            name: "Q2 report.pdf".to_string(),
+                                            content_type: "application/pdf".to_string(),
+                                            content_id: None,
+                                            content_location: None,
+                                            size: Some(41876),
+                                            last_modified_time: Some(date_time(1714640251)),
+                                            is_inline: Some(false),
+                                            is_contact_photo: Some(false),
+                                            content: None,
+                                        },
+                                        Attachment::ItemAttachment {
+                                            attachment_id: Some(attachment_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwAl+70IqQ8iO4m7coZpNP+XFx4FxfDjAAAAAAEMAABnVzI0VGRdmPQ/Nbk3apWpjA/0DdYuAAAAARIAEABbIjQl1sv9YAcWvIfecKbPAAESABAAVZJle6Wm1lD2Nvm//hjKxQ==")),
+                                            name: "Re: Q2 numbers".to_string(),
+                                            content_type: String::new(),
+                                            content_id: None,
+                                            content_location: None,
+                                            size: Some(5124),
+                                            last_modified_time: Some(date_time(1714640268)),
+                                            is_inline: Some(false),
+                                            content: None,
+                                        },
+                                    ],
+                                }),
+                                size: Some(48213),
+                                importance: Some(Importance::Normal),
+                                is_submitted: Some(false),
+                                is_draft: Some(false),
+                                is_from_me: Some(false),
+                                is_resend: Some(false),
+                                is_unmodified: Some(true),
+                                date_time_sent: Some(date_time(1714640290)),
+                                date_time_created: Some(date_time(1714641164)),
+                                display_cc: Some(String::new()),
+                                display_to: Some("Bob Example".to_string()),
+                                has_attachments: Some(true),
+                                culture: Some("en-US".to_string()),
+                                sender: Some(Recipient {
+                                    mailbox: mailbox("Alice Test", "alice@example.com"),
+                                }),
+                                to_recipients: Some(ArrayOfRecipients(vec![Recipient {
+                                    mailbox: mailbox("Bob Example", "bob@example.com"),
+                                }])),
+                                is_read_receipt_requested: Some(false),
+                                from: Some(Recipient {
+                                    mailbox: mailbox("Alice Test", "alice@example.com"),
+                                }),
+                                internet_message_id: Some(
+                                    "<DB9PR04MB8461C1F7A0E2B3D4@DB9PR04MB8461.eurprd04.prod.outlook.com>"
+                                        .to_string(),
+                                ),
+                                is_read: Some(true),
+                                ..Default::default()
+                            })),
+                        }],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    /// A meeting and a contact, requested together. Neither attachment has a
+    /// content type.
+    ///
+    /// The response is synthetic, but follows the layout Exchange uses for
+    /// item attachments, including elements this crate does not model.
+    #[test]
+    fn deserialize_item_attachment_with_calendar_item_and_contact() {
+        let content = r#"<m:GetAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:ItemAttachment>
+                                <t:AttachmentId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwBO5S2Pn++L1qxLFywxB+vLmV+y9BirAAAAAAEMAABmSOhOptb94jIddDPMVXFnyQyPth5EAAAAARIAEAAZ3/ujHvUxM1Uptv3aJnkO" />
+                                <t:Name>Project sync</t:Name>
+                                <t:Size>9371</t:Size>
+                                <t:LastModifiedTime>2024-05-03T14:20:05Z</t:LastModifiedTime>
+                                <t:IsInline>false</t:IsInline>
+                                <t:CalendarItem>
+                                    <t:ItemClass>IPM.Appointment</t:ItemClass>
+                                    <t:Subject>Project sync</t:Subject>
+                                    <t:Sensitivity>Normal</t:Sensitivity>
+                                    <t:Body BodyType="Text" IsTruncated="false">Agenda to follow.</t:Body>
+                                    <t:Size>9371</t:Size>
+                                    <t:DateTimeCreated>2024-05-03T14:20:05Z</t:DateTimeCreated>
+                                    <t:HasAttachments>false</t:HasAttachments>
+                                    <t:Culture>en-US</t:Culture>
+                                    <t:UID>040000008200E00074C5B7101A82E00800000000F0B5D1A4739DDA01000000000000000010000000C9E8B07A5D6F1448A3B2C1D0E9F8A7B6</t:UID>
+                                    <t:Start>2024-05-06T15:00:00Z</t:Start>
+                                    <t:End>2024-05-06T15:30:00Z</t:End>
+                                    <t:IsAllDayEvent>false</t:IsAllDayEvent>
+                                    <t:LegacyFreeBusyStatus>Busy</t:LegacyFreeBusyStatus>
+                                    <t:Location>Room 4</t:Location>
+                                    <t:IsMeeting>true</t:IsMeeting>
+                                    <t:IsCancelled>false</t:IsCancelled>
+                                    <t:IsRecurring>false</t:IsRecurring>
+                                    <t:MeetingRequestWasSent>true</t:MeetingRequestWasSent>
+                                    <t:CalendarItemType>Single</t:CalendarItemType>
+                                    <t:Organizer>
+                                        <t:Mailbox>
+                                            <t:Name>Alice Test</t:Name>
+                                            <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                            <t:RoutingType>SMTP</t:RoutingType>
+                                        </t:Mailbox>
+                                    </t:Organizer>
+                                    <t:RequiredAttendees>
+                                        <t:Attendee>
+                                            <t:Mailbox>
+                                                <t:Name>Bob Example</t:Name>
+                                                <t:EmailAddress>bob@example.com</t:EmailAddress>
+                                                <t:RoutingType>SMTP</t:RoutingType>
+                                            </t:Mailbox>
+                                            <t:ResponseType>Unknown</t:ResponseType>
+                                        </t:Attendee>
+                                    </t:RequiredAttendees>
+                                    <t:Duration>PT30M</t:Duration>
+                                    <t:TimeZone>(UTC) Coordinated Universal Time</t:TimeZone>
+                                    <t:AppointmentSequenceNumber>0</t:AppointmentSequenceNumber>
+                                    <t:AppointmentState>1</t:AppointmentState>
+                                </t:CalendarItem>
+                            </t:ItemAttachment>
+                        </m:Attachments>
+                    </m:GetAttachmentResponseMessage>
+                    <m:GetAttachmentResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Attachments>
+                            <t:ItemAttachment>
+                                <t:AttachmentId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwB/nMmDR9xf+5x9opcJ2hx8I8y5m7F/AAAAAAEMAAD51MQGQX3DyPx4WTwCGGCVSHIt/gshAAAAARIAEAAfkd2dDOyd4zTJMfLS3Uyw" />
+                                <t:Name>Bob Example</t:Name>
+                                <t:Size>6020</t:Size>
+                                <t:LastModifiedTime>2024-05-03T14:21:40Z</t:LastModifiedTime>
+                                <t:IsInline>false</t:IsInline>
+                                <t:Contact>
+                                    <t:ItemClass>IPM.Contact</t:ItemClass>
+                                    <t:Subject>Bob Example</t:Subject>
+                                    <t:Sensitivity>Normal</t:Sensitivity>
+                                    <t:Size>6020</t:Size>
+                                    <t:DateTimeCreated>2024-05-03T14:21:40Z</t:DateTimeCreated>
+                                    <t:HasAttachments>false</t:HasAttachments>
+                                    <t:Culture>en-US</t:Culture>
+                                    <t:FileAs>Example, Bob</t:FileAs>
+                                    <t:DisplayName>Bob Example</t:DisplayName>
+                                    <t:GivenName>Bob</t:GivenName>
+                                    <t:CompleteName>
+                                        <t:FirstName>Bob</t:FirstName>
+                                        <t:LastName>Example</t:LastName>
+                                        <t:FullName>Bob Example</t:FullName>
+                                    </t:CompleteName>
+                                    <t:CompanyName>Example Ltd</t:CompanyName>
+                                    <t:EmailAddresses>
+                                        <t:Entry Key="EmailAddress1">bob@example.com</t:Entry>
+                                    </t:EmailAddresses>
+                                    <t:PhoneNumbers>
+                                        <t:Entry Key="BusinessPhone">+1 555 0100</t:Entry>
+                                        <t:Entry Key="MobilePhone">+1 555 0199</t:Entry>
+                                    </t:PhoneNumbers>
+                                    <t:JobTitle>Finance lead</t:JobTitle>
+                                    <t:Surname>Example</t:Surname>
+                                </t:Contact>
+                            </t:ItemAttachment>
+                        </m:Attachments>
+                    </m:GetAttachmentResponseMessage>
+                </m:ResponseMessages>
+            </m:GetAttachmentResponse>"#;
+
+        let attachment = |id: &str, name: &str, size, last_modified_time, content| {
+            ResponseClass::Success(GetAttachmentResponseMessage {
+                attachments: Attachments {
+                    inner: vec![Attachment::ItemAttachment {
+                        attachment_id: Some(attachment_id(id)),
+                        name: name.to_string(),
+                        content_type: String::new(),
+                        content_id: None,
+                        content_location: None,
+                        size: Some(size),
+                        last_modified_time: Some(date_time(last_modified_time)),
+                        is_inline: Some(false),
+                        content: Some(content),
+                    }],
+                },
+            })
+        };
+
+        let mailbox = |name: &str, email_address: &str| Mailbox {
+            name: Some(name.to_string()),
+            email_address: Some(email_address.to_string()),
+            routing_type: Some("SMTP".to_string()),
+            ..Default::default()
+        };
+
+        let expected = GetAttachmentResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![
+                    attachment(
+                        "AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwBO5S2Pn++L1qxLFywxB+vLmV+y9BirAAAAAAEMAABmSOhOptb94jIddDPMVXFnyQyPth5EAAAAARIAEAAZ3/ujHvUxM1Uptv3aJnkO",
+                        "Project sync",
+                        9371,
+                        1714746005,
+                        AttachmentItem::CalendarItem(CalendarItem {
+                            item_class: Some("IPM.Appointment".to_string()),
+                            subject: Some("Project sync".to_string()),
+                            sensitivity: Some(Sensitivity::Normal),
+                            body: Some(Body {
+                                body_type: BodyType::Text,
+                                is_truncated: Some(false),
+                                content: Some("Agenda to follow.".to_string()),
+                            }),
+                            size: Some(9371),
+                            date_time_created: Some(date_time(1714746005)),
+                            has_attachments: Some(false),
+                            culture: Some("en-US".to_string()),
+                            uid: Some("040000008200E00074C5B7101A82E00800000000F0B5D1A4739DDA01000000000000000010000000C9E8B07A5D6F1448A3B2C1D0E9F8A7B6".to_string()),
+                            start: Some(date_time(1715007600)),
+                            end: Some(date_time(1715009400)),
+                            is_all_day_event: Some(false),
+                            legacy_free_busy_status: Some(LegacyFreeBusyStatus::Busy),
+                            location: Some("Room 4".to_string()),
+                            is_meeting: Some(true),
+                            is_cancelled: Some(false),
+                            is_recurring: Some(false),
+                            meeting_request_was_sent: Some(true),
+                            calendar_item_type: Some(CalendarItemType::Single),
+                            organizer: Some(Recipient {
+                                mailbox: mailbox("Alice Test", "alice@example.com"),
+                            }),
+                            required_attendees: Some(ArrayOfAttendees {
+                                inner: vec![Attendee {
+                                    mailbox: mailbox("Bob Example", "bob@example.com"),
+                                    response_type: Some(MeetingResponseType::Unknown),
+                                    last_response_time: None,
+                                }],
+                            }),
+                            duration: Some("PT30M".to_string()),
+                            time_zone: Some("(UTC) Coordinated Universal Time".to_string()),
+                            appointment_sequence_number: Some(0),
+                            appointment_state: Some(1),
+                            ..Default::default()
+                        }),
+                    ),
+                    attachment(
+                        "AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAAADeBzDZCSvCyPzHIk7tsEjBwB/nMmDR9xf+5x9opcJ2hx8I8y5m7F/AAAAAAEMAAD51MQGQX3DyPx4WTwCGGCVSHIt/gshAAAAARIAEAAfkd2dDOyd4zTJMfLS3Uyw",
+                        "Bob Example",
+                        6020,
+                        1714746100,
+                        AttachmentItem::Contact(Contact {
+                            item_class: Some("IPM.Contact".to_string()),
+                            subject: Some("Bob Example".to_string()),
+                            sensitivity: Some(Sensitivity::Normal),
+                            size: Some(6020),
+                            date_time_created: Some(date_time(1714746100)),
+                            has_attachments: Some(false),
+                            file_as: Some("Example, Bob".to_string()),
+                            display_name: Some("Bob Example".to_string()),
+                            given_name: Some("Bob".to_string()),
+                            complete_name: Some(CompleteName {
+                                first_name: Some("Bob".to_string()),
+                                last_name: Some("Example".to_string()),
+                                full_name: Some("Bob Example".to_string()),
+                                ..Default::default()
+                            }),
+                            company_name: Some("Example Ltd".to_string()),
+                            email_addresses: Some(Dictionary {
+                                entries: vec![EmailAddressEntry {
+                                    key: EmailAddressKey::EmailAddress1,
+                                    name: None,
+                                    routing_type: None,
+                                    mailbox_type: None,
+                                    address: "bob@example.com".to_string(),
+                                }],
+                            }),
+                            phone_numbers: Some(Dictionary {
+                                entries: vec![
+                                    PhoneNumberEntry {
+                                        key: PhoneNumberKey::BusinessPhone,
+                                        number: "+1 555 0100".to_string(),
+                                    },
+                                    PhoneNumberEntry {
+                                        key: PhoneNumberKey::MobilePhone,
+                                        number: "+1 555 0199".to_string(),
+                                    },
+                                ],
+                            }),
+                            job_title: Some("Finance lead".to_string()),
+                            surname: Some("Example".to_string()),
+                            ..Default::default()
+                        }),
+                    ),
+                ],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
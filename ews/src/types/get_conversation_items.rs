@@ -0,0 +1,317 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseFolderId, ItemId, ItemShape, Items, MESSAGES_NS_URI};
+
+/// A request to get the items of one or more conversations.
+///
+/// This operation was introduced in Exchange 2013.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getconversationitems>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetConversationItemsResponseMessage)]
+pub struct GetConversationItems {
+    /// The item properties to include in the response.
+    pub item_shape: ItemShape,
+
+    /// Folders whose items are left out of the response, e.g. the deleted
+    /// items folder.
+    pub folders_to_ignore: Option<Vec<BaseFolderId>>,
+
+    /// The maximum number of items to return for each conversation.
+    pub max_items_to_return: Option<u32>,
+
+    /// The order in which the nodes of each conversation are returned.
+    pub sort_order: Option<ConversationNodeSortOrder>,
+
+    pub conversations: ConversationRequests,
+}
+
+/// The order in which the nodes of a conversation are returned.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sortorder-conversationnodesortorder>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum ConversationNodeSortOrder {
+    TreeOrderAscending,
+    TreeOrderDescending,
+    DateOrderAscending,
+    DateOrderDescending,
+}
+
+/// The conversations to get the items of.
+#[derive(Clone, Debug, Default)]
+pub struct ConversationRequests(pub Vec<ConversationRequest>);
+
+impl XmlSerialize for ConversationRequests {
+    /// Serializes each conversation as a `<t:Conversation>` element.
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        for conversation in &self.0 {
+            conversation.serialize_as_element(writer, "t:Conversation")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A conversation to get the items of.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversation-conversationrequesttype>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct ConversationRequest {
+    #[xml_struct(ns_prefix = "t")]
+    pub conversation_id: ItemId,
+
+    /// The synchronization state returned by a previous request for the same
+    /// conversation, if only the changes since then should be returned.
+    #[xml_struct(ns_prefix = "t")]
+    pub sync_state: Option<String>,
+}
+
+/// Contains the status and result of getting the items of a single
+/// conversation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getconversationitemsresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetConversationItemsResponseMessage {
+    pub conversation: ConversationResponse,
+}
+
+/// The items of a conversation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversation-conversationresponsetype>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConversationResponse {
+    pub conversation_id: ItemId,
+
+    /// The synchronization state to send in the next request for this
+    /// conversation.
+    pub sync_state: Option<String>,
+
+    pub conversation_nodes: Option<ConversationNodes>,
+}
+
+/// The nodes of a conversation.
+///
+/// Nodes are listed flat; the tree of replies is described by the
+/// `parent_internet_message_id` of each node.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversationnodes>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct ConversationNodes {
+    #[serde(rename = "ConversationNode", default)]
+    pub inner: Vec<ConversationNode>,
+}
+
+impl ConversationNodes {
+    /// The nodes which don't reply to another node of the conversation.
+    ///
+    /// A node whose parent isn't part of the response, e.g. because it was
+    /// deleted or is in an ignored folder, is considered a root.
+    pub fn roots(&self) -> impl Iterator<Item = &ConversationNode> {
+        self.inner
+            .iter()
+            .filter(|node| match node.parent_internet_message_id.as_deref() {
+                Some(parent_id) => self.find(parent_id).is_none(),
+                None => true,
+            })
+    }
+
+    /// The nodes replying directly to `node`.
+    pub fn children<'a>(
+        &'a self,
+        node: &'a ConversationNode,
+    ) -> impl Iterator<Item = &'a ConversationNode> {
+        self.inner.iter().filter(move |child| {
+            node.internet_message_id.is_some()
+                && child.parent_internet_message_id == node.internet_message_id
+        })
+    }
+
+    fn find(&self, internet_message_id: &str) -> Option<&ConversationNode> {
+        self.inner
+            .iter()
+            .find(|node| node.internet_message_id.as_deref() == Some(internet_message_id))
+    }
+}
+
+/// A message of a conversation, along with its copies in other folders.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conversationnode>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConversationNode {
+    pub internet_message_id: Option<String>,
+
+    /// The Internet message ID of the message this node replies to.
+    pub parent_internet_message_id: Option<String>,
+
+    pub items: Items,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BaseShape, DistinguishedFolderName, Message, RealItem, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_get_conversation_items() {
+        let get_conversation_items = GetConversationItems {
+            item_shape: ItemShape {
+                base_shape: BaseShape::IdOnly,
+                include_mime_content: None,
+                additional_properties: None,
+            },
+            folders_to_ignore: Some(vec![BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::DeletedItems,
+                change_key: None,
+                mailbox: None,
+            }]),
+            max_items_to_return: Some(20),
+            sort_order: Some(ConversationNodeSortOrder::TreeOrderDescending),
+            conversations: ConversationRequests(vec![ConversationRequest {
+                conversation_id: ItemId {
+                    id: "AAQkADk=".to_string(),
+                    change_key: None,
+                },
+                sync_state: Some("AAAAAQ==".to_string()),
+            }]),
+        };
+
+        let expected = r#"<GetConversationItems xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><ItemShape><t:BaseShape>IdOnly</t:BaseShape></ItemShape><FoldersToIgnore><t:DistinguishedFolderId Id="deleteditems"/></FoldersToIgnore><MaxItemsToReturn>20</MaxItemsToReturn><SortOrder>TreeOrderDescending</SortOrder><Conversations><t:Conversation><t:ConversationId Id="AAQkADk="/><t:SyncState>AAAAAQ==</t:SyncState></t:Conversation></Conversations></GetConversationItems>"#;
+
+        assert_serialized_content(&get_conversation_items, "GetConversationItems", expected);
+    }
+
+    #[test]
+    fn deserialize_get_conversation_items_response() {
+        let content = r#"<GetConversationItemsResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetConversationItemsResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Conversation>
+                            <t:ConversationId Id="AAQkADk=" />
+                            <t:SyncState>AAAAAg==</t:SyncState>
+                            <t:ConversationNodes>
+                                <t:ConversationNode>
+                                    <t:InternetMessageId>&lt;first@example.com&gt;</t:InternetMessageId>
+                                    <t:Items>
+                                        <t:Message>
+                                            <t:ItemId Id="AAMkADk=" ChangeKey="CQAAAA==" />
+                                        </t:Message>
+                                    </t:Items>
+                                </t:ConversationNode>
+                                <t:ConversationNode>
+                                    <t:InternetMessageId>&lt;reply@example.com&gt;</t:InternetMessageId>
+                                    <t:ParentInternetMessageId>&lt;first@example.com&gt;</t:ParentInternetMessageId>
+                                    <t:Items>
+                                        <t:Message>
+                                            <t:ItemId Id="AAMkADl=" ChangeKey="CQAAAB==" />
+                                        </t:Message>
+                                    </t:Items>
+                                </t:ConversationNode>
+                            </t:ConversationNodes>
+                        </m:Conversation>
+                    </m:GetConversationItemsResponseMessage>
+                </m:ResponseMessages>
+            </GetConversationItemsResponse>"#;
+
+        let node =
+            |id: &str, parent_id: Option<&str>, item_id: &str, change_key: &str| ConversationNode {
+                internet_message_id: Some(id.to_string()),
+                parent_internet_message_id: parent_id.map(str::to_string),
+                items: Items {
+                    inner: vec![RealItem::Message(Message {
+                        item_id: Some(ItemId {
+                            id: item_id.to_string(),
+                            change_key: Some(change_key.to_string()),
+                        }),
+                        ..Default::default()
+                    })],
+                },
+            };
+
+        let expected = GetConversationItemsResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    GetConversationItemsResponseMessage {
+                        conversation: ConversationResponse {
+                            conversation_id: ItemId {
+                                id: "AAQkADk=".to_string(),
+                                change_key: None,
+                            },
+                            sync_state: Some("AAAAAg==".to_string()),
+                            conversation_nodes: Some(ConversationNodes {
+                                inner: vec![
+                                    node("<first@example.com>", None, "AAMkADk=", "CQAAAA=="),
+                                    node(
+                                        "<reply@example.com>",
+                                        Some("<first@example.com>"),
+                                        "AAMkADl=",
+                                        "CQAAAB==",
+                                    ),
+                                ],
+                            }),
+                        },
+                    },
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn conversation_nodes_tree() {
+        let node = |id: &str, parent_id: Option<&str>| ConversationNode {
+            internet_message_id: Some(id.to_string()),
+            parent_internet_message_id: parent_id.map(str::to_string),
+            items: Items { inner: vec![] },
+        };
+
+        let nodes = ConversationNodes {
+            inner: vec![
+                node("<a@example.com>", None),
+                node("<b@example.com>", Some("<a@example.com>")),
+                node("<c@example.com>", Some("<a@example.com>")),
+                node("<d@example.com>", Some("<deleted@example.com>")),
+            ],
+        };
+
+        let ids = |nodes: Vec<&ConversationNode>| {
+            nodes
+                .into_iter()
+                .map(|node| node.internet_message_id.clone().unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(nodes.roots().collect()),
+            ["<a@example.com>", "<d@example.com>"]
+        );
+        assert_eq!(
+            ids(nodes.children(&nodes.inner[0]).collect()),
+            ["<b@example.com>", "<c@example.com>"]
+        );
+        assert!(nodes.children(&nodes.inner[1]).next().is_none());
+    }
+}
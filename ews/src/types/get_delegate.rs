@@ -0,0 +1,161 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    DelegateUserResponseMessage, DeliverMeetingRequests, Mailbox, ResponseMessages, UserIds,
+    MESSAGES_NS_URI,
+};
+
+/// A request to list the delegates of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getdelegate-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetDelegateResponseMessage, single_message)]
+pub struct GetDelegate {
+    /// Whether to include the permissions of each delegate in the response.
+    #[xml_struct(attribute)]
+    pub include_permissions: bool,
+
+    /// The mailbox to list the delegates of. Only the address is required.
+    pub mailbox: Mailbox,
+
+    /// The delegates to include in the response. All delegates are included
+    /// if this is not set.
+    pub user_ids: Option<UserIds>,
+}
+
+/// The result of a [`GetDelegate`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getdelegateresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetDelegateResponseMessage {
+    /// One response message per delegate.
+    #[serde(default)]
+    pub response_messages: ResponseMessages<DelegateUserResponseMessage>,
+
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        response::{ResponseCode, ResponseError},
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DelegateFolderPermissionLevel, DelegatePermissions, DelegateUser, ResponseClass, UserId,
+    };
+
+    #[test]
+    fn serialize_get_delegate() {
+        let get_delegate = GetDelegate {
+            include_permissions: true,
+            mailbox: Mailbox {
+                email_address: Some("boss@example.com".to_string()),
+                ..Default::default()
+            },
+            user_ids: Some(UserIds(vec![UserId::from_smtp_address(
+                "assistant@example.com",
+            )])),
+        };
+
+        let expected = r#"<GetDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" IncludePermissions="true"><Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></Mailbox><UserIds><t:UserId><t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress></t:UserId></UserIds></GetDelegate>"#;
+
+        assert_serialized_content(&get_delegate, "GetDelegate", expected);
+    }
+
+    #[test]
+    fn deserialize_get_delegate_response() {
+        let content = r#"<m:GetDelegateResponse ResponseClass="Success"
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseCode>NoError</m:ResponseCode>
+                <m:ResponseMessages>
+                    <m:DelegateUserResponseMessageType ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:DelegateUser>
+                            <t:UserId>
+                                <t:SID>S-1-5-21-1333220396-2200287332-232816053-1118</t:SID>
+                                <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                                <t:DisplayName>Assistant</t:DisplayName>
+                            </t:UserId>
+                            <t:DelegatePermissions>
+                                <t:CalendarFolderPermissionLevel>Editor</t:CalendarFolderPermissionLevel>
+                                <t:TasksFolderPermissionLevel>None</t:TasksFolderPermissionLevel>
+                                <t:InboxFolderPermissionLevel>Reviewer</t:InboxFolderPermissionLevel>
+                                <t:ContactsFolderPermissionLevel>None</t:ContactsFolderPermissionLevel>
+                                <t:NotesFolderPermissionLevel>None</t:NotesFolderPermissionLevel>
+                                <t:JournalFolderPermissionLevel>None</t:JournalFolderPermissionLevel>
+                            </t:DelegatePermissions>
+                            <t:ReceiveCopiesOfMeetingMessages>true</t:ReceiveCopiesOfMeetingMessages>
+                            <t:ViewPrivateItems>false</t:ViewPrivateItems>
+                        </m:DelegateUser>
+                    </m:DelegateUserResponseMessageType>
+                    <m:DelegateUserResponseMessageType ResponseClass="Error">
+                        <m:MessageText>The delegate does not exist in the delegate list.</m:MessageText>
+                        <m:ResponseCode>ErrorNotDelegate</m:ResponseCode>
+                        <m:DescriptiveLinkKey>0</m:DescriptiveLinkKey>
+                    </m:DelegateUserResponseMessageType>
+                </m:ResponseMessages>
+                <m:DeliverMeetingRequests>DelegatesAndMe</m:DeliverMeetingRequests>
+            </m:GetDelegateResponse>"#;
+
+        let expected = GetDelegateResponse {
+            response_message: ResponseClass::Success(GetDelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![
+                        ResponseClass::Success(DelegateUserResponseMessage {
+                            delegate_user: Some(DelegateUser {
+                                user_id: UserId {
+                                    SID: Some(
+                                        "S-1-5-21-1333220396-2200287332-232816053-1118".to_string(),
+                                    ),
+                                    primary_smtp_address: Some("assistant@example.com".to_string()),
+                                    display_name: Some("Assistant".to_string()),
+                                    ..Default::default()
+                                },
+                                delegate_permissions: Some(DelegatePermissions {
+                                    calendar_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::Editor,
+                                    ),
+                                    tasks_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::None,
+                                    ),
+                                    inbox_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::Reviewer,
+                                    ),
+                                    contacts_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::None,
+                                    ),
+                                    notes_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::None,
+                                    ),
+                                    journal_folder_permission_level: Some(
+                                        DelegateFolderPermissionLevel::None,
+                                    ),
+                                }),
+                                receive_copies_of_meeting_messages: Some(true),
+                                view_private_items: Some(false),
+                            }),
+                        }),
+                        ResponseClass::Error(ResponseError {
+                            message_text: "The delegate does not exist in the delegate list."
+                                .to_string(),
+                            response_code: ResponseCode::ErrorNotDelegate,
+                            message_xml: None,
+                        }),
+                    ],
+                },
+                deliver_meeting_requests: Some(DeliverMeetingRequests::DelegatesAndMe),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
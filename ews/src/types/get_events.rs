@@ -0,0 +1,222 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Notification, MESSAGES_NS_URI};
+
+/// A request for the events of a pull subscription which occurred after a
+/// given watermark.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getevents>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetEventsResponseMessage)]
+pub struct GetEvents {
+    /// The identifier of the subscription, as returned by [`Subscribe`].
+    ///
+    /// [`Subscribe`]: crate::subscribe::Subscribe
+    pub subscription_id: String,
+
+    /// The watermark of the last event retrieved, or the one returned when
+    /// subscribing if no events have been retrieved yet.
+    pub watermark: String,
+}
+
+/// A response to a [`GetEvents`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/geteventsresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetEventsResponseMessage {
+    pub notification: Notification,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DateTime, FolderId, ItemId, ModifiedEvent, MovedOrCopiedEvent, NotificationEvent,
+        ObjectChangedEvent, ResponseClass, ResponseMessages, StatusEvent,
+    };
+
+    fn folder_id(id: &str, change_key: &str) -> FolderId {
+        FolderId {
+            id: id.to_string(),
+            change_key: Some(change_key.to_string()),
+        }
+    }
+
+    fn item_id(id: &str, change_key: &str) -> ItemId {
+        ItemId {
+            id: id.to_string(),
+            change_key: Some(change_key.to_string()),
+        }
+    }
+
+    fn time_stamp(timestamp: i64) -> DateTime {
+        DateTime(time::OffsetDateTime::from_unix_timestamp(timestamp).unwrap())
+    }
+
+    #[test]
+    fn serialize_get_events() {
+        let get_events = GetEvents {
+            subscription_id: "f6bc657d-dde1-4f94-952d-143b95d6483d".to_string(),
+            watermark: "AQAAALvDkwLHP6n/1gRw8if8JvrABgAAAAAAAAEAAAA=".to_string(),
+        };
+
+        let expected = r#"<GetEvents xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><SubscriptionId>f6bc657d-dde1-4f94-952d-143b95d6483d</SubscriptionId><Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrABgAAAAAAAAEAAAA=</Watermark></GetEvents>"#;
+
+        assert_serialized_content(&get_events, "GetEvents", expected);
+    }
+
+    #[test]
+    fn deserialize_get_events_response() {
+        // A synthetic response in the shape Exchange returns, with made-up
+        // identifiers encoded the way Exchange encodes them.
+        let content = r#"<m:GetEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetEventsResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Notification>
+                            <t:SubscriptionId>f6bc657d-dde1-4f94-952d-143b95d6483d</t:SubscriptionId>
+                            <t:PreviousWatermark>AQAAALvDkwLHP6n/1gRw8if8JvrABgAAAAAAAAEAAAA=</t:PreviousWatermark>
+                            <t:MoreEvents>true</t:MoreEvents>
+                            <t:CreatedEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrBBgAAAAAAAAEAAAA=</t:Watermark>
+                                <t:TimeStamp>2024-05-02T09:12:44Z</t:TimeStamp>
+                                <t:ItemId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=" ChangeKey="CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn" />
+                                <t:ParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=" ChangeKey="AQAAABYAAADYDk1mJRuqF2gFqah+3GU6BRUiE3Xy" />
+                            </t:CreatedEvent>
+                            <t:NewMailEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrCBgAAAAAAAAEAAAA=</t:Watermark>
+                                <t:TimeStamp>2024-05-02T09:12:44Z</t:TimeStamp>
+                                <t:ItemId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=" ChangeKey="CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn" />
+                                <t:ParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=" ChangeKey="AQAAABYAAADYDk1mJRuqF2gFqah+3GU6BRUiE3Xy" />
+                            </t:NewMailEvent>
+                            <t:ModifiedEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrDBgAAAAAAAAEAAAA=</t:Watermark>
+                                <t:TimeStamp>2024-05-02T09:12:45Z</t:TimeStamp>
+                                <t:FolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=" ChangeKey="AQAAABYAAAC6ZmyIvkeKB+EDp3ZSLSPOELcKuNyu" />
+                                <t:ParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEA17w3Erlcu3kgjqsEHxnpJAAAAAEAAAA=" ChangeKey="AQAAABYAAAD28T04b1y7da64aclzFferRoo27a8/" />
+                                <t:UnreadCount>3</t:UnreadCount>
+                            </t:ModifiedEvent>
+                            <t:MovedEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrEBgAAAAAAAAEAAAA=</t:Watermark>
+                                <t:TimeStamp>2024-05-02T09:12:46Z</t:TimeStamp>
+                                <t:ItemId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwCDhKbrLWm6+cj2EgqCqpixWjauZuzuAAAAAAEMAADn9E5IJkLaYZw71B/etEeflmpNZ8x9AAA=" ChangeKey="CQAAABYAAACx2T0YgHiYcDt7Lud5ShI/fnjwkdNg" />
+                                <t:ParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAsOb6LQ1EUTmeBpuTLm/jywAAAAoAAAA=" ChangeKey="AQAAABYAAADf2sDWG/yptCDl42mntb8Vm4drmdkZ" />
+                                <t:OldItemId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=" ChangeKey="CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn" />
+                                <t:OldParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=" ChangeKey="AQAAABYAAAC6ZmyIvkeKB+EDp3ZSLSPOELcKuNyu" />
+                            </t:MovedEvent>
+                            <t:DeletedEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrFBgAAAAAAAAEAAAA=</t:Watermark>
+                                <t:TimeStamp>2024-05-02T09:12:47Z</t:TimeStamp>
+                                <t:ItemId Id="AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwCDhKbrLWm6+cj2EgqCqpixWjauZuzuAAAAAAEMAADn9E5IJkLaYZw71B/etEeflmpNZ8x9AAA=" />
+                                <t:ParentFolderId Id="AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAsOb6LQ1EUTmeBpuTLm/jywAAAAoAAAA=" ChangeKey="AQAAABYAAADf2sDWG/yptCDl42mntb8Vm4drmdkZ" />
+                            </t:DeletedEvent>
+                        </m:Notification>
+                    </m:GetEventsResponseMessage>
+                </m:ResponseMessages>
+            </m:GetEventsResponse>"#;
+
+        let expected = GetEventsResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetEventsResponseMessage {
+                    notification: Notification {
+                        subscription_id: "f6bc657d-dde1-4f94-952d-143b95d6483d".to_string(),
+                        previous_watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrABgAAAAAAAAEAAAA=".to_string()),
+                        more_events: Some(true),
+                        events: vec![
+                            NotificationEvent::CreatedEvent(ObjectChangedEvent {
+                                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrBBgAAAAAAAAEAAAA=".to_string()),
+                                time_stamp: time_stamp(1714641164),
+                                item_id: Some(item_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=", "CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn")),
+                                folder_id: None,
+                                parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=", "AQAAABYAAADYDk1mJRuqF2gFqah+3GU6BRUiE3Xy"),
+                            }),
+                            NotificationEvent::NewMailEvent(ObjectChangedEvent {
+                                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrCBgAAAAAAAAEAAAA=".to_string()),
+                                time_stamp: time_stamp(1714641164),
+                                item_id: Some(item_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=", "CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn")),
+                                folder_id: None,
+                                parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=", "AQAAABYAAADYDk1mJRuqF2gFqah+3GU6BRUiE3Xy"),
+                            }),
+                            NotificationEvent::ModifiedEvent(ModifiedEvent {
+                                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrDBgAAAAAAAAEAAAA=".to_string()),
+                                time_stamp: time_stamp(1714641165),
+                                item_id: None,
+                                folder_id: Some(folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=", "AQAAABYAAAC6ZmyIvkeKB+EDp3ZSLSPOELcKuNyu")),
+                                parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEA17w3Erlcu3kgjqsEHxnpJAAAAAEAAAA=", "AQAAABYAAAD28T04b1y7da64aclzFferRoo27a8/"),
+                                unread_count: Some(3),
+                            }),
+                            NotificationEvent::MovedEvent(MovedOrCopiedEvent {
+                                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrEBgAAAAAAAAEAAAA=".to_string()),
+                                time_stamp: time_stamp(1714641166),
+                                item_id: Some(item_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwCDhKbrLWm6+cj2EgqCqpixWjauZuzuAAAAAAEMAADn9E5IJkLaYZw71B/etEeflmpNZ8x9AAA=", "CQAAABYAAACx2T0YgHiYcDt7Lud5ShI/fnjwkdNg")),
+                                folder_id: None,
+                                parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAsOb6LQ1EUTmeBpuTLm/jywAAAAoAAAA=", "AQAAABYAAADf2sDWG/yptCDl42mntb8Vm4drmdkZ"),
+                                old_item_id: Some(item_id("AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwBGYOW0U87sn2HJ4kvAcLsUNo0qNgc5AAAAAAEMAAAueKo9EPw8jJGfJ35UHzpirbge0v9yAAA=", "CQAAABYAAACZvbYxjcZ/ZPhb5EXSWgctlrXxJ6Mn")),
+                                old_folder_id: None,
+                                old_parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAuVEqQaewnpUPHsiWCG7dSAAAAAwAAAA=", "AQAAABYAAAC6ZmyIvkeKB+EDp3ZSLSPOELcKuNyu"),
+                            }),
+                            NotificationEvent::DeletedEvent(ObjectChangedEvent {
+                                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrFBgAAAAAAAAEAAAA=".to_string()),
+                                time_stamp: time_stamp(1714641167),
+                                item_id: Some(ItemId {
+                                    id: "AAMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNABGAAAAAABhVTv+KubpYpPlsEiNRmLaBwCDhKbrLWm6+cj2EgqCqpixWjauZuzuAAAAAAEMAADn9E5IJkLaYZw71B/etEeflmpNZ8x9AAA=".to_string(),
+                                    change_key: None,
+                                }),
+                                folder_id: None,
+                                parent_folder_id: folder_id("AQMkADZmM2QyYTE4LTk0YzctNGJlNS1hMWQwLThlMmM3YjVmOWEzNAAuAAADYVU7/irm6WKT5bBIjUZi2gEAsOb6LQ1EUTmeBpuTLm/jywAAAAoAAAA=", "AQAAABYAAADf2sDWG/yptCDl42mntb8Vm4drmdkZ"),
+                            }),
+                        ],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn deserialize_status_event() {
+        let content = r#"<m:GetEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetEventsResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Notification>
+                            <t:SubscriptionId>f6bc657d-dde1-4f94-952d-143b95d6483d</t:SubscriptionId>
+                            <t:PreviousWatermark>AQAAALvDkwLHP6n/1gRw8if8JvrFBgAAAAAAAAEAAAA=</t:PreviousWatermark>
+                            <t:MoreEvents>false</t:MoreEvents>
+                            <t:StatusEvent>
+                                <t:Watermark>AQAAALvDkwLHP6n/1gRw8if8JvrGBgAAAAAAAAEAAAA=</t:Watermark>
+                            </t:StatusEvent>
+                        </m:Notification>
+                    </m:GetEventsResponseMessage>
+                </m:ResponseMessages>
+            </m:GetEventsResponse>"#;
+
+        let response: GetEventsResponse = quick_xml::de::from_str(content).unwrap();
+        let notification = match response.response_messages.response_messages.as_slice() {
+            [ResponseClass::Success(message)] => &message.notification,
+            other => panic!("unexpected response messages: {other:?}"),
+        };
+
+        assert_eq!(notification.more_events, Some(false));
+        assert_eq!(
+            notification.events,
+            [NotificationEvent::StatusEvent(StatusEvent {
+                watermark: Some("AQAAALvDkwLHP6n/1gRw8if8JvrGBgAAAAAAAAEAAAA=".to_string()),
+            })]
+        );
+        assert_eq!(
+            notification.events[0].watermark(),
+            Some("AQAAALvDkwLHP6n/1gRw8if8JvrGBgAAAAAAAAEAAAA=")
+        );
+    }
+}
@@ -32,3 +32,145 @@ pub struct GetFolderResponseMessage {
     /// A collection of the retrieved folders.
     pub folders: Folders,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BaseShape, CalendarPermission, CalendarPermissionLevel, CalendarPermissionReadAccess,
+        CalendarPermissions, DistinguishedFolderName, DistinguishedUser, Folder, FolderId,
+        PathToElement, PermissionAction, PermissionSet, ResponseClass, ResponseMessages,
+        UnindexedFieldURI, UserId,
+    };
+
+    #[test]
+    fn serialize_get_folder_with_permissions() {
+        let get_folder = GetFolder {
+            folder_shape: FolderShape {
+                base_shape: BaseShape::IdOnly,
+                additional_properties: Some(vec![PathToElement::FieldURI {
+                    field_URI: UnindexedFieldURI::FolderPermissionSet,
+                }]),
+            },
+            folder_ids: vec![BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::Calendar,
+                change_key: None,
+                mailbox: None,
+            }],
+        };
+
+        let expected = r#"<GetFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><FolderShape><t:BaseShape>IdOnly</t:BaseShape><t:AdditionalProperties><t:FieldURI FieldURI="folder:PermissionSet"/></t:AdditionalProperties></FolderShape><FolderIds><t:DistinguishedFolderId Id="calendar"/></FolderIds></GetFolder>"#;
+
+        assert_serialized_content(&get_folder, "GetFolder", expected);
+    }
+
+    #[test]
+    fn deserialize_get_folder_response_with_permissions() {
+        let content = r#"<GetFolderResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetFolderResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Folders>
+                            <t:CalendarFolder>
+                                <t:FolderId Id="AAMkADk=" ChangeKey="AgAAABQA" />
+                                <t:PermissionSet>
+                                    <t:CalendarPermissions>
+                                        <t:CalendarPermission>
+                                            <t:UserId>
+                                                <t:DistinguishedUser>Default</t:DistinguishedUser>
+                                            </t:UserId>
+                                            <t:CanCreateItems>false</t:CanCreateItems>
+                                            <t:CanCreateSubFolders>false</t:CanCreateSubFolders>
+                                            <t:IsFolderOwner>false</t:IsFolderOwner>
+                                            <t:IsFolderVisible>false</t:IsFolderVisible>
+                                            <t:IsFolderContact>false</t:IsFolderContact>
+                                            <t:EditItems>None</t:EditItems>
+                                            <t:DeleteItems>None</t:DeleteItems>
+                                            <t:ReadItems>TimeOnly</t:ReadItems>
+                                            <t:CalendarPermissionLevel>FreeBusyTimeOnly</t:CalendarPermissionLevel>
+                                        </t:CalendarPermission>
+                                        <t:CalendarPermission>
+                                            <t:UserId>
+                                                <t:SID>S-1-5-21-1333220396-2200287332-232816053-1118</t:SID>
+                                                <t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress>
+                                                <t:DisplayName>Assistant</t:DisplayName>
+                                            </t:UserId>
+                                            <t:CalendarPermissionLevel>Editor</t:CalendarPermissionLevel>
+                                        </t:CalendarPermission>
+                                    </t:CalendarPermissions>
+                                </t:PermissionSet>
+                            </t:CalendarFolder>
+                        </m:Folders>
+                    </m:GetFolderResponseMessage>
+                </m:ResponseMessages>
+            </GetFolderResponse>"#;
+
+        let expected = GetFolderResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(GetFolderResponseMessage {
+                    folders: Folders {
+                        inner: vec![Folder::CalendarFolder {
+                            folder_id: Some(FolderId {
+                                id: "AAMkADk=".to_string(),
+                                change_key: Some("AgAAABQA".to_string()),
+                            }),
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: None,
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            permission_set: Some(PermissionSet {
+                                permissions: None,
+                                calendar_permissions: Some(CalendarPermissions {
+                                    inner: vec![
+                                        CalendarPermission {
+                                            can_create_items: Some(false),
+                                            can_create_sub_folders: Some(false),
+                                            is_folder_owner: Some(false),
+                                            is_folder_visible: Some(false),
+                                            is_folder_contact: Some(false),
+                                            edit_items: Some(PermissionAction::None),
+                                            delete_items: Some(PermissionAction::None),
+                                            read_items: Some(
+                                                CalendarPermissionReadAccess::TimeOnly,
+                                            ),
+                                            ..CalendarPermission::new(
+                                                UserId {
+                                                    distinguished_user: Some(
+                                                        DistinguishedUser::Default,
+                                                    ),
+                                                    ..Default::default()
+                                                },
+                                                CalendarPermissionLevel::FreeBusyTimeOnly,
+                                            )
+                                        },
+                                        CalendarPermission::new(
+                                            UserId {
+                                                SID: Some(
+                                                    "S-1-5-21-1333220396-2200287332-232816053-1118"
+                                                        .to_string(),
+                                                ),
+                                                primary_smtp_address: Some(
+                                                    "assistant@example.com".to_string(),
+                                                ),
+                                                display_name: Some("Assistant".to_string()),
+                                                ..Default::default()
+                                            },
+                                            CalendarPermissionLevel::Editor,
+                                        ),
+                                    ],
+                                }),
+                            }),
+                        }],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,150 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Rule, MESSAGES_NS_URI};
+
+/// A request to list the Inbox rules of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getinboxrules-operation>
+#[derive(Clone, Debug, Default, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetInboxRulesResponseMessage, single_message)]
+pub struct GetInboxRules {
+    /// The address of the mailbox to list the rules of, if not the
+    /// authenticated user's.
+    pub mailbox_smtp_address: Option<String>,
+}
+
+/// The result of a [`GetInboxRules`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getinboxrulesresponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetInboxRulesResponseMessage {
+    /// Whether the mailbox has rules created by Outlook. If so, an
+    /// `UpdateInboxRules` request fails unless `remove_outlook_rule_blob` is
+    /// set, in which case those rules are removed.
+    pub outlook_rule_blob_exists: Option<bool>,
+
+    #[serde(default)]
+    pub inbox_rules: InboxRules,
+}
+
+/// A list of Inbox rules, in no particular order.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/inboxrules>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct InboxRules {
+    #[serde(rename = "Rule", default)]
+    pub inner: Vec<Rule>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ArrayOfEmailAddresses, ArrayOfStrings, BaseFolderId, Mailbox, ResponseClass, RuleActions,
+        RulePredicates, TargetFolderId,
+    };
+
+    #[test]
+    fn serialize_get_inbox_rules() {
+        let get_inbox_rules = GetInboxRules {
+            mailbox_smtp_address: Some("user1@example.com".to_string()),
+        };
+
+        let expected = r#"<GetInboxRules xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><MailboxSmtpAddress>user1@example.com</MailboxSmtpAddress></GetInboxRules>"#;
+
+        assert_serialized_content(&get_inbox_rules, "GetInboxRules", expected);
+    }
+
+    #[test]
+    fn deserialize_get_inbox_rules_response() {
+        let content = r#"<GetInboxRulesResponse ResponseClass="Success"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <ResponseCode>NoError</ResponseCode>
+                <OutlookRuleBlobExists>false</OutlookRuleBlobExists>
+                <InboxRules>
+                    <Rule xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                        <RuleId>AQAAAAAAAAE=</RuleId>
+                        <DisplayName>Newsletters</DisplayName>
+                        <Priority>1</Priority>
+                        <IsEnabled>true</IsEnabled>
+                        <IsNotSupported>false</IsNotSupported>
+                        <IsInError>false</IsInError>
+                        <Conditions>
+                            <ContainsSubjectStrings>
+                                <String>Newsletter</String>
+                                <String>Digest</String>
+                            </ContainsSubjectStrings>
+                            <FromAddresses>
+                                <Address>
+                                    <Name>News</Name>
+                                    <EmailAddress>news@example.com</EmailAddress>
+                                    <RoutingType>SMTP</RoutingType>
+                                </Address>
+                            </FromAddresses>
+                        </Conditions>
+                        <Exceptions />
+                        <Actions>
+                            <MarkAsRead>true</MarkAsRead>
+                            <MoveToFolder>
+                                <FolderId Id="AAMkAGEz" ChangeKey="AQAAABYA" />
+                            </MoveToFolder>
+                            <StopProcessingRules>true</StopProcessingRules>
+                        </Actions>
+                    </Rule>
+                </InboxRules>
+            </GetInboxRulesResponse>"#;
+
+        let expected = GetInboxRulesResponse {
+            response_message: ResponseClass::Success(GetInboxRulesResponseMessage {
+                outlook_rule_blob_exists: Some(false),
+                inbox_rules: InboxRules {
+                    inner: vec![Rule {
+                        rule_id: Some("AQAAAAAAAAE=".to_string()),
+                        display_name: "Newsletters".to_string(),
+                        priority: 1,
+                        is_enabled: true,
+                        is_not_supported: Some(false),
+                        is_in_error: Some(false),
+                        conditions: Some(RulePredicates {
+                            contains_subject_strings: Some(ArrayOfStrings {
+                                inner: vec!["Newsletter".to_string(), "Digest".to_string()],
+                            }),
+                            from_addresses: Some(ArrayOfEmailAddresses {
+                                inner: vec![Mailbox {
+                                    name: Some("News".to_string()),
+                                    email_address: Some("news@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    ..Default::default()
+                                }],
+                            }),
+                            ..Default::default()
+                        }),
+                        exceptions: Some(RulePredicates::default()),
+                        actions: Some(RuleActions {
+                            mark_as_read: Some(true),
+                            move_to_folder: Some(TargetFolderId {
+                                folder_id: BaseFolderId::FolderId {
+                                    id: "AAMkAGEz".to_string(),
+                                    change_key: Some("AQAAABYA".to_string()),
+                                },
+                            }),
+                            stop_processing_rules: Some(true),
+                            ..Default::default()
+                        }),
+                    }],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,216 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{Persona, PersonaId, MESSAGES_NS_URI};
+
+/// A request to get the full set of properties of a persona.
+///
+/// This operation was introduced in Exchange 2013.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getpersona-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetPersonaResponseMessage,
+    single_message,
+    response_name = "GetPersonaResponseMessage"
+)]
+pub struct GetPersona {
+    /// The identifier of the persona to get, as returned by [`FindPeople`].
+    ///
+    /// [`FindPeople`]: crate::find_people::FindPeople
+    pub persona_id: PersonaId,
+}
+
+/// The result of a [`GetPersona`] operation.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getpersonaresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetPersonaResponseMessage {
+    pub persona: Persona,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        AttributedValue, AttributedValues, Attribution, AttributionIds, Attributions, FolderId,
+        ItemId, Mailbox, MailboxType, PersonaPhoneNumber, ResponseClass,
+    };
+
+    #[test]
+    fn serialize_get_persona() {
+        let get_persona = GetPersona {
+            persona_id: PersonaId {
+                id: "AAUQAMsIQGQ=".to_string(),
+            },
+        };
+
+        let expected = r#"<GetPersona xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><PersonaId Id="AAUQAMsIQGQ="/></GetPersona>"#;
+
+        assert_serialized_content(&get_persona, "GetPersona", expected);
+    }
+
+    #[test]
+    fn deserialize_get_persona_response() {
+        let content = r#"<GetPersonaResponseMessage ResponseClass="Success"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <ResponseCode>NoError</ResponseCode>
+                <Persona>
+                    <t:PersonaId Id="AAUQAMsIQGQ=" />
+                    <t:PersonaType>Person</t:PersonaType>
+                    <t:DisplayName>Alice Test</t:DisplayName>
+                    <t:Attributions>
+                        <t:Attribution>
+                            <t:Id>0</t:Id>
+                            <t:SourceId Id="AAMkADk=" ChangeKey="EQAAABYA" />
+                            <t:DisplayName>Outlook</t:DisplayName>
+                            <t:IsWritable>true</t:IsWritable>
+                            <t:IsQuickContact>false</t:IsQuickContact>
+                            <t:IsHidden>false</t:IsHidden>
+                            <t:FolderId Id="AQMkADk=" ChangeKey="AQAAAA==" />
+                        </t:Attribution>
+                        <t:Attribution>
+                            <t:Id>1</t:Id>
+                            <t:DisplayName>GAL</t:DisplayName>
+                            <t:IsWritable>false</t:IsWritable>
+                            <t:IsQuickContact>false</t:IsQuickContact>
+                            <t:IsHidden>false</t:IsHidden>
+                        </t:Attribution>
+                    </t:Attributions>
+                    <t:DisplayNames>
+                        <t:StringAttributedValue>
+                            <t:Value>Alice Test</t:Value>
+                            <t:Attributions>
+                                <t:Attribution>0</t:Attribution>
+                                <t:Attribution>1</t:Attribution>
+                            </t:Attributions>
+                        </t:StringAttributedValue>
+                    </t:DisplayNames>
+                    <t:EmailAddresses>
+                        <t:EmailAddressAttributedValue>
+                            <t:Value>
+                                <t:Name>Alice Test</t:Name>
+                                <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                <t:RoutingType>SMTP</t:RoutingType>
+                                <t:MailboxType>Mailbox</t:MailboxType>
+                            </t:Value>
+                            <t:Attributions>
+                                <t:Attribution>1</t:Attribution>
+                            </t:Attributions>
+                        </t:EmailAddressAttributedValue>
+                    </t:EmailAddresses>
+                    <t:PhoneNumbers>
+                        <t:PhoneNumberAttributedValue>
+                            <t:Value>
+                                <t:Number>555-0100</t:Number>
+                                <t:Type>Mobile</t:Type>
+                            </t:Value>
+                            <t:Attributions>
+                                <t:Attribution>0</t:Attribution>
+                            </t:Attributions>
+                        </t:PhoneNumberAttributedValue>
+                    </t:PhoneNumbers>
+                </Persona>
+            </GetPersonaResponseMessage>"#;
+
+        let expected = GetPersonaResponse {
+            response_message: ResponseClass::Success(GetPersonaResponseMessage {
+                persona: Persona {
+                    persona_id: PersonaId {
+                        id: "AAUQAMsIQGQ=".to_string(),
+                    },
+                    persona_type: Some("Person".to_string()),
+                    creation_time: None,
+                    display_name: Some("Alice Test".to_string()),
+                    display_name_first_last: None,
+                    display_name_last_first: None,
+                    file_as: None,
+                    given_name: None,
+                    surname: None,
+                    company_name: None,
+                    email_address: None,
+                    relevance_score: None,
+                    attributions: Some(Attributions {
+                        inner: vec![
+                            Attribution {
+                                id: "0".to_string(),
+                                source_id: Some(ItemId {
+                                    id: "AAMkADk=".to_string(),
+                                    change_key: Some("EQAAABYA".to_string()),
+                                }),
+                                display_name: "Outlook".to_string(),
+                                is_writable: true,
+                                is_quick_contact: false,
+                                is_hidden: false,
+                                folder_id: Some(FolderId {
+                                    id: "AQMkADk=".to_string(),
+                                    change_key: Some("AQAAAA==".to_string()),
+                                }),
+                            },
+                            Attribution {
+                                id: "1".to_string(),
+                                source_id: None,
+                                display_name: "GAL".to_string(),
+                                is_writable: false,
+                                is_quick_contact: false,
+                                is_hidden: false,
+                                folder_id: None,
+                            },
+                        ],
+                    }),
+                    display_names: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: "Alice Test".to_string(),
+                            attributions: AttributionIds {
+                                inner: vec!["0".to_string(), "1".to_string()],
+                            },
+                        }],
+                    }),
+                    email_addresses: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: Mailbox {
+                                name: Some("Alice Test".to_string()),
+                                email_address: Some("alice@example.com".to_string()),
+                                routing_type: Some("SMTP".to_string()),
+                                mailbox_type: Some(MailboxType::Mailbox),
+                                item_id: None,
+                            },
+                            attributions: AttributionIds {
+                                inner: vec!["1".to_string()],
+                            },
+                        }],
+                    }),
+                    phone_numbers: Some(AttributedValues {
+                        inner: vec![AttributedValue {
+                            value: PersonaPhoneNumber {
+                                number: "555-0100".to_string(),
+                                phone_number_type: "Mobile".to_string(),
+                            },
+                            attributions: AttributionIds {
+                                inner: vec!["0".to_string()],
+                            },
+                        }],
+                    }),
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+
+        let response: GetPersonaResponse = quick_xml::de::from_str(content).unwrap();
+        let ResponseClass::Success(message) = &response.response_message else {
+            panic!("response should be a success");
+        };
+        let source = message.persona.attribution("1").unwrap();
+        assert_eq!(source.display_name, "GAL");
+    }
+}
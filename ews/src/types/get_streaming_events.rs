@@ -0,0 +1,478 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    response::ResponseCode, soap::Envelope, Error, MessageXml, Notification, SubscriptionIds,
+    MESSAGES_NS_URI,
+};
+
+/// A request to open a connection over which the events of one or more
+/// streaming subscriptions are delivered as they occur.
+///
+/// The server keeps the response open for up to `connection_timeout`
+/// minutes, writing a complete SOAP envelope each time events occur, and
+/// periodically otherwise. Use a [`StreamingEventsParser`] to read them.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getstreamingevents>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetStreamingEventsResponseMessage, error = GetStreamingEventsError)]
+pub struct GetStreamingEvents {
+    /// The identifiers of the subscriptions, as returned by [`Subscribe`].
+    ///
+    /// [`Subscribe`]: crate::subscribe::Subscribe
+    pub subscription_ids: SubscriptionIds,
+
+    /// The number of minutes to keep the connection open, in the range
+    /// `1..=30`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/connectiontimeout>
+    pub connection_timeout: u32,
+}
+
+/// One of the responses written to a [`GetStreamingEvents`] connection.
+///
+/// If the server can't deliver the events of a subscription, it sends a
+/// [`GetStreamingEventsError`] instead.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getstreamingeventsresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetStreamingEventsResponseMessage {
+    /// The notifications of events which occurred since the previous
+    /// response, if any.
+    pub notifications: Option<Notifications>,
+
+    /// The status of the connection, included when no events occurred.
+    pub connection_status: Option<ConnectionStatus>,
+}
+
+/// An error written to a [`GetStreamingEvents`] connection.
+///
+/// With [`ResponseCode::ErrorMissedNotificationEvents`], the affected
+/// subscriptions are listed in `error_subscription_ids`. These subscriptions
+/// must be recreated, and any changes since the last event fetched with e.g.
+/// [`SyncFolderItems`].
+///
+/// [`SyncFolderItems`]: crate::sync_folder_items::SyncFolderItems
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetStreamingEventsError {
+    pub message_text: String,
+    pub response_code: ResponseCode,
+    pub message_xml: Option<MessageXml>,
+
+    /// The subscriptions affected by the error.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/errorsubscriptionids>
+    pub error_subscription_ids: Option<SubscriptionIds>,
+}
+
+/// The notifications included in a [`GetStreamingEventsResponseMessage`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/notifications-ex15websvcsotherref>
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct Notifications {
+    #[serde(rename = "Notification", default)]
+    pub inner: Vec<Notification>,
+}
+
+/// The status of a [`GetStreamingEvents`] connection.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/connectionstatus>
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+pub enum ConnectionStatus {
+    /// The connection is open, and more responses will follow.
+    #[serde(rename = "OK")]
+    Ok,
+
+    /// The connection has been closed by the server, e.g. because its
+    /// timeout expired. A new request is needed to keep receiving events.
+    Closed,
+}
+
+/// An incremental parser for the body of a response to
+/// [`GetStreamingEvents`].
+///
+/// The body of such a response is a sequence of complete SOAP envelopes,
+/// written over several minutes. The parser accepts the body in chunks of
+/// any size as they are received, and yields each envelope as soon as it is
+/// complete.
+#[derive(Debug, Default)]
+pub struct StreamingEventsParser {
+    /// Received bytes which are not part of an envelope yielded yet.
+    buffer: Vec<u8>,
+
+    /// The number of bytes of `buffer` which have been scanned.
+    scanned: usize,
+
+    /// The state of the scanner after the last scanned byte.
+    state: ScanState,
+
+    /// The number of elements open after the last scanned byte.
+    depth: usize,
+}
+
+impl StreamingEventsParser {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Adds a chunk of the response body to the parser.
+    pub fn push(&mut self, chunk: &[u8]) {
+        self.buffer.extend_from_slice(chunk);
+    }
+
+    /// Returns the next complete envelope received, if any.
+    ///
+    /// An error returned for an envelope doesn't prevent the parsing of
+    /// subsequent ones.
+    pub fn next_response(&mut self) -> Option<Result<Envelope<GetStreamingEventsResponse>, Error>> {
+        let end = self.scan()?;
+        let document: Vec<u8> = self.buffer.drain(..end).collect();
+        self.scanned = 0;
+
+        // Envelopes may be separated by whitespace, which isn't allowed before
+        // an XML declaration.
+        let start = document
+            .iter()
+            .position(|byte| !byte.is_ascii_whitespace())
+            .unwrap_or(document.len());
+
+        Some(Envelope::from_xml_document(&document[start..]))
+    }
+
+    /// Whether part of an envelope has been received without being complete.
+    pub fn has_partial_response(&self) -> bool {
+        self.buffer.iter().any(|byte| !byte.is_ascii_whitespace())
+    }
+
+    /// Scans the buffer up to the end of the first complete document, and
+    /// returns its length.
+    fn scan(&mut self) -> Option<usize> {
+        while self.scanned < self.buffer.len() {
+            let byte = self.buffer[self.scanned];
+            self.scanned += 1;
+
+            let (state, event) = self.state.next(byte);
+            self.state = state;
+
+            match event {
+                Some(TagEvent::Start) => self.depth += 1,
+                Some(TagEvent::End) => self.depth = self.depth.saturating_sub(1),
+                Some(TagEvent::Empty) | None => (),
+            }
+
+            // A document is complete once its root element is closed.
+            if self.depth == 0 && matches!(event, Some(TagEvent::End | TagEvent::Empty)) {
+                return Some(self.scanned);
+            }
+        }
+
+        None
+    }
+}
+
+/// The position of the scanner within the XML markup.
+///
+/// This only tracks enough of the syntax to find where elements start and
+/// end, so that documents can be split without being fully parsed.
+#[derive(Clone, Copy, Debug, Default)]
+enum ScanState {
+    /// Within character data, or between documents.
+    #[default]
+    Text,
+
+    /// After a `<`.
+    MarkupStart,
+
+    /// Within a start tag, possibly within an attribute value.
+    StartTag {
+        quote: Option<u8>,
+        after_slash: bool,
+    },
+
+    /// Within an end tag.
+    EndTag,
+
+    /// Within a processing instruction or an XML declaration.
+    ProcessingInstruction { after_question_mark: bool },
+
+    /// After a `<!`.
+    BangStart,
+
+    /// After a `<!-`.
+    BangDash,
+
+    /// Within a comment.
+    Comment { dashes: u8 },
+
+    /// Within a CDATA section.
+    CData { brackets: u8 },
+
+    /// Within a document type declaration.
+    Declaration,
+}
+
+/// An element boundary found by the scanner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum TagEvent {
+    Start,
+    End,
+    Empty,
+}
+
+impl ScanState {
+    fn next(self, byte: u8) -> (Self, Option<TagEvent>) {
+        let state = match (self, byte) {
+            (Self::Text, b'<') => Self::MarkupStart,
+            (Self::Text, _) => Self::Text,
+
+            (Self::MarkupStart, b'/') => Self::EndTag,
+            (Self::MarkupStart, b'?') => Self::ProcessingInstruction {
+                after_question_mark: false,
+            },
+            (Self::MarkupStart, b'!') => Self::BangStart,
+            (Self::MarkupStart, _) => Self::StartTag {
+                quote: None,
+                after_slash: false,
+            },
+
+            (
+                Self::StartTag {
+                    quote: Some(quote), ..
+                },
+                _,
+            ) => Self::StartTag {
+                quote: (byte != quote).then_some(quote),
+                after_slash: false,
+            },
+            (Self::StartTag { after_slash, .. }, b'>') => {
+                let event = if after_slash {
+                    TagEvent::Empty
+                } else {
+                    TagEvent::Start
+                };
+
+                return (Self::Text, Some(event));
+            }
+            (Self::StartTag { .. }, b'"' | b'\'') => Self::StartTag {
+                quote: Some(byte),
+                after_slash: false,
+            },
+            (Self::StartTag { .. }, _) => Self::StartTag {
+                quote: None,
+                after_slash: byte == b'/',
+            },
+
+            (Self::EndTag, b'>') => return (Self::Text, Some(TagEvent::End)),
+            (Self::EndTag, _) => Self::EndTag,
+
+            (
+                Self::ProcessingInstruction {
+                    after_question_mark: true,
+                },
+                b'>',
+            ) => Self::Text,
+            (Self::ProcessingInstruction { .. }, _) => Self::ProcessingInstruction {
+                after_question_mark: byte == b'?',
+            },
+
+            (Self::BangStart, b'-') => Self::BangDash,
+            (Self::BangStart, b'[') => Self::CData { brackets: 0 },
+            (Self::BangStart, b'>') => Self::Text,
+            (Self::BangStart, _) => Self::Declaration,
+
+            (Self::BangDash, b'-') => Self::Comment { dashes: 0 },
+            (Self::BangDash, _) => Self::Declaration,
+
+            (Self::Comment { dashes }, b'>') if dashes >= 2 => Self::Text,
+            (Self::Comment { dashes }, b'-') => Self::Comment {
+                dashes: dashes.saturating_add(1),
+            },
+            (Self::Comment { .. }, _) => Self::Comment { dashes: 0 },
+
+            (Self::CData { brackets }, b'>') if brackets >= 2 => Self::Text,
+            (Self::CData { brackets }, b']') => Self::CData {
+                brackets: brackets.saturating_add(1),
+            },
+            (Self::CData { .. }, _) => Self::CData { brackets: 0 },
+
+            (Self::Declaration, b'>') => Self::Text,
+            (Self::Declaration, _) => Self::Declaration,
+        };
+
+        (state, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::assert_serialized_content, NotificationEvent, ResponseClass};
+
+    const KEEPALIVE: &str = r#"<?xml version="1.0" encoding="utf-8"?><Envelope xmlns="http://schemas.xmlsoap.org/soap/envelope/"><soap11:Header xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><ServerVersionInfo xmlns="http://schemas.microsoft.com/exchange/services/2006/types" MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08"/></soap11:Header><soap11:Body xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><m:GetStreamingEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:GetStreamingEventsResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:ConnectionStatus>OK</m:ConnectionStatus></m:GetStreamingEventsResponseMessage></m:ResponseMessages></m:GetStreamingEventsResponse></soap11:Body></Envelope>"#;
+
+    const NOTIFICATION: &str = r#"<Envelope xmlns="http://schemas.xmlsoap.org/soap/envelope/">
+  <soap11:Header xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/">
+    <ServerVersionInfo xmlns="http://schemas.microsoft.com/exchange/services/2006/types" MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08" />
+  </soap11:Header>
+  <soap11:Body xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/">
+    <!-- A comment containing markup: <m:Notifications> -->
+    <m:GetStreamingEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+      <m:ResponseMessages>
+        <m:GetStreamingEventsResponseMessage ResponseClass="Success">
+          <m:ResponseCode>NoError</m:ResponseCode>
+          <m:Notifications>
+            <m:Notification>
+              <t:SubscriptionId>JwBkYjVwcjA2bWIxNDY0LmV1cnByZDA2LnByb2Qub3V0bG9vay5jb20QAAAA</t:SubscriptionId>
+              <t:NewMailEvent>
+                <t:TimeStamp>2024-05-02T09:12:44Z</t:TimeStamp>
+                <t:ItemId Id="AAMkADk=" ChangeKey="CQAAAA==" />
+                <t:ParentFolderId Id="AQMkAGI=" ChangeKey="AQAAAA==" />
+              </t:NewMailEvent>
+            </m:Notification>
+          </m:Notifications>
+        </m:GetStreamingEventsResponseMessage>
+      </m:ResponseMessages>
+    </m:GetStreamingEventsResponse>
+  </soap11:Body>
+</Envelope>"#;
+
+    const CLOSED: &str = r#"<Envelope xmlns="http://schemas.xmlsoap.org/soap/envelope/"><soap11:Header xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><ServerVersionInfo xmlns="http://schemas.microsoft.com/exchange/services/2006/types" MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08"/></soap11:Header><soap11:Body xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><m:GetStreamingEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:GetStreamingEventsResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:ConnectionStatus>Closed</m:ConnectionStatus></m:GetStreamingEventsResponseMessage></m:ResponseMessages></m:GetStreamingEventsResponse></soap11:Body></Envelope>"#;
+
+    const MISSED_EVENTS: &str = r#"<Envelope xmlns="http://schemas.xmlsoap.org/soap/envelope/"><soap11:Header xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><ServerVersionInfo xmlns="http://schemas.microsoft.com/exchange/services/2006/types" MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08"/></soap11:Header><soap11:Body xmlns:soap11="http://schemas.xmlsoap.org/soap/envelope/"><m:GetStreamingEventsResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:GetStreamingEventsResponseMessage ResponseClass="Error"><m:MessageText>Events were lost for this subscription.</m:MessageText><m:ResponseCode>ErrorMissedNotificationEvents</m:ResponseCode><m:DescriptiveLinkKey>0</m:DescriptiveLinkKey><m:ErrorSubscriptionIds><m:SubscriptionId>JwBkYjVwcjA2bWIxNDY0LmV1cnByZDA2LnByb2Qub3V0bG9vay5jb20QAAAA</m:SubscriptionId></m:ErrorSubscriptionIds></m:GetStreamingEventsResponseMessage></m:ResponseMessages></m:GetStreamingEventsResponse></soap11:Body></Envelope>"#;
+
+    fn single_message(
+        envelope: Envelope<GetStreamingEventsResponse>,
+    ) -> ResponseClass<GetStreamingEventsResponseMessage, GetStreamingEventsError> {
+        let mut messages = envelope.body.response_messages.response_messages;
+        assert_eq!(messages.len(), 1);
+
+        messages.remove(0)
+    }
+
+    #[test]
+    fn serialize_get_streaming_events() {
+        let get_streaming_events = GetStreamingEvents {
+            subscription_ids: SubscriptionIds {
+                inner: vec!["JwBkYjVwcjA2bWIxNDY0".to_string()],
+            },
+            connection_timeout: 30,
+        };
+
+        let expected = r#"<GetStreamingEvents xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><SubscriptionIds><t:SubscriptionId>JwBkYjVwcjA2bWIxNDY0</t:SubscriptionId></SubscriptionIds><ConnectionTimeout>30</ConnectionTimeout></GetStreamingEvents>"#;
+
+        assert_serialized_content(&get_streaming_events, "GetStreamingEvents", expected);
+    }
+
+    #[test]
+    fn parse_envelopes_split_across_chunks() {
+        let stream = [KEEPALIVE, "\r\n", NOTIFICATION, CLOSED].concat();
+
+        // Feed the stream in small chunks, which split tags, attribute values
+        // and comments, and check each envelope is yielded once complete.
+        let mut parser = StreamingEventsParser::new();
+        let mut messages = Vec::new();
+        for chunk in stream.as_bytes().chunks(7) {
+            parser.push(chunk);
+            while let Some(envelope) = parser.next_response() {
+                messages.push(single_message(envelope.unwrap()));
+            }
+        }
+
+        assert!(!parser.has_partial_response());
+        assert_eq!(messages.len(), 3);
+
+        assert_eq!(
+            messages[0],
+            ResponseClass::Success(GetStreamingEventsResponseMessage {
+                notifications: None,
+                connection_status: Some(ConnectionStatus::Ok),
+            })
+        );
+
+        let ResponseClass::Success(GetStreamingEventsResponseMessage {
+            notifications: Some(notifications),
+            connection_status: None,
+        }) = &messages[1]
+        else {
+            panic!("expected notifications, got {:?}", messages[1]);
+        };
+        assert_eq!(notifications.inner.len(), 1);
+        assert_eq!(
+            notifications.inner[0].subscription_id,
+            "JwBkYjVwcjA2bWIxNDY0LmV1cnByZDA2LnByb2Qub3V0bG9vay5jb20QAAAA"
+        );
+        let [NotificationEvent::NewMailEvent(event)] = notifications.inner[0].events.as_slice()
+        else {
+            panic!("expected a new mail event");
+        };
+        assert_eq!(event.watermark, None);
+        assert_eq!(event.item_id.as_ref().unwrap().id, "AAMkADk=");
+
+        assert_eq!(
+            messages[2],
+            ResponseClass::Success(GetStreamingEventsResponseMessage {
+                notifications: None,
+                connection_status: Some(ConnectionStatus::Closed),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_incomplete_envelope() {
+        let mut parser = StreamingEventsParser::new();
+        let (first, second) = KEEPALIVE.split_at(KEEPALIVE.len() - 1);
+
+        parser.push(first.as_bytes());
+        assert!(parser.next_response().is_none());
+        assert!(parser.has_partial_response());
+
+        parser.push(second.as_bytes());
+        assert!(parser.next_response().unwrap().is_ok());
+        assert!(parser.next_response().is_none());
+        assert!(!parser.has_partial_response());
+    }
+
+    #[test]
+    fn parse_missed_notification_events() {
+        let mut parser = StreamingEventsParser::new();
+        parser.push(MISSED_EVENTS.as_bytes());
+
+        let message = single_message(parser.next_response().unwrap().unwrap());
+
+        let ResponseClass::Error(GetStreamingEventsError {
+            response_code,
+            error_subscription_ids,
+            ..
+        }) = message
+        else {
+            panic!("expected an error, got {message:?}");
+        };
+        assert_eq!(response_code, ResponseCode::ErrorMissedNotificationEvents);
+        assert_eq!(
+            error_subscription_ids,
+            Some(SubscriptionIds {
+                inner: vec![
+                    "JwBkYjVwcjA2bWIxNDY0LmV1cnByZDA2LnByb2Qub3V0bG9vay5jb20QAAAA".to_string()
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_after_invalid_envelope() {
+        let mut parser = StreamingEventsParser::new();
+        parser.push(b"<Envelope><Body><Unexpected/></Body></Envelope>");
+        parser.push(CLOSED.as_bytes());
+
+        assert!(parser.next_response().unwrap().is_err());
+        assert!(parser.next_response().unwrap().is_ok());
+    }
+}
@@ -0,0 +1,115 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    UserConfigurationData, UserConfigurationName, UserConfigurationProperties, MESSAGES_NS_URI,
+};
+
+/// A request to read a user configuration object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserconfiguration>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(GetUserConfigurationResponseMessage)]
+pub struct GetUserConfiguration {
+    pub user_configuration_name: UserConfigurationName,
+
+    /// The parts of the object to include in the response.
+    pub user_configuration_properties: UserConfigurationProperties,
+}
+
+/// A response to a [`GetUserConfiguration`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuserconfigurationresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct GetUserConfigurationResponseMessage {
+    pub user_configuration: UserConfigurationData,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BaseFolderId, DistinguishedFolderName, ItemId, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_get_user_configuration() {
+        let get_user_configuration = GetUserConfiguration {
+            user_configuration_name: UserConfigurationName {
+                name: "CategoryList".to_string(),
+                folder_id: BaseFolderId::DistinguishedFolderId {
+                    id: DistinguishedFolderName::Calendar,
+                    change_key: None,
+                    mailbox: None,
+                },
+            },
+            user_configuration_properties: UserConfigurationProperties::XmlData,
+        };
+
+        let expected = r#"<GetUserConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><UserConfigurationName Name="CategoryList"><t:DistinguishedFolderId Id="calendar"/></UserConfigurationName><UserConfigurationProperties>XmlData</UserConfigurationProperties></GetUserConfiguration>"#;
+
+        assert_serialized_content(&get_user_configuration, "GetUserConfiguration", expected);
+    }
+
+    #[test]
+    fn deserialize_get_user_configuration_response() {
+        let content = r#"<GetUserConfigurationResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:GetUserConfigurationResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:UserConfiguration>
+                            <t:UserConfigurationName Name="CategoryList">
+                                <t:DistinguishedFolderId Id="calendar" />
+                            </t:UserConfigurationName>
+                            <t:ItemId Id="AAMkADk=" ChangeKey="CQAAAA==" />
+                            <t:XmlData>PGNhdGVnb3JpZXMvPg==</t:XmlData>
+                        </m:UserConfiguration>
+                    </m:GetUserConfigurationResponseMessage>
+                </m:ResponseMessages>
+            </GetUserConfigurationResponse>"#;
+
+        let expected = GetUserConfigurationResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    GetUserConfigurationResponseMessage {
+                        user_configuration: UserConfigurationData {
+                            item_id: Some(ItemId {
+                                id: "AAMkADk=".to_string(),
+                                change_key: Some("CQAAAA==".to_string()),
+                            }),
+                            xml_data: Some("PGNhdGVnb3JpZXMvPg==".to_string()),
+                            binary_data: None,
+                        },
+                    },
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn decode_user_configuration_data() {
+        let data = UserConfigurationData {
+            item_id: None,
+            xml_data: Some("PGNhdGVnb3JpZXMvPg==".to_string()),
+            binary_data: None,
+        };
+
+        assert_eq!(
+            data.decoded_xml_data().unwrap(),
+            Some(b"<categories/>".to_vec())
+        );
+        assert_eq!(data.decoded_binary_data().unwrap(), None);
+    }
+}
@@ -0,0 +1,157 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{EmailAddress, ExternalAudience, ResponseClass, UserOofSettings, MESSAGES_NS_URI};
+
+/// A request to read the out-of-office settings of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/getuseroofsettings-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    GetUserOofSettingsResponseMessage,
+    single_message,
+    name = "GetUserOofSettingsRequest",
+    try_from = RawGetUserOofSettingsResponse
+)]
+pub struct GetUserOofSettings {
+    /// The mailbox to read the settings of. Only the address is required.
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: EmailAddress,
+}
+
+/// The out-of-office settings returned by a [`GetUserOofSettings`] request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetUserOofSettingsResponseMessage {
+    pub oof_settings: UserOofSettings,
+
+    /// The most permissive external audience the mailbox is allowed to set.
+    pub allow_external_oof: Option<ExternalAudience>,
+}
+
+/// The response as sent by EWS, with the settings as siblings of the response
+/// message rather than children of it.
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawGetUserOofSettingsResponse {
+    response_message: ResponseClass<RawResponseMessage>,
+    oof_settings: Option<UserOofSettings>,
+    allow_external_oof: Option<ExternalAudience>,
+}
+
+#[derive(Deserialize)]
+struct RawResponseMessage {}
+
+impl TryFrom<RawGetUserOofSettingsResponse> for GetUserOofSettingsResponse {
+    type Error = &'static str;
+
+    fn try_from(raw: RawGetUserOofSettingsResponse) -> Result<Self, Self::Error> {
+        let RawGetUserOofSettingsResponse {
+            response_message,
+            oof_settings,
+            allow_external_oof,
+        } = raw;
+
+        let into_message = || {
+            oof_settings
+                .map(|oof_settings| GetUserOofSettingsResponseMessage {
+                    oof_settings,
+                    allow_external_oof,
+                })
+                .ok_or("missing OofSettings in successful response")
+        };
+
+        let response_message = match response_message {
+            ResponseClass::Success(_) => ResponseClass::Success(into_message()?),
+            ResponseClass::Warning(_) => ResponseClass::Warning(into_message()?),
+            ResponseClass::Error(err) => ResponseClass::Error(err),
+        };
+
+        Ok(Self { response_message })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DateTime, Duration, OofState, ReplyBody,
+    };
+
+    #[test]
+    fn serialize_get_user_oof_settings() {
+        let get_user_oof_settings = GetUserOofSettings {
+            mailbox: EmailAddress {
+                address: "user1@example.com".to_string(),
+                ..Default::default()
+            },
+        };
+
+        let expected = r#"<GetUserOofSettingsRequest xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><t:Mailbox><t:Address>user1@example.com</t:Address></t:Mailbox></GetUserOofSettingsRequest>"#;
+
+        assert_serialized_content(
+            &get_user_oof_settings,
+            "GetUserOofSettingsRequest",
+            expected,
+        );
+    }
+
+    #[test]
+    fn deserialize_get_user_oof_settings_response() {
+        let content = r#"<GetUserOofSettingsResponse
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <ResponseMessage ResponseClass="Success">
+                    <ResponseCode>NoError</ResponseCode>
+                </ResponseMessage>
+                <OofSettings xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                    <OofState>Scheduled</OofState>
+                    <ExternalAudience>Known</ExternalAudience>
+                    <Duration>
+                        <StartTime>2024-05-02T09:12:44Z</StartTime>
+                        <EndTime>2024-05-09T09:12:44Z</EndTime>
+                    </Duration>
+                    <InternalReply xml:lang="en-US">
+                        <Message>I'm away this week.</Message>
+                    </InternalReply>
+                    <ExternalReply>
+                        <Message />
+                    </ExternalReply>
+                </OofSettings>
+                <AllowExternalOof>All</AllowExternalOof>
+            </GetUserOofSettingsResponse>"#;
+
+        let expected = GetUserOofSettingsResponse {
+            response_message: ResponseClass::Success(GetUserOofSettingsResponseMessage {
+                oof_settings: UserOofSettings {
+                    oof_state: OofState::Scheduled,
+                    external_audience: ExternalAudience::Known,
+                    duration: Some(Duration {
+                        start_time: DateTime(
+                            time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap(),
+                        ),
+                        end_time: DateTime(
+                            time::OffsetDateTime::from_unix_timestamp(1715245964).unwrap(),
+                        ),
+                    }),
+                    internal_reply: Some(ReplyBody {
+                        lang: Some("en-US".to_string()),
+                        message: Some("I'm away this week.".to_string()),
+                    }),
+                    external_reply: Some(ReplyBody {
+                        lang: None,
+                        message: Some("".to_string()),
+                    }),
+                },
+                allow_external_oof: Some(ExternalAudience::All),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
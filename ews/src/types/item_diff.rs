@@ -0,0 +1,962 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Computation of the changes needed to update an item from one state to
+//! another, for use in an [`UpdateItem`] request.
+//!
+//! Only properties which can be set by clients are compared. Read-only
+//! properties, such as [`Message::date_time_received`], and those which are
+//! changed through other operations, such as [`Message::attachments`], are
+//! ignored.
+//!
+//! [`UpdateItem`]: crate::update_item::UpdateItem
+
+use crate::{
+    update_item::ItemChangeDescription, CalendarItem, Contact, Dictionary, DictionaryURI, Error,
+    ExtendedProperty, Message, PathToElement, PhysicalAddressEntry, PhysicalAddressKey, RealItem,
+    UnindexedFieldURI,
+};
+
+/// Computes the changes needed to turn `original` into `modified`.
+///
+/// Both items must be of the same kind.
+pub fn diff_items(
+    original: &RealItem,
+    modified: &RealItem,
+) -> Result<Vec<ItemChangeDescription>, Error> {
+    let changes = match (original, modified) {
+        (RealItem::Message(original), RealItem::Message(modified)) => {
+            diff_message_fields(original, modified, RealItem::Message)
+        }
+        (RealItem::CalendarItem(original), RealItem::CalendarItem(modified)) => {
+            diff_calendar_items(original, modified)
+        }
+        (RealItem::MeetingMessage(original), RealItem::MeetingMessage(modified)) => {
+            diff_message_fields(original, modified, RealItem::MeetingMessage)
+        }
+        (RealItem::MeetingRequest(original), RealItem::MeetingRequest(modified)) => {
+            diff_message_fields(original, modified, RealItem::MeetingRequest)
+        }
+        (RealItem::MeetingResponse(original), RealItem::MeetingResponse(modified)) => {
+            diff_message_fields(original, modified, RealItem::MeetingResponse)
+        }
+        (RealItem::MeetingCancellation(original), RealItem::MeetingCancellation(modified)) => {
+            diff_message_fields(original, modified, RealItem::MeetingCancellation)
+        }
+        (RealItem::Contact(original), RealItem::Contact(modified)) => {
+            diff_contacts(original, modified)
+        }
+
+        _ => return Err(Error::MismatchedItemKinds),
+    };
+
+    Ok(changes)
+}
+
+/// Computes the changes needed to turn the message `original` into
+/// `modified`.
+pub fn diff_messages(original: &Message, modified: &Message) -> Vec<ItemChangeDescription> {
+    diff_message_fields(original, modified, RealItem::Message)
+}
+
+/// Computes the changes needed to turn the contact `original` into
+/// `modified`.
+pub fn diff_contacts(original: &Contact, modified: &Contact) -> Vec<ItemChangeDescription> {
+    let mut changes = Vec::new();
+
+    // The payload of each change is a contact with only the changed field set,
+    // which needs the field's name in a struct expression.
+    macro_rules! contact_field {
+        ($field_uri:expr, $field:ident) => {
+            diff_field(
+                &mut changes,
+                $field_uri,
+                &original.$field,
+                &modified.$field,
+                &|value| {
+                    RealItem::Contact(Contact {
+                        $field: Some(value),
+                        ..Default::default()
+                    })
+                },
+            )
+        };
+    }
+
+    contact_field!(UnindexedFieldURI::ItemItemClass, item_class);
+    contact_field!(UnindexedFieldURI::ItemSubject, subject);
+    contact_field!(UnindexedFieldURI::ItemSensitivity, sensitivity);
+    contact_field!(UnindexedFieldURI::ItemBody, body);
+    contact_field!(UnindexedFieldURI::ItemCategories, categories);
+    contact_field!(UnindexedFieldURI::ItemImportance, importance);
+    contact_field!(UnindexedFieldURI::ContactsFileAs, file_as);
+    contact_field!(UnindexedFieldURI::ContactsDisplayName, display_name);
+    contact_field!(UnindexedFieldURI::ContactsGivenName, given_name);
+    contact_field!(UnindexedFieldURI::ContactsInitials, initials);
+    contact_field!(UnindexedFieldURI::ContactsMiddleName, middle_name);
+    contact_field!(UnindexedFieldURI::ContactsNickname, nickname);
+    contact_field!(UnindexedFieldURI::ContactsCompanyName, company_name);
+    contact_field!(UnindexedFieldURI::ContactsAssistantName, assistant_name);
+    contact_field!(UnindexedFieldURI::ContactsBirthday, birthday);
+    contact_field!(
+        UnindexedFieldURI::ContactsBusinessHomePage,
+        business_home_page
+    );
+    contact_field!(UnindexedFieldURI::ContactsDepartment, department);
+    contact_field!(UnindexedFieldURI::ContactsGeneration, generation);
+    contact_field!(UnindexedFieldURI::ContactsJobTitle, job_title);
+    contact_field!(UnindexedFieldURI::ContactsManager, manager);
+    contact_field!(UnindexedFieldURI::ContactsOfficeLocation, office_location);
+    contact_field!(UnindexedFieldURI::ContactsProfession, profession);
+    contact_field!(UnindexedFieldURI::ContactsSpouseName, spouse_name);
+    contact_field!(UnindexedFieldURI::ContactsSurname, surname);
+
+    diff_entries(
+        &mut changes,
+        DictionaryURI::ContactsEmailAddress,
+        &original.email_addresses,
+        &modified.email_addresses,
+        |entry| entry.key.as_str(),
+        |entry| {
+            RealItem::Contact(Contact {
+                email_addresses: Some(Dictionary {
+                    entries: vec![entry],
+                }),
+                ..Default::default()
+            })
+        },
+    );
+    diff_entries(
+        &mut changes,
+        DictionaryURI::ContactsPhoneNumber,
+        &original.phone_numbers,
+        &modified.phone_numbers,
+        |entry| entry.key.as_str(),
+        |entry| {
+            RealItem::Contact(Contact {
+                phone_numbers: Some(Dictionary {
+                    entries: vec![entry],
+                }),
+                ..Default::default()
+            })
+        },
+    );
+    diff_entries(
+        &mut changes,
+        DictionaryURI::ContactsImAddress,
+        &original.im_addresses,
+        &modified.im_addresses,
+        |entry| entry.key.as_str(),
+        |entry| {
+            RealItem::Contact(Contact {
+                im_addresses: Some(Dictionary {
+                    entries: vec![entry],
+                }),
+                ..Default::default()
+            })
+        },
+    );
+    diff_physical_addresses(
+        &mut changes,
+        &original.physical_addresses,
+        &modified.physical_addresses,
+    );
+
+    diff_extended_properties(
+        &mut changes,
+        &original.extended_property,
+        &modified.extended_property,
+        |property| {
+            RealItem::Contact(Contact {
+                extended_property: Some(vec![property]),
+                ..Default::default()
+            })
+        },
+    );
+
+    changes
+}
+
+/// Computes the changes needed to turn the calendar item `original` into
+/// `modified`.
+pub fn diff_calendar_items(
+    original: &CalendarItem,
+    modified: &CalendarItem,
+) -> Vec<ItemChangeDescription> {
+    let mut changes = Vec::new();
+
+    // See `diff_contacts`.
+    macro_rules! calendar_field {
+        ($field_uri:expr, $field:ident) => {
+            diff_field(
+                &mut changes,
+                $field_uri,
+                &original.$field,
+                &modified.$field,
+                &|value| {
+                    RealItem::CalendarItem(CalendarItem {
+                        $field: Some(value),
+                        ..Default::default()
+                    })
+                },
+            )
+        };
+    }
+
+    calendar_field!(UnindexedFieldURI::ItemItemClass, item_class);
+    calendar_field!(UnindexedFieldURI::ItemSubject, subject);
+    calendar_field!(UnindexedFieldURI::ItemSensitivity, sensitivity);
+    calendar_field!(UnindexedFieldURI::ItemBody, body);
+    calendar_field!(UnindexedFieldURI::ItemCategories, categories);
+    calendar_field!(UnindexedFieldURI::ItemImportance, importance);
+    calendar_field!(UnindexedFieldURI::ItemReminderDueBy, reminder_due_by);
+    calendar_field!(UnindexedFieldURI::ItemReminderIsSet, reminder_is_set);
+    calendar_field!(
+        UnindexedFieldURI::ItemReminderMinutesBeforeStart,
+        reminder_minutes_before_start
+    );
+    calendar_field!(UnindexedFieldURI::ItemCulture, culture);
+    calendar_field!(UnindexedFieldURI::CalendarUID, uid);
+    calendar_field!(UnindexedFieldURI::CalendarStart, start);
+    calendar_field!(UnindexedFieldURI::CalendarEnd, end);
+    calendar_field!(UnindexedFieldURI::CalendarIsAllDayEvent, is_all_day_event);
+    calendar_field!(
+        UnindexedFieldURI::CalendarLegacyFreeBusyStatus,
+        legacy_free_busy_status
+    );
+    calendar_field!(UnindexedFieldURI::CalendarLocation, location);
+    calendar_field!(UnindexedFieldURI::CalendarWhen, when);
+    calendar_field!(
+        UnindexedFieldURI::CalendarIsResponseRequested,
+        is_response_requested
+    );
+    calendar_field!(
+        UnindexedFieldURI::CalendarRequiredAttendees,
+        required_attendees
+    );
+    calendar_field!(
+        UnindexedFieldURI::CalendarOptionalAttendees,
+        optional_attendees
+    );
+    calendar_field!(UnindexedFieldURI::CalendarResources, resources);
+    calendar_field!(UnindexedFieldURI::CalendarConferenceType, conference_type);
+    calendar_field!(
+        UnindexedFieldURI::CalendarAllowNewTimeProposal,
+        allow_new_time_proposal
+    );
+    calendar_field!(
+        UnindexedFieldURI::CalendarIsOnlineMeeting,
+        is_online_meeting
+    );
+    calendar_field!(
+        UnindexedFieldURI::CalendarMeetingWorkspaceUrl,
+        meeting_workspace_url
+    );
+    calendar_field!(UnindexedFieldURI::CalendarNetShowUrl, net_show_url);
+
+    diff_extended_properties(
+        &mut changes,
+        &original.extended_property,
+        &modified.extended_property,
+        |property| {
+            RealItem::CalendarItem(CalendarItem {
+                extended_property: Some(vec![property]),
+                ..Default::default()
+            })
+        },
+    );
+
+    changes
+}
+
+/// Computes the changes between two messages, wrapping each new value in an
+/// item of the kind produced by `wrap`.
+fn diff_message_fields(
+    original: &Message,
+    modified: &Message,
+    wrap: fn(Message) -> RealItem,
+) -> Vec<ItemChangeDescription> {
+    let mut changes = Vec::new();
+
+    // See `diff_contacts`.
+    macro_rules! message_field {
+        ($field_uri:expr, $field:ident) => {
+            diff_field(
+                &mut changes,
+                $field_uri,
+                &original.$field,
+                &modified.$field,
+                &|value| {
+                    wrap(Message {
+                        $field: Some(value),
+                        ..Default::default()
+                    })
+                },
+            )
+        };
+    }
+
+    message_field!(UnindexedFieldURI::ItemItemClass, item_class);
+    message_field!(UnindexedFieldURI::ItemSubject, subject);
+    message_field!(UnindexedFieldURI::ItemSensitivity, sensitivity);
+    message_field!(UnindexedFieldURI::ItemBody, body);
+    message_field!(UnindexedFieldURI::ItemCategories, categories);
+    message_field!(UnindexedFieldURI::ItemImportance, importance);
+    message_field!(UnindexedFieldURI::ItemInReplyTo, in_reply_to);
+    message_field!(UnindexedFieldURI::ItemReminderDueBy, reminder_due_by);
+    message_field!(UnindexedFieldURI::ItemReminderIsSet, reminder_is_set);
+    message_field!(
+        UnindexedFieldURI::ItemReminderMinutesBeforeStart,
+        reminder_minutes_before_start
+    );
+    message_field!(UnindexedFieldURI::ItemCulture, culture);
+    message_field!(UnindexedFieldURI::MessageSender, sender);
+    message_field!(UnindexedFieldURI::MessageToRecipients, to_recipients);
+    message_field!(UnindexedFieldURI::MessageCcRecipients, cc_recipients);
+    message_field!(UnindexedFieldURI::MessageBccRecipients, bcc_recipients);
+    message_field!(
+        UnindexedFieldURI::MessageIsReadReceiptRequested,
+        is_read_receipt_requested
+    );
+    message_field!(
+        UnindexedFieldURI::MessageIsDeliveryReceiptRequested,
+        is_delivery_receipt_requested
+    );
+    message_field!(UnindexedFieldURI::MessageFrom, from);
+    message_field!(UnindexedFieldURI::MessageIsRead, is_read);
+    message_field!(
+        UnindexedFieldURI::MessageIsResponseRequested,
+        is_response_requested
+    );
+    message_field!(UnindexedFieldURI::MessageReplyTo, reply_to);
+    message_field!(UnindexedFieldURI::MessageReferences, references);
+    message_field!(UnindexedFieldURI::ItemFlag, flag);
+
+    diff_extended_properties(
+        &mut changes,
+        &original.extended_property,
+        &modified.extended_property,
+        |property| {
+            wrap(Message {
+                extended_property: Some(vec![property]),
+                ..Default::default()
+            })
+        },
+    );
+
+    changes
+}
+
+/// Adds the change needed to turn the value of a single field from `original`
+/// into `modified`, if they differ.
+///
+/// `payload` produces an item with only that field set to the given value.
+fn diff_field<T>(
+    changes: &mut Vec<ItemChangeDescription>,
+    field_uri: UnindexedFieldURI,
+    original: &Option<T>,
+    modified: &Option<T>,
+    payload: &dyn Fn(T) -> RealItem,
+) where
+    T: Clone + PartialEq,
+{
+    if original == modified {
+        return;
+    }
+
+    let field_uri = PathToElement::FieldURI {
+        field_URI: field_uri,
+    };
+
+    changes.push(match modified {
+        Some(value) => ItemChangeDescription::SetItemField {
+            field_uri,
+            item: payload(value.clone()),
+        },
+        None => ItemChangeDescription::DeleteItemField { field_uri },
+    });
+}
+
+/// Adds the changes needed to turn the entries of a dictionary property from
+/// `original` into `modified`, one per changed key.
+///
+/// `key` returns the key of an entry, as used in its field index.
+fn diff_entries<E>(
+    changes: &mut Vec<ItemChangeDescription>,
+    field_uri: DictionaryURI,
+    original: &Option<Dictionary<E>>,
+    modified: &Option<Dictionary<E>>,
+    key: impl Fn(&E) -> &'static str,
+    payload: impl Fn(E) -> RealItem,
+) where
+    E: Clone + PartialEq,
+{
+    let original = original.as_ref().map_or(&[][..], |dict| &dict.entries);
+    let modified = modified.as_ref().map_or(&[][..], |dict| &dict.entries);
+
+    let field_path = |entry: &E| PathToElement::IndexedFieldURI {
+        field_URI: field_uri.clone(),
+        field_index: key(entry).to_string(),
+    };
+
+    for entry in modified {
+        let previous = original.iter().find(|other| key(other) == key(entry));
+        if previous != Some(entry) {
+            changes.push(ItemChangeDescription::SetItemField {
+                field_uri: field_path(entry),
+                item: payload(entry.clone()),
+            });
+        }
+    }
+
+    for entry in original {
+        if !modified.iter().any(|other| key(other) == key(entry)) {
+            changes.push(ItemChangeDescription::DeleteItemField {
+                field_uri: field_path(entry),
+            });
+        }
+    }
+}
+
+/// Adds the changes needed to turn a contact's postal addresses from
+/// `original` into `modified`.
+///
+/// Each part of an address is a separate indexed property, so addresses are
+/// compared part by part.
+fn diff_physical_addresses(
+    changes: &mut Vec<ItemChangeDescription>,
+    original: &Option<Dictionary<PhysicalAddressEntry>>,
+    modified: &Option<Dictionary<PhysicalAddressEntry>>,
+) {
+    type Part = fn(&mut PhysicalAddressEntry) -> &mut Option<String>;
+    const PARTS: [(DictionaryURI, Part); 5] = [
+        (DictionaryURI::ContactsPhysicalAddressStreet, |entry| {
+            &mut entry.street
+        }),
+        (DictionaryURI::ContactsPhysicalAddressCity, |entry| {
+            &mut entry.city
+        }),
+        (DictionaryURI::ContactsPhysicalAddressState, |entry| {
+            &mut entry.state
+        }),
+        (
+            DictionaryURI::ContactsPhysicalAddressCountryOrRegion,
+            |entry| &mut entry.country_or_region,
+        ),
+        (DictionaryURI::ContactsPhysicalAddressPostalCode, |entry| {
+            &mut entry.postal_code
+        }),
+    ];
+
+    let original = original.as_ref().map_or(&[][..], |dict| &dict.entries);
+    let modified = modified.as_ref().map_or(&[][..], |dict| &dict.entries);
+
+    let mut keys: Vec<_> = modified.iter().map(|entry| entry.key).collect();
+    keys.extend(
+        original
+            .iter()
+            .map(|entry| entry.key)
+            .filter(|key| !modified.iter().any(|entry| entry.key == *key)),
+    );
+
+    for key in keys {
+        let find = |entries: &[PhysicalAddressEntry]| {
+            entries
+                .iter()
+                .find(|entry| entry.key == key)
+                .cloned()
+                .unwrap_or_else(|| empty_physical_address(key))
+        };
+        let (mut original, mut modified) = (find(original), find(modified));
+
+        for (field_uri, part) in PARTS {
+            let original = part(&mut original).clone();
+            let modified = part(&mut modified).clone();
+            if original == modified {
+                continue;
+            }
+
+            let field_uri = PathToElement::IndexedFieldURI {
+                field_URI: field_uri,
+                field_index: key.as_str().to_string(),
+            };
+
+            changes.push(match modified {
+                Some(value) => {
+                    let mut entry = empty_physical_address(key);
+                    *part(&mut entry) = Some(value);
+
+                    ItemChangeDescription::SetItemField {
+                        field_uri,
+                        item: RealItem::Contact(Contact {
+                            physical_addresses: Some(Dictionary {
+                                entries: vec![entry],
+                            }),
+                            ..Default::default()
+                        }),
+                    }
+                }
+                None => ItemChangeDescription::DeleteItemField { field_uri },
+            });
+        }
+    }
+}
+
+fn empty_physical_address(key: PhysicalAddressKey) -> PhysicalAddressEntry {
+    PhysicalAddressEntry {
+        key,
+        street: None,
+        city: None,
+        state: None,
+        country_or_region: None,
+        postal_code: None,
+    }
+}
+
+/// Adds the changes needed to turn an item's extended properties from
+/// `original` into `modified`, one per changed property.
+fn diff_extended_properties(
+    changes: &mut Vec<ItemChangeDescription>,
+    original: &Option<Vec<ExtendedProperty>>,
+    modified: &Option<Vec<ExtendedProperty>>,
+    payload: impl Fn(ExtendedProperty) -> RealItem,
+) {
+    let original = original.as_deref().unwrap_or_default();
+    let modified = modified.as_deref().unwrap_or_default();
+
+    for property in modified {
+        let previous = original
+            .iter()
+            .find(|other| other.extended_field_URI == property.extended_field_URI);
+        if previous != Some(property) {
+            changes.push(ItemChangeDescription::SetItemField {
+                field_uri: property.extended_field_URI.clone().into(),
+                item: payload(property.clone()),
+            });
+        }
+    }
+
+    for property in original {
+        if !modified
+            .iter()
+            .any(|other| other.extended_field_URI == property.extended_field_URI)
+        {
+            changes.push(ItemChangeDescription::DeleteItemField {
+                field_uri: property.extended_field_URI.clone().into(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        ArrayOfRecipients, Attachments, Body, BodyType, DateTime, EmailAddressEntry,
+        EmailAddressKey, ExtendedFieldURI, ExtendedPropertyValue, Flag, FolderId, Importance,
+        InternetMessageHeaders, ItemId, Mailbox, MimeContent, PhoneNumberEntry, PhoneNumberKey,
+        PropertyType, Recipient, Sensitivity, StringElement,
+    };
+
+    /// Summarizes changes as their kind and field URI, for comparison.
+    fn summarize(changes: &[ItemChangeDescription]) -> Vec<String> {
+        changes
+            .iter()
+            .map(|change| {
+                let (kind, field_uri) = match change {
+                    ItemChangeDescription::SetItemField { field_uri, .. } => ("set", field_uri),
+                    ItemChangeDescription::AppendToItemField { field_uri, .. } => {
+                        ("append", field_uri)
+                    }
+                    ItemChangeDescription::DeleteItemField { field_uri } => ("delete", field_uri),
+                };
+
+                let field_uri = match field_uri {
+                    PathToElement::FieldURI { field_URI } => field_URI.to_string(),
+                    PathToElement::IndexedFieldURI {
+                        field_URI,
+                        field_index,
+                    } => format!("{field_URI}[{field_index}]"),
+                    PathToElement::ExtendedFieldURI { property_tag, .. } => {
+                        format!("extended:{}", property_tag.as_deref().unwrap_or_default())
+                    }
+                };
+
+                format!("{kind} {field_uri}")
+            })
+            .collect()
+    }
+
+    fn recipient(address: &str) -> Recipient {
+        Recipient {
+            mailbox: Mailbox {
+                email_address: Some(address.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn date_time() -> DateTime {
+        DateTime(time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap())
+    }
+
+    fn extended_property(value: &str) -> ExtendedProperty {
+        ExtendedProperty {
+            extended_field_URI: ExtendedFieldURI {
+                distinguished_property_set_id: None,
+                property_set_id: None,
+                property_tag: Some("0x1090".to_string()),
+                property_name: None,
+                property_id: None,
+                property_type: PropertyType::Integer,
+            },
+            value: ExtendedPropertyValue::Value(value.to_string()),
+        }
+    }
+
+    /// Every field of `Message`, with the field URI expected when it changes
+    /// or `None` if it can't be set.
+    #[allow(clippy::type_complexity)]
+    fn message_fields() -> Vec<(Option<&'static str>, fn(&mut Message))> {
+        vec![
+            (None, |m| {
+                m.mime_content = Some(MimeContent::from_rfc822_bytes(b"Subject: Hi"))
+            }),
+            (None, |m| {
+                m.item_id = Some(ItemId {
+                    id: "AAMkADk=".to_string(),
+                    change_key: None,
+                })
+            }),
+            (None, |m| {
+                m.parent_folder_id = Some(FolderId {
+                    id: "AQMkADk=".to_string(),
+                    change_key: None,
+                })
+            }),
+            (Some("item:ItemClass"), |m| {
+                m.item_class = Some("IPM.Note".to_string())
+            }),
+            (Some("item:Subject"), |m| m.subject = Some("Hi".to_string())),
+            (Some("item:Sensitivity"), |m| {
+                m.sensitivity = Some(Sensitivity::Private)
+            }),
+            (Some("item:Body"), |m| {
+                m.body = Some(Body {
+                    body_type: BodyType::Text,
+                    is_truncated: None,
+                    content: Some("Hello".to_string()),
+                })
+            }),
+            (None, |m| {
+                m.attachments = Some(Attachments { inner: vec![] })
+            }),
+            (None, |m| m.date_time_received = Some(date_time())),
+            (None, |m| m.size = Some(1024)),
+            (Some("item:Categories"), |m| {
+                m.categories = Some(vec![StringElement {
+                    string: "Travel".to_string(),
+                }])
+            }),
+            (Some("extended:0x1090"), |m| {
+                m.extended_property = Some(vec![extended_property("2")])
+            }),
+            (Some("item:Importance"), |m| {
+                m.importance = Some(Importance::High)
+            }),
+            (Some("item:InReplyTo"), |m| {
+                m.in_reply_to = Some("<a@example.com>".to_string())
+            }),
+            (None, |m| m.is_submitted = Some(true)),
+            (None, |m| m.is_draft = Some(true)),
+            (None, |m| m.is_from_me = Some(true)),
+            (None, |m| m.is_resend = Some(true)),
+            (None, |m| m.is_unmodified = Some(true)),
+            (None, |m| {
+                m.internet_message_headers = Some(InternetMessageHeaders {
+                    internet_message_header: vec![],
+                })
+            }),
+            (None, |m| m.date_time_sent = Some(date_time())),
+            (None, |m| m.date_time_created = Some(date_time())),
+            (Some("item:ReminderDueBy"), |m| {
+                m.reminder_due_by = Some(date_time())
+            }),
+            (Some("item:ReminderIsSet"), |m| {
+                m.reminder_is_set = Some(true)
+            }),
+            (Some("item:ReminderMinutesBeforeStart"), |m| {
+                m.reminder_minutes_before_start = Some(15)
+            }),
+            (None, |m| m.display_cc = Some("Bob".to_string())),
+            (None, |m| m.display_to = Some("Alice".to_string())),
+            (None, |m| m.has_attachments = Some(true)),
+            (Some("item:Culture"), |m| {
+                m.culture = Some("en-US".to_string())
+            }),
+            (Some("message:Sender"), |m| {
+                m.sender = Some(recipient("alice@example.com"))
+            }),
+            (Some("message:ToRecipients"), |m| {
+                m.to_recipients = Some(ArrayOfRecipients(vec![recipient("bob@example.com")]))
+            }),
+            (Some("message:CcRecipients"), |m| {
+                m.cc_recipients = Some(ArrayOfRecipients(vec![recipient("bob@example.com")]))
+            }),
+            (Some("message:BccRecipients"), |m| {
+                m.bcc_recipients = Some(ArrayOfRecipients(vec![recipient("bob@example.com")]))
+            }),
+            (Some("message:IsReadReceiptRequested"), |m| {
+                m.is_read_receipt_requested = Some(true)
+            }),
+            (Some("message:IsDeliveryReceiptRequested"), |m| {
+                m.is_delivery_receipt_requested = Some(true)
+            }),
+            (None, |m| m.conversation_index = Some("AQHa".to_string())),
+            (None, |m| m.conversation_topic = Some("Hi".to_string())),
+            (Some("message:From"), |m| {
+                m.from = Some(recipient("alice@example.com"))
+            }),
+            (None, |m| {
+                m.internet_message_id = Some("<b@example.com>".to_string())
+            }),
+            (Some("message:IsRead"), |m| m.is_read = Some(true)),
+            (Some("message:IsResponseRequested"), |m| {
+                m.is_response_requested = Some(true)
+            }),
+            (Some("message:ReplyTo"), |m| {
+                m.reply_to = Some(ArrayOfRecipients(vec![recipient("alice@example.com")]))
+            }),
+            (None, |m| m.received_by = Some(recipient("bob@example.com"))),
+            (None, |m| {
+                m.received_representing = Some(recipient("bob@example.com"))
+            }),
+            (None, |m| m.last_modified_name = Some("Alice".to_string())),
+            (None, |m| m.last_modified_time = Some(date_time())),
+            (None, |m| m.is_associated = Some(true)),
+            (None, |m| {
+                m.conversation_id = Some(ItemId {
+                    id: "AAQkADk=".to_string(),
+                    change_key: None,
+                })
+            }),
+            (Some("message:References"), |m| {
+                m.references = Some("<a@example.com>".to_string())
+            }),
+            (Some("item:Flag"), |m| m.flag = Some(Flag::flagged())),
+            (None, |m| m.preview = Some("Hello".to_string())),
+        ]
+    }
+
+    #[test]
+    fn message_fields_cover_message() {
+        let mut message = Message::default();
+        for (_, set) in message_fields() {
+            set(&mut message);
+        }
+
+        // Destructuring without `..` stops compiling when a field is added to
+        // `Message`, so that it gets added to `message_fields` too.
+        macro_rules! assert_all_set {
+            ($($field:ident),* $(,)?) => {
+                let Message { $($field),* } = message;
+                $(assert!(
+                    $field.is_some(),
+                    concat!("`", stringify!($field), "` is missing from `message_fields`")
+                );)*
+            };
+        }
+
+        assert_all_set!(
+            mime_content,
+            item_id,
+            parent_folder_id,
+            item_class,
+            subject,
+            sensitivity,
+            body,
+            attachments,
+            date_time_received,
+            size,
+            categories,
+            extended_property,
+            importance,
+            in_reply_to,
+            is_submitted,
+            is_draft,
+            is_from_me,
+            is_resend,
+            is_unmodified,
+            internet_message_headers,
+            date_time_sent,
+            date_time_created,
+            reminder_due_by,
+            reminder_is_set,
+            reminder_minutes_before_start,
+            display_cc,
+            display_to,
+            has_attachments,
+            culture,
+            sender,
+            to_recipients,
+            cc_recipients,
+            bcc_recipients,
+            is_read_receipt_requested,
+            is_delivery_receipt_requested,
+            conversation_index,
+            conversation_topic,
+            from,
+            internet_message_id,
+            is_read,
+            is_response_requested,
+            reply_to,
+            received_by,
+            received_representing,
+            last_modified_name,
+            last_modified_time,
+            is_associated,
+            conversation_id,
+            references,
+            flag,
+            preview,
+        );
+    }
+
+    #[test]
+    fn diff_each_message_field() {
+        for (field_uri, set) in message_fields() {
+            let original = Message::default();
+            let mut modified = Message::default();
+            set(&mut modified);
+
+            let expected: Vec<String> = field_uri
+                .map(|field_uri| format!("set {field_uri}"))
+                .into_iter()
+                .collect();
+            assert_eq!(summarize(&diff_messages(&original, &modified)), expected);
+
+            let expected: Vec<String> = field_uri
+                .map(|field_uri| format!("delete {field_uri}"))
+                .into_iter()
+                .collect();
+            assert_eq!(summarize(&diff_messages(&modified, &original)), expected);
+
+            assert!(diff_messages(&modified, &modified).is_empty());
+        }
+    }
+
+    #[test]
+    fn diff_calendar_item_with_several_changes() {
+        let original = CalendarItem {
+            subject: Some("Hi".to_string()),
+            location: Some("Room 4".to_string()),
+            is_all_day_event: Some(false),
+            extended_property: Some(vec![extended_property("1")]),
+            ..Default::default()
+        };
+        let modified = CalendarItem {
+            subject: Some("Hi".to_string()),
+            location: Some("Room 5".to_string()),
+            extended_property: Some(vec![extended_property("2")]),
+            ..Default::default()
+        };
+
+        let changes = diff_items(
+            &RealItem::CalendarItem(original),
+            &RealItem::CalendarItem(modified),
+        )
+        .unwrap();
+        assert_eq!(
+            summarize(&changes),
+            [
+                "delete calendar:IsAllDayEvent",
+                "set calendar:Location",
+                "set extended:0x1090"
+            ]
+        );
+
+        let ItemChangeDescription::SetItemField { item, .. } = &changes[1] else {
+            panic!("change should set a field");
+        };
+        assert_eq!(
+            item,
+            &RealItem::CalendarItem(CalendarItem {
+                location: Some("Room 5".to_string()),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn diff_contact_entries() {
+        let original = Contact {
+            given_name: Some("Alice".to_string()),
+            email_addresses: Some(Dictionary {
+                entries: vec![EmailAddressEntry {
+                    key: EmailAddressKey::EmailAddress1,
+                    name: None,
+                    routing_type: None,
+                    mailbox_type: None,
+                    address: "alice@example.com".to_string(),
+                }],
+            }),
+            phone_numbers: Some(Dictionary {
+                entries: vec![PhoneNumberEntry {
+                    key: PhoneNumberKey::HomePhone,
+                    number: "555-0100".to_string(),
+                }],
+            }),
+            physical_addresses: Some(Dictionary {
+                entries: vec![PhysicalAddressEntry {
+                    city: Some("Paris".to_string()),
+                    ..empty_physical_address(PhysicalAddressKey::Home)
+                }],
+            }),
+            ..Default::default()
+        };
+        let modified = Contact {
+            given_name: None,
+            surname: Some("Smith".to_string()),
+            email_addresses: Some(Dictionary {
+                entries: vec![EmailAddressEntry {
+                    key: EmailAddressKey::EmailAddress1,
+                    name: None,
+                    routing_type: None,
+                    mailbox_type: None,
+                    address: "alice@example.org".to_string(),
+                }],
+            }),
+            phone_numbers: None,
+            physical_addresses: Some(Dictionary {
+                entries: vec![PhysicalAddressEntry {
+                    street: Some("1 Rue de Rivoli".to_string()),
+                    city: Some("Paris".to_string()),
+                    ..empty_physical_address(PhysicalAddressKey::Home)
+                }],
+            }),
+            ..original.clone()
+        };
+
+        assert_eq!(
+            summarize(&diff_contacts(&original, &modified)),
+            [
+                "delete contacts:GivenName",
+                "set contacts:Surname",
+                "set contacts:EmailAddress[EmailAddress1]",
+                "delete contacts:PhoneNumber[HomePhone]",
+                "set contacts:PhysicalAddress:Street[Home]",
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_items_of_different_kinds() {
+        let result = diff_items(
+            &RealItem::Message(Message::default()),
+            &RealItem::Contact(Contact::default()),
+        );
+
+        assert!(matches!(result, Err(Error::MismatchedItemKinds)));
+    }
+}
@@ -23,8 +23,8 @@ mod test {
     use crate::{
         move_folder::{MoveFolder, MoveFolderResponse},
         test_utils::{assert_deserialized_content, assert_serialized_content},
-        BaseFolderId, CopyMoveFolderData, Folder, FolderId, FolderResponseMessage, Folders,
-        ResponseClass, ResponseMessages,
+        BaseFolderId, CopyMoveFolderData, DistinguishedFolderName, Folder, FolderId,
+        FolderResponseMessage, Folders, ResponseClass, ResponseMessages,
     };
 
     #[test]
@@ -32,8 +32,9 @@ mod test {
         let move_folder = MoveFolder {
             inner: CopyMoveFolderData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "junkemail".to_string(),
+                    id: DistinguishedFolderName::JunkEmail,
                     change_key: None,
+                    mailbox: None,
                 },
                 folder_ids: vec![BaseFolderId::FolderId {
                     id: "AScAc".to_string(),
@@ -79,6 +80,7 @@ mod test {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
@@ -25,8 +25,8 @@ mod test {
     use crate::{
         test_utils::{assert_deserialized_content, assert_serialized_content},
         types::common::ItemResponseMessage,
-        BaseFolderId, BaseItemId, CopyMoveItemData, ItemId, Items, Message, RealItem,
-        ResponseClass, ResponseMessages,
+        BaseFolderId, BaseItemId, CopyMoveItemData, DistinguishedFolderName, ItemId, Items,
+        Message, RealItem, ResponseClass, ResponseMessages,
     };
 
     use super::{MoveItem, MoveItemResponse};
@@ -36,8 +36,9 @@ mod test {
         let move_item = MoveItem {
             inner: CopyMoveItemData {
                 to_folder_id: BaseFolderId::DistinguishedFolderId {
-                    id: "drafts".to_string(),
+                    id: DistinguishedFolderName::Drafts,
                     change_key: None,
+                    mailbox: None,
                 },
                 item_ids: vec![BaseItemId::ItemId {
                     id: "AAAtAEF/swbAAA=".to_string(),
@@ -43,12 +43,37 @@ pub trait OperationResponse:
     /// The type of the messages in the response.
     type Message;
 
+    /// The type of error response messages, usually [`ResponseError`].
+    ///
+    /// [`ResponseError`]: crate::response::ResponseError
+    type Error;
+
     /// In practice, every operation response contains a single `response_messages` field. This
     /// returns that field.
-    fn response_messages(&self) -> &[crate::ResponseClass<Self::Message>];
+    fn response_messages(&self) -> &[crate::ResponseClass<Self::Message, Self::Error>];
 
     /// Convert the response into its internal type.
-    fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message>>;
+    fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message, Self::Error>>;
+}
+
+/// Returns the only message in a response to an operation on a single item or
+/// folder, or the error it contains.
+pub(crate) fn single_response_message<R>(response: R) -> Result<R::Message, crate::Error>
+where
+    R: OperationResponse<Error = crate::response::ResponseError>,
+{
+    let mut messages = response.into_response_messages();
+    if messages.len() != 1 {
+        return Err(crate::Error::UnexpectedResponseMessageCount {
+            expected: 1,
+            actual: messages.len(),
+        });
+    }
+
+    messages
+        .remove(0)
+        .into_result()
+        .map_err(|err| crate::Error::ResponseError(Box::new(err)))
 }
 
 pub(super) mod sealed {
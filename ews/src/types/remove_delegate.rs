@@ -0,0 +1,71 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{DelegateResponseMessage, Mailbox, UserIds, MESSAGES_NS_URI};
+
+/// A request to remove delegates from a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/removedelegate-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(DelegateResponseMessage, single_message)]
+pub struct RemoveDelegate {
+    /// The mailbox to remove the delegates from. Only the address is
+    /// required.
+    pub mailbox: Mailbox,
+
+    pub user_ids: UserIds,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DelegateUserResponseMessage, ResponseClass, ResponseMessages, UserId,
+    };
+
+    #[test]
+    fn serialize_remove_delegate() {
+        let remove_delegate = RemoveDelegate {
+            mailbox: Mailbox {
+                email_address: Some("boss@example.com".to_string()),
+                ..Default::default()
+            },
+            user_ids: UserIds(vec![UserId::from_smtp_address("assistant@example.com")]),
+        };
+
+        let expected = r#"<RemoveDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></Mailbox><UserIds><t:UserId><t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress></t:UserId></UserIds></RemoveDelegate>"#;
+
+        assert_serialized_content(&remove_delegate, "RemoveDelegate", expected);
+    }
+
+    #[test]
+    fn deserialize_remove_delegate_response() {
+        let content = r#"<m:RemoveDelegateResponse ResponseClass="Success"
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:ResponseCode>NoError</m:ResponseCode>
+                <m:ResponseMessages>
+                    <m:DelegateUserResponseMessageType ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                    </m:DelegateUserResponseMessageType>
+                </m:ResponseMessages>
+            </m:RemoveDelegateResponse>"#;
+
+        let expected = RemoveDelegateResponse {
+            response_message: ResponseClass::Success(DelegateResponseMessage {
+                response_messages: ResponseMessages {
+                    response_messages: vec![ResponseClass::Success(DelegateUserResponseMessage {
+                        delegate_user: None,
+                    })],
+                },
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -0,0 +1,268 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{response::ResponseCode, BaseFolderId, BaseShape, Contact, Mailbox, MESSAGES_NS_URI};
+
+/// A request to resolve an ambiguous name or address against the directory
+/// and/or the contacts in a mailbox.
+///
+/// If the name matches more than one entry, the response message will have the
+/// [`Warning`] class and contain every match; see
+/// [`ResolveNamesResponseMessage::is_ambiguous`].
+///
+/// [`Warning`]: crate::ResponseClass::Warning
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenames>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(ResolveNamesResponseMessage)]
+pub struct ResolveNames {
+    /// Whether to include the full contact data of each match in the response.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenames#returnfullcontactdata-attribute>
+    #[xml_struct(attribute)]
+    pub return_full_contact_data: bool,
+
+    /// The sources to search, and the order in which to search them.
+    ///
+    /// If omitted, the server will default to
+    /// [`ActiveDirectoryContacts`].
+    ///
+    /// [`ActiveDirectoryContacts`]: `SearchScope::ActiveDirectoryContacts`
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenames#searchscope-attribute>
+    #[xml_struct(attribute)]
+    pub search_scope: Option<SearchScope>,
+
+    /// The set of contact properties to include in the response when
+    /// `return_full_contact_data` is set.
+    ///
+    /// This attribute was introduced in Exchange 2010 SP2.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenames#contactdatashape-attribute>
+    #[xml_struct(attribute)]
+    pub contact_data_shape: Option<BaseShape>,
+
+    /// The contacts folders to search.
+    ///
+    /// If omitted, the default contacts folder of the mailbox is searched.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/parentfolderids>
+    pub parent_folder_ids: Option<Vec<BaseFolderId>>,
+
+    /// The name or address to resolve.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unresolvedentry>
+    pub unresolved_entry: String,
+}
+
+/// The sources searched by a [`ResolveNames`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenames#searchscope-attribute>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum SearchScope {
+    /// Only the directory is searched.
+    ActiveDirectory,
+
+    /// The directory is searched first, then the contacts folders.
+    ActiveDirectoryContacts,
+
+    /// Only the contacts folders are searched.
+    Contacts,
+
+    /// The contacts folders are searched first, then the directory.
+    ContactsActiveDirectory,
+}
+
+/// A response to a request for the resolution of a single name.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolvenamesresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResolveNamesResponseMessage {
+    /// The status code of this response message.
+    ///
+    /// This is used to distinguish an unambiguous match from multiple
+    /// matches, which Exchange reports with the [`Warning`] response class.
+    ///
+    /// [`Warning`]: crate::ResponseClass::Warning
+    #[serde(default)]
+    pub response_code: ResponseCode,
+
+    /// The entries matching the requested name.
+    pub resolution_set: ResolutionSet,
+}
+
+impl ResolveNamesResponseMessage {
+    /// Whether the requested name matched more than one entry.
+    ///
+    /// When this is `true`, the caller is expected to pick one of the entries
+    /// in [`resolution_set`](Self::resolution_set), e.g. by prompting the user.
+    pub fn is_ambiguous(&self) -> bool {
+        self.response_code == ResponseCode::ErrorNameResolutionMultipleResults
+    }
+}
+
+/// A collection of entries matching a name.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolutionset>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct ResolutionSet {
+    /// The total number of matching entries.
+    #[serde(rename = "@TotalItemsInView")]
+    pub total_items_in_view: Option<usize>,
+
+    /// Whether the response includes the last matching entry.
+    #[serde(rename = "@IncludesLastItemInRange")]
+    pub includes_last_item_in_range: Option<bool>,
+
+    /// The matching entries.
+    #[serde(rename = "Resolution", default)]
+    pub resolutions: Vec<Resolution>,
+}
+
+/// A single entry matching a name.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/resolution>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct Resolution {
+    /// The mailbox of the matching entry.
+    pub mailbox: Mailbox,
+
+    /// The contact data of the matching entry.
+    ///
+    /// This is only included if full contact data was requested.
+    pub contact: Option<Contact>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ContactSource, Dictionary, ItemId, MailboxType, PhoneNumberEntry, PhoneNumberKey,
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_resolve_names() {
+        let resolve_names = ResolveNames {
+            return_full_contact_data: true,
+            search_scope: Some(SearchScope::ActiveDirectoryContacts),
+            contact_data_shape: Some(BaseShape::IdOnly),
+            parent_folder_ids: None,
+            unresolved_entry: "ali".to_string(),
+        };
+
+        let expected = r#"<ResolveNames xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" ReturnFullContactData="true" SearchScope="ActiveDirectoryContacts" ContactDataShape="IdOnly"><UnresolvedEntry>ali</UnresolvedEntry></ResolveNames>"#;
+
+        assert_serialized_content(&resolve_names, "ResolveNames", expected);
+    }
+
+    #[test]
+    fn deserialize_ambiguous_resolve_names_response() {
+        let content = r#"<m:ResolveNamesResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
+                xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:ResolveNamesResponseMessage ResponseClass="Warning">
+                        <m:MessageText>Multiple results were found.</m:MessageText>
+                        <m:ResponseCode>ErrorNameResolutionMultipleResults</m:ResponseCode>
+                        <m:DescriptiveLinkKey>0</m:DescriptiveLinkKey>
+                        <m:ResolutionSet TotalItemsInView="2" IncludesLastItemInRange="true">
+                            <t:Resolution>
+                                <t:Mailbox>
+                                    <t:Name>Alice Test</t:Name>
+                                    <t:EmailAddress>alice@example.com</t:EmailAddress>
+                                    <t:RoutingType>SMTP</t:RoutingType>
+                                    <t:MailboxType>Mailbox</t:MailboxType>
+                                </t:Mailbox>
+                                <t:Contact>
+                                    <t:DisplayName>Alice Test</t:DisplayName>
+                                    <t:GivenName>Alice</t:GivenName>
+                                    <t:PhoneNumbers>
+                                        <t:Entry Key="BusinessPhone">555-0100</t:Entry>
+                                    </t:PhoneNumbers>
+                                    <t:ContactSource>ActiveDirectory</t:ContactSource>
+                                    <t:Surname>Test</t:Surname>
+                                </t:Contact>
+                            </t:Resolution>
+                            <t:Resolution>
+                                <t:Mailbox>
+                                    <t:Name>Alison Example</t:Name>
+                                    <t:EmailAddress>alison@example.com</t:EmailAddress>
+                                    <t:RoutingType>SMTP</t:RoutingType>
+                                    <t:MailboxType>Contact</t:MailboxType>
+                                    <t:ItemId Id="AAMkADk=" ChangeKey="EQAAABYA" />
+                                </t:Mailbox>
+                            </t:Resolution>
+                        </m:ResolutionSet>
+                    </m:ResolveNamesResponseMessage>
+                </m:ResponseMessages>
+            </m:ResolveNamesResponse>"#;
+
+        let expected = ResolveNamesResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Warning(ResolveNamesResponseMessage {
+                    response_code: ResponseCode::ErrorNameResolutionMultipleResults,
+                    resolution_set: ResolutionSet {
+                        total_items_in_view: Some(2),
+                        includes_last_item_in_range: Some(true),
+                        resolutions: vec![
+                            Resolution {
+                                mailbox: Mailbox {
+                                    name: Some("Alice Test".to_string()),
+                                    email_address: Some("alice@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    mailbox_type: Some(MailboxType::Mailbox),
+                                    item_id: None,
+                                },
+                                contact: Some(Contact {
+                                    display_name: Some("Alice Test".to_string()),
+                                    given_name: Some("Alice".to_string()),
+                                    phone_numbers: Some(Dictionary {
+                                        entries: vec![PhoneNumberEntry {
+                                            key: PhoneNumberKey::BusinessPhone,
+                                            number: "555-0100".to_string(),
+                                        }],
+                                    }),
+                                    contact_source: Some(ContactSource::ActiveDirectory),
+                                    surname: Some("Test".to_string()),
+                                    ..Default::default()
+                                }),
+                            },
+                            Resolution {
+                                mailbox: Mailbox {
+                                    name: Some("Alison Example".to_string()),
+                                    email_address: Some("alison@example.com".to_string()),
+                                    routing_type: Some("SMTP".to_string()),
+                                    mailbox_type: Some(MailboxType::Contact),
+                                    item_id: Some(ItemId {
+                                        id: "AAMkADk=".to_string(),
+                                        change_key: Some("EQAAABYA".to_string()),
+                                    }),
+                                },
+                                contact: None,
+                            },
+                        ],
+                    },
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+
+        let response: ResolveNamesResponse = quick_xml::de::from_str(content).unwrap();
+        let ResponseClass::Warning(message) = &response.response_messages.response_messages[0]
+        else {
+            panic!("response should be a warning");
+        };
+        assert!(message.is_ambiguous());
+    }
+}
@@ -0,0 +1,237 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    create_attachment::{CreateAttachment, CreateAttachmentResponse},
+    create_item::{CreateItem, CreateItemResponse},
+    types::operations::single_response_message,
+    Attachment, BaseFolderId, BaseItemId, DistinguishedFolderName, Error, ItemId, Message,
+    MessageDisposition, RealItem, MESSAGES_NS_URI,
+};
+
+/// A request to send one or more existing items, such as drafts.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/senditem>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(SendItemResponseMessage)]
+pub struct SendItem {
+    /// Whether to save a copy of the items once they have been sent.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/senditem#saveitemtofolder-attribute>
+    #[xml_struct(attribute)]
+    pub save_item_to_folder: bool,
+
+    /// The items to send.
+    ///
+    /// Change keys should be included, and must be current.
+    pub item_ids: Vec<BaseItemId>,
+
+    /// The folder in which to save copies of the sent items.
+    ///
+    /// This is ignored unless `save_item_to_folder` is `true`, in which case
+    /// copies are saved in the Sent Items folder if it is omitted.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/saveditemfolderid>
+    pub saved_item_folder_id: Option<BaseFolderId>,
+}
+
+/// A response to a request to send a single item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/senditemresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct SendItemResponseMessage {}
+
+// Sending a message with attachments which are too large to be included in a
+// single `CreateItem` request takes three operations, which the functions
+// below build and interpret in turn:
+//
+// 1. `create_draft` saves the message in the Drafts folder, and
+//    `draft_id` reads its ID from the response.
+// 2. `add_attachments` adds the attachments to the draft, and
+//    `draft_id_after_attachments` reads the change key resulting from the
+//    additions from the response.
+// 3. `send_draft` sends the draft with that change key.
+//
+// This crate doesn't perform requests itself, so callers send each operation
+// and deserialize its response in between.
+
+/// Creates a request to save a message as a draft without sending it.
+pub fn create_draft(message: Message) -> CreateItem {
+    CreateItem {
+        message_disposition: Some(MessageDisposition::SaveOnly),
+        saved_item_folder_id: None,
+        items: vec![RealItem::Message(message).into()],
+    }
+}
+
+/// Returns the ID of the draft saved by a [`create_draft`] request.
+pub fn draft_id(response: CreateItemResponse) -> Result<ItemId, Error> {
+    single_response_message(response)?
+        .items
+        .inner
+        .into_iter()
+        .next()
+        .and_then(RealItem::into_inner_message)
+        .and_then(|message| message.item_id)
+        .ok_or(Error::MissingResponseContent("item ID"))
+}
+
+/// Creates a request to add attachments to a draft.
+pub fn add_attachments(draft: &ItemId, attachments: Vec<Attachment>) -> CreateAttachment {
+    CreateAttachment {
+        parent_item_id: BaseItemId::ItemId {
+            id: draft.id.clone(),
+            change_key: draft.change_key.clone(),
+        },
+        attachments,
+    }
+}
+
+/// Returns the ID of a draft with the change key resulting from an
+/// [`add_attachments`] request.
+pub fn draft_id_after_attachments(
+    draft: ItemId,
+    response: CreateAttachmentResponse,
+) -> Result<ItemId, Error> {
+    // Each attachment is reported in its own response message, and each
+    // addition changes the draft, so only the last change key is current.
+    let mut change_key = draft.change_key;
+    for message in response.response_messages.response_messages {
+        let message = message
+            .into_result()
+            .map_err(|err| Error::ResponseError(Box::new(err)))?;
+
+        for attachment in message.attachments.inner {
+            let attachment_id = match attachment {
+                Attachment::FileAttachment { attachment_id, .. }
+                | Attachment::ItemAttachment { attachment_id, .. } => attachment_id,
+            };
+
+            if let Some(root_item_change_key) =
+                attachment_id.and_then(|attachment_id| attachment_id.root_item_change_key)
+            {
+                change_key = Some(root_item_change_key);
+            }
+        }
+    }
+
+    Ok(ItemId {
+        id: draft.id,
+        change_key,
+    })
+}
+
+/// Creates a request to send a draft, saving a copy in the folder given by
+/// `saved_item_folder_id`, or in the Sent Items folder if it is `None`.
+pub fn send_draft(draft: ItemId, saved_item_folder_id: Option<BaseFolderId>) -> SendItem {
+    SendItem {
+        save_item_to_folder: true,
+        item_ids: vec![BaseItemId::ItemId {
+            id: draft.id,
+            change_key: draft.change_key,
+        }],
+        saved_item_folder_id: Some(
+            saved_item_folder_id
+                .unwrap_or_else(|| BaseFolderId::distinguished(DistinguishedFolderName::SentItems)),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_send_item() {
+        let send_item = SendItem {
+            save_item_to_folder: true,
+            item_ids: vec![BaseItemId::ItemId {
+                id: "AAMkADk=".to_string(),
+                change_key: Some("CQAAABYA".to_string()),
+            }],
+            saved_item_folder_id: Some(BaseFolderId::DistinguishedFolderId {
+                id: DistinguishedFolderName::SentItems,
+                change_key: None,
+                mailbox: None,
+            }),
+        };
+
+        let expected = r#"<SendItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" SaveItemToFolder="true"><ItemIds><t:ItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/></ItemIds><SavedItemFolderId><t:DistinguishedFolderId Id="sentitems"/></SavedItemFolderId></SendItem>"#;
+
+        assert_serialized_content(&send_item, "SendItem", expected);
+    }
+
+    #[test]
+    fn deserialize_send_item_response() {
+        let content = r#"<m:SendItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:ResponseMessages>
+                    <m:SendItemResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                    </m:SendItemResponseMessage>
+                </m:ResponseMessages>
+            </m:SendItemResponse>"#;
+
+        let expected = SendItemResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(SendItemResponseMessage {})],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn draft_workflow_uses_latest_change_key() {
+        let create_item = create_draft(Message {
+            subject: Some("Hello".to_string()),
+            ..Default::default()
+        });
+        assert!(matches!(
+            create_item.message_disposition,
+            Some(MessageDisposition::SaveOnly)
+        ));
+
+        let response = r#"<m:CreateItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:CreateItemResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Items><t:Message><t:ItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/></t:Message></m:Items></m:CreateItemResponseMessage></m:ResponseMessages></m:CreateItemResponse>"#;
+        let draft = draft_id(quick_xml::de::from_str(response).unwrap()).unwrap();
+
+        let create_attachment = add_attachments(
+            &draft,
+            vec![
+                Attachment::new_file_attachment("a.txt", "text/plain", b"hello"),
+                Attachment::new_file_attachment("b.txt", "text/plain", b"world"),
+            ],
+        );
+        assert!(matches!(
+            &create_attachment.parent_item_id,
+            BaseItemId::ItemId { id, change_key }
+                if id == "AAMkADk=" && change_key.as_deref() == Some("CQAAABYA")
+        ));
+
+        let response = r#"<m:CreateAttachmentResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:CreateAttachmentResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Attachments><t:FileAttachment><t:AttachmentId Id="AAMkADl=" RootItemId="AAMkADk=" RootItemChangeKey="CQAAABYB"/></t:FileAttachment></m:Attachments></m:CreateAttachmentResponseMessage><m:CreateAttachmentResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Attachments><t:FileAttachment><t:AttachmentId Id="AAMkADm=" RootItemId="AAMkADk=" RootItemChangeKey="CQAAABYC"/></t:FileAttachment></m:Attachments></m:CreateAttachmentResponseMessage></m:ResponseMessages></m:CreateAttachmentResponse>"#;
+        let draft =
+            draft_id_after_attachments(draft, quick_xml::de::from_str(response).unwrap()).unwrap();
+
+        let expected = r#"<SendItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" SaveItemToFolder="true"><ItemIds><t:ItemId Id="AAMkADk=" ChangeKey="CQAAABYC"/></ItemIds><SavedItemFolderId><t:DistinguishedFolderId Id="sentitems"/></SavedItemFolderId></SendItem>"#;
+
+        assert_serialized_content(&send_draft(draft, None), "SendItem", expected);
+    }
+
+    #[test]
+    fn draft_id_surfaces_error_response() {
+        let response = r#"<m:CreateItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"><m:ResponseMessages><m:CreateItemResponseMessage ResponseClass="Error"><m:MessageText>The specified folder could not be found in the store.</m:MessageText><m:ResponseCode>ErrorFolderNotFound</m:ResponseCode><m:Items/></m:CreateItemResponseMessage></m:ResponseMessages></m:CreateItemResponse>"#;
+
+        let err = draft_id(quick_xml::de::from_str(response).unwrap()).unwrap_err();
+
+        assert!(matches!(err, Error::ResponseError(_)), "got {err:?}");
+    }
+}
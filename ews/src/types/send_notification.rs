@@ -0,0 +1,181 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! The messages exchanged when Exchange pushes notifications to a client.
+//!
+//! Unlike other operations, these flow in the opposite direction: Exchange
+//! sends a [`SendNotification`] request to the URL given when creating a push
+//! subscription, and the client replies with a [`SendNotificationResult`].
+//! See [`crate::push`] for a handler implementing the client side.
+
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    response::ResponseError, types::sealed::EnvelopeBodyContents, Notification, OperationResponse,
+    ResponseClass, ResponseMessages, MESSAGES_NS_URI,
+};
+
+/// A request from Exchange delivering the events of a push subscription.
+///
+/// This is deserialized like an operation response, with
+/// [`Envelope::from_xml_document`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sendnotification>
+///
+/// [`Envelope::from_xml_document`]: crate::soap::Envelope::from_xml_document
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SendNotification {
+    pub response_messages: ResponseMessages<SendNotificationResponseMessage>,
+}
+
+impl OperationResponse for SendNotification {
+    type Message = SendNotificationResponseMessage;
+    type Error = ResponseError;
+
+    fn response_messages(&self) -> &[ResponseClass<Self::Message>] {
+        self.response_messages.response_messages.as_slice()
+    }
+
+    fn into_response_messages(self) -> Vec<ResponseClass<Self::Message>> {
+        self.response_messages.response_messages
+    }
+}
+
+impl EnvelopeBodyContents for SendNotification {
+    const NAME: &'static str = "SendNotification";
+}
+
+/// The events of a push subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sendnotificationresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SendNotificationResponseMessage {
+    pub notification: Notification,
+}
+
+/// A client's reply to a [`SendNotification`] request.
+///
+/// This is serialized like an operation, with [`Envelope::as_xml_document`].
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/sendnotificationresult>
+///
+/// [`Envelope::as_xml_document`]: crate::soap::Envelope::as_xml_document
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+pub struct SendNotificationResult {
+    pub subscription_status: SubscriptionStatus,
+}
+
+impl EnvelopeBodyContents for SendNotificationResult {
+    const NAME: &'static str = "SendNotificationResult";
+}
+
+/// Whether a push subscription should be kept.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/subscriptionstatus>
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubscriptionStatus {
+    /// Keep sending notifications.
+    Ok,
+
+    /// End the subscription.
+    Unsubscribe,
+}
+
+impl SubscriptionStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Unsubscribe => "Unsubscribe",
+        }
+    }
+}
+
+impl XmlSerialize for SubscriptionStatus {
+    fn serialize_child_nodes<W>(
+        &self,
+        writer: &mut quick_xml::Writer<W>,
+    ) -> Result<(), xml_struct::Error>
+    where
+        W: std::io::Write,
+    {
+        self.as_str().serialize_child_nodes(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{soap::Envelope, NotificationEvent, StatusEvent};
+
+    #[test]
+    fn deserialize_send_notification() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xsd="http://www.w3.org/2001/XMLSchema">
+  <soap:Header>
+    <t:RequestServerVersion xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types" Version="Exchange2010_SP2" />
+  </soap:Header>
+  <soap:Body>
+    <m:SendNotification xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+      <m:ResponseMessages>
+        <m:SendNotificationResponseMessage ResponseClass="Success">
+          <m:ResponseCode>NoError</m:ResponseCode>
+          <m:Notification>
+            <t:SubscriptionId xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">FQBkYjVwcjA2bWIxNDY0</t:SubscriptionId>
+            <t:PreviousWatermark xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">AQAAAMAGAAAAAAAAAQ==</t:PreviousWatermark>
+            <t:MoreEvents xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">false</t:MoreEvents>
+            <t:StatusEvent xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+              <t:Watermark>AQAAAM4GAAAAAAAAAQ==</t:Watermark>
+            </t:StatusEvent>
+          </m:Notification>
+        </m:SendNotificationResponseMessage>
+      </m:ResponseMessages>
+    </m:SendNotification>
+  </soap:Body>
+</soap:Envelope>"#;
+
+        let envelope: Envelope<SendNotification> =
+            Envelope::from_xml_document(xml.as_bytes()).expect("deserialization should succeed");
+
+        assert_eq!(
+            envelope.body.into_response_messages(),
+            [ResponseClass::Success(SendNotificationResponseMessage {
+                notification: Notification {
+                    subscription_id: "FQBkYjVwcjA2bWIxNDY0".to_string(),
+                    previous_watermark: Some("AQAAAMAGAAAAAAAAAQ==".to_string()),
+                    more_events: Some(false),
+                    events: vec![NotificationEvent::StatusEvent(StatusEvent {
+                        watermark: Some("AQAAAM4GAAAAAAAAAQ==".to_string()),
+                    })],
+                },
+            })]
+        );
+    }
+
+    #[test]
+    fn serialize_send_notification_result() {
+        for (status, expected) in [
+            (SubscriptionStatus::Ok, "OK"),
+            (SubscriptionStatus::Unsubscribe, "Unsubscribe"),
+        ] {
+            let envelope = Envelope {
+                headers: vec![],
+                body: SendNotificationResult {
+                    subscription_status: status,
+                },
+            };
+
+            let document = String::from_utf8(envelope.as_xml_document().unwrap()).unwrap();
+            assert_eq!(
+                document,
+                format!(
+                    r#"<?xml version="1.0" encoding="utf-8"?><soap:Envelope xmlns:soap="http://schemas.xmlsoap.org/soap/envelope/" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><soap:Header></soap:Header><soap:Body><SendNotificationResult xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><SubscriptionStatus>{expected}</SubscriptionStatus></SendNotificationResult></soap:Body></soap:Envelope>"#
+                )
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{EmailAddress, UserOofSettings, MESSAGES_NS_URI};
+
+/// A request to change the out-of-office settings of a mailbox.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setuseroofsettings-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    SetUserOofSettingsResponseMessage,
+    single_message = "ResponseMessage",
+    name = "SetUserOofSettingsRequest"
+)]
+pub struct SetUserOofSettings {
+    /// The mailbox to change the settings of. Only the address is required.
+    #[xml_struct(ns_prefix = "t")]
+    pub mailbox: EmailAddress,
+
+    #[xml_struct(ns_prefix = "t")]
+    pub user_oof_settings: UserOofSettings,
+}
+
+/// The result of a [`SetUserOofSettings`] request, which carries no data
+/// beyond its response class.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct SetUserOofSettingsResponseMessage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DateTime, Duration, ExternalAudience, OofState, ReplyBody, ResponseClass,
+    };
+
+    #[test]
+    fn serialize_set_user_oof_settings() {
+        let set_user_oof_settings = SetUserOofSettings {
+            mailbox: EmailAddress {
+                address: "user1@example.com".to_string(),
+                ..Default::default()
+            },
+            user_oof_settings: UserOofSettings {
+                oof_state: OofState::Scheduled,
+                external_audience: ExternalAudience::None,
+                duration: Some(Duration {
+                    start_time: DateTime(
+                        time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap(),
+                    ),
+                    end_time: DateTime(
+                        time::OffsetDateTime::from_unix_timestamp(1715245964).unwrap(),
+                    ),
+                }),
+                internal_reply: Some(ReplyBody {
+                    lang: Some("en-US".to_string()),
+                    message: Some("I'm away this week.".to_string()),
+                }),
+                external_reply: None,
+            },
+        };
+
+        let expected = r#"<SetUserOofSettingsRequest xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><t:Mailbox><t:Address>user1@example.com</t:Address></t:Mailbox><t:UserOofSettings><t:OofState>Scheduled</t:OofState><t:ExternalAudience>None</t:ExternalAudience><t:Duration><t:StartTime>2024-05-02T09:12:44.000000000Z</t:StartTime><t:EndTime>2024-05-09T09:12:44.000000000Z</t:EndTime></t:Duration><t:InternalReply xml:lang="en-US"><t:Message>I&apos;m away this week.</t:Message></t:InternalReply></t:UserOofSettings></SetUserOofSettingsRequest>"#;
+
+        assert_serialized_content(
+            &set_user_oof_settings,
+            "SetUserOofSettingsRequest",
+            expected,
+        );
+    }
+
+    #[test]
+    fn deserialize_set_user_oof_settings_response() {
+        let content = r#"<SetUserOofSettingsResponse
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <ResponseMessage ResponseClass="Success">
+                    <ResponseCode>NoError</ResponseCode>
+                </ResponseMessage>
+            </SetUserOofSettingsResponse>"#;
+
+        let expected = SetUserOofSettingsResponse {
+            response_message: ResponseClass::Success(SetUserOofSettingsResponseMessage {}),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
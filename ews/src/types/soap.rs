@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::io::{self, BufRead, Write};
+
 use de::EnvelopeContent;
 use quick_xml::{
     events::{BytesDecl, BytesEnd, BytesStart, Event},
@@ -11,8 +13,11 @@ use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
 use crate::{
-    response::ResponseCode, types::sealed, types::server_version, Error, MessageXml, Operation,
-    OperationResponse, SOAP_NS_URI, TYPES_NS_URI,
+    content::{self, ContentKind},
+    response::ResponseCode,
+    types::sealed,
+    types::server_version,
+    Error, MessageXml, OperationResponse, SOAP_NS_URI, TYPES_NS_URI,
 };
 
 mod de;
@@ -61,9 +66,14 @@ pub struct Envelope<B> {
 
 impl<B> Envelope<B>
 where
-    B: Operation,
+    B: XmlSerialize + sealed::EnvelopeBodyContents,
 {
     /// Serializes the SOAP envelope as a complete XML document.
+    ///
+    /// The body is usually an [`Operation`], but may also be e.g. the reply to
+    /// a request sent by Exchange.
+    ///
+    /// [`Operation`]: crate::Operation
     pub fn as_xml_document(&self) -> Result<Vec<u8>, Error> {
         const SOAP_ENVELOPE: &str = "soap:Envelope";
         const SOAP_HEADER: &str = "soap:Header";
@@ -117,15 +127,39 @@ where
 
         match envelope.body {
             EnvelopeContent::Body(body) => Ok(Envelope {
+                // Responses from EWS always include headers, but requests sent
+                // by Exchange, such as `SendNotification`, may not.
                 headers: envelope
                     .header
-                    .expect("all non-fault responses should have headers")
-                    .inner,
+                    .map(|header| header.inner)
+                    .unwrap_or_default(),
                 body,
             }),
             EnvelopeContent::Fault(fault) => Err(Error::RequestFault(Box::new(fault))),
         }
     }
+
+    /// Populates an [`Envelope`] from raw XML, decoding the content of file
+    /// attachments and items' MIME content into the sinks returned by
+    /// `sink_for` rather than keeping it in memory.
+    ///
+    /// The corresponding fields of the returned envelope are left empty. See
+    /// [`stream_content`] for details.
+    ///
+    /// [`stream_content`]: crate::content::stream_content
+    pub fn from_xml_reader_with_content_sinks<R, F, W>(
+        reader: R,
+        sink_for: F,
+    ) -> Result<Self, Error>
+    where
+        R: BufRead,
+        F: FnMut(ContentKind) -> io::Result<W>,
+        W: Write,
+    {
+        let document = content::stream_content(reader, sink_for)?;
+
+        Self::from_xml_document(&document)
+    }
 }
 
 /// A structured representation of a SOAP fault, indicating an error in an EWS
@@ -187,12 +221,16 @@ pub struct FaultDetail {
 
 #[cfg(test)]
 mod tests {
+    use std::io::Read;
+
     use ews_proc_macros::operation_response;
     use serde::Deserialize;
     use xml_struct::XmlSerialize;
 
     use crate::{
+        content::{ContentKind, TempFileBuffer},
         get_folder::{GetFolderResponse, GetFolderResponseMessage},
+        get_item::GetItemResponse,
         response::{ResponseClass, ResponseCode, ResponseError, ResponseMessages},
         sync_folder_items::SyncFolderItemsResponse,
         types::{
@@ -202,7 +240,7 @@ mod tests {
             },
             sealed::EnvelopeBodyContents,
         },
-        Error, Folder, FolderId, Folders, MessageXml, OperationResponse,
+        Error, Folder, FolderId, Folders, MessageXml, OperationResponse, RealItem,
     };
 
     use super::Envelope;
@@ -217,6 +255,7 @@ mod tests {
 
         impl OperationResponse for SomeStruct {
             type Message = ();
+            type Error = ResponseError;
             fn response_messages(&self) -> &[ResponseClass<Self::Message>] {
                 &self.other_field.response_messages
             }
@@ -243,6 +282,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_envelope_with_content_sinks() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?><s:Envelope xmlns:s="http://schemas.xmlsoap.org/soap/envelope/"><s:Header><h:ServerVersionInfo MajorVersion="15" MinorVersion="20" MajorBuildNumber="7452" MinorBuildNumber="50" Version="V2018_01_08" xmlns:h="http://schemas.microsoft.com/exchange/services/2006/types"/></s:Header><s:Body><m:GetItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types"><m:ResponseMessages><m:GetItemResponseMessage ResponseClass="Success"><m:ResponseCode>NoError</m:ResponseCode><m:Items><t:Message><t:MimeContent CharacterSet="UTF-8">U3ViamVjdDogSGVsbG8NCg0KSGk=</t:MimeContent><t:ItemId Id="AAMkADk=" ChangeKey="CQAAABYA"/><t:Subject>Hello</t:Subject></t:Message></m:Items></m:GetItemResponseMessage></m:ResponseMessages></m:GetItemResponse></s:Body></s:Envelope>"#;
+
+        let mut mime_content = TempFileBuffer::new().unwrap();
+        let envelope: Envelope<GetItemResponse> =
+            Envelope::from_xml_reader_with_content_sinks(xml.as_bytes(), |kind| {
+                assert_eq!(kind, ContentKind::MimeContent);
+
+                mime_content.writer()
+            })
+            .expect("deserialization should succeed");
+
+        let mut decoded = String::new();
+        mime_content
+            .reader()
+            .unwrap()
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "Subject: Hello\r\n\r\nHi");
+
+        let ResponseClass::Success(message) = &envelope.body.response_messages.response_messages[0]
+        else {
+            panic!("response should be successful");
+        };
+        let RealItem::Message(message) = &message.items.inner[0] else {
+            panic!("item should be a message");
+        };
+
+        let mime_content = message
+            .mime_content
+            .as_ref()
+            .expect("MIME content element should be present");
+        assert_eq!(mime_content.character_set.as_deref(), Some("UTF-8"));
+        assert!(
+            mime_content.content.is_empty(),
+            "streamed content should not be kept in the envelope"
+        );
+        assert_eq!(message.subject.as_deref(), Some("Hello"));
+    }
+
     /// A meaningless struct.
     #[derive(Clone, Debug, XmlSerialize)]
     #[operation_response(Bar)]
@@ -545,6 +625,7 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None
                         }
                     ]},
@@ -616,6 +697,7 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None
                         }
                     ]},
@@ -0,0 +1,283 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{BaseFolderId, EventTypes, MESSAGES_NS_URI};
+
+/// A request to be notified of changes to folders and the items they
+/// contain.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/subscribe>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(SubscribeResponseMessage)]
+pub struct Subscribe {
+    /// The kind of subscription to create.
+    #[xml_struct(flatten)]
+    pub subscription_request: SubscriptionRequest,
+}
+
+/// The kind of a subscription, which determines how its notifications are
+/// delivered.
+#[derive(Clone, Debug, XmlSerialize)]
+pub enum SubscriptionRequest {
+    /// A subscription whose events are retrieved with [`GetEvents`].
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pullsubscriptionrequest>
+    ///
+    /// [`GetEvents`]: crate::get_events::GetEvents
+    PullSubscriptionRequest(PullSubscriptionRequest),
+
+    /// A subscription whose events are delivered over a long-lived
+    /// [`GetStreamingEvents`] connection.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/streamingsubscriptionrequest>
+    ///
+    /// [`GetStreamingEvents`]: crate::get_streaming_events::GetStreamingEvents
+    StreamingSubscriptionRequest(StreamingSubscriptionRequest),
+
+    /// A subscription whose events are sent by Exchange to a client-provided
+    /// URL, in [`SendNotification`] requests.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pushsubscriptionrequest>
+    ///
+    /// [`SendNotification`]: crate::send_notification::SendNotification
+    PushSubscriptionRequest(PushSubscriptionRequest),
+}
+
+/// The parameters of a pull subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pullsubscriptionrequest>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct PullSubscriptionRequest {
+    /// Whether to subscribe to all folders of the mailbox, in which case
+    /// `folder_ids` must be omitted.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pullsubscriptionrequest#subscribetoallfolders-attribute>
+    #[xml_struct(attribute)]
+    pub subscribe_to_all_folders: Option<bool>,
+
+    /// The folders to subscribe to.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/folderids>
+    #[xml_struct(ns_prefix = "t")]
+    pub folder_ids: Option<Vec<BaseFolderId>>,
+
+    /// The kinds of events to be notified of.
+    #[xml_struct(ns_prefix = "t")]
+    pub event_types: EventTypes,
+
+    /// The watermark of a previous subscription's event, after which to
+    /// resume notifications.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/watermark>
+    #[xml_struct(ns_prefix = "t")]
+    pub watermark: Option<String>,
+
+    /// The number of minutes after which the subscription expires if no
+    /// events are retrieved, in the range `1..=1440`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/timeout>
+    #[xml_struct(ns_prefix = "t")]
+    pub timeout: u32,
+}
+
+/// The parameters of a streaming subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/streamingsubscriptionrequest>
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct StreamingSubscriptionRequest {
+    /// Whether to subscribe to all folders of the mailbox, in which case
+    /// `folder_ids` must be omitted.
+    #[xml_struct(attribute)]
+    pub subscribe_to_all_folders: Option<bool>,
+
+    /// The folders to subscribe to.
+    #[xml_struct(ns_prefix = "t")]
+    pub folder_ids: Option<Vec<BaseFolderId>>,
+
+    /// The kinds of events to be notified of.
+    #[xml_struct(ns_prefix = "t")]
+    pub event_types: EventTypes,
+}
+
+/// The parameters of a push subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/pushsubscriptionrequest>
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, XmlSerialize)]
+pub struct PushSubscriptionRequest {
+    /// Whether to subscribe to all folders of the mailbox, in which case
+    /// `folder_ids` must be omitted.
+    #[xml_struct(attribute)]
+    pub subscribe_to_all_folders: Option<bool>,
+
+    /// The folders to subscribe to.
+    #[xml_struct(ns_prefix = "t")]
+    pub folder_ids: Option<Vec<BaseFolderId>>,
+
+    /// The kinds of events to be notified of.
+    #[xml_struct(ns_prefix = "t")]
+    pub event_types: EventTypes,
+
+    /// The watermark of a previous subscription's event, after which to
+    /// resume notifications.
+    #[xml_struct(ns_prefix = "t")]
+    pub watermark: Option<String>,
+
+    /// The number of minutes between notifications sent when no events occur,
+    /// in the range `1..=1440`.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/statusfrequency>
+    #[xml_struct(ns_prefix = "t")]
+    pub status_frequency: u32,
+
+    /// The URL to which Exchange sends notifications.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/url-ex15websvcsotherref>
+    #[xml_struct(ns_prefix = "t")]
+    pub URL: String,
+
+    /// Data returned by Exchange with each notification, e.g. to identify the
+    /// subscription.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/callerdata>
+    #[xml_struct(ns_prefix = "t")]
+    pub caller_data: Option<String>,
+}
+
+/// A response to a request to create a subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/subscriberesponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct SubscribeResponseMessage {
+    /// The identifier of the new subscription.
+    pub subscription_id: String,
+
+    /// The watermark from which the subscription starts.
+    ///
+    /// Not included for streaming subscriptions.
+    pub watermark: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DistinguishedFolderName, EventType, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_pull_subscription() {
+        let subscribe = Subscribe {
+            subscription_request: SubscriptionRequest::PullSubscriptionRequest(
+                PullSubscriptionRequest {
+                    subscribe_to_all_folders: None,
+                    folder_ids: Some(vec![BaseFolderId::DistinguishedFolderId {
+                        id: DistinguishedFolderName::Inbox,
+                        change_key: None,
+                        mailbox: None,
+                    }]),
+                    event_types: EventTypes(vec![EventType::NewMailEvent, EventType::MovedEvent]),
+                    watermark: None,
+                    timeout: 10,
+                },
+            ),
+        };
+
+        let expected = r#"<Subscribe xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><PullSubscriptionRequest><t:FolderIds><t:DistinguishedFolderId Id="inbox"/></t:FolderIds><t:EventTypes><t:EventType>NewMailEvent</t:EventType><t:EventType>MovedEvent</t:EventType></t:EventTypes><t:Timeout>10</t:Timeout></PullSubscriptionRequest></Subscribe>"#;
+
+        assert_serialized_content(&subscribe, "Subscribe", expected);
+    }
+
+    #[test]
+    fn serialize_pull_subscription_to_all_folders() {
+        let subscribe = Subscribe {
+            subscription_request: SubscriptionRequest::PullSubscriptionRequest(
+                PullSubscriptionRequest {
+                    subscribe_to_all_folders: Some(true),
+                    folder_ids: None,
+                    event_types: EventTypes(vec![EventType::CreatedEvent]),
+                    watermark: Some("AQAAAMAGAAAAAAAAAQ==".to_string()),
+                    timeout: 1440,
+                },
+            ),
+        };
+
+        let expected = r#"<Subscribe xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><PullSubscriptionRequest SubscribeToAllFolders="true"><t:EventTypes><t:EventType>CreatedEvent</t:EventType></t:EventTypes><t:Watermark>AQAAAMAGAAAAAAAAAQ==</t:Watermark><t:Timeout>1440</t:Timeout></PullSubscriptionRequest></Subscribe>"#;
+
+        assert_serialized_content(&subscribe, "Subscribe", expected);
+    }
+
+    #[test]
+    fn serialize_streaming_subscription() {
+        let subscribe = Subscribe {
+            subscription_request: SubscriptionRequest::StreamingSubscriptionRequest(
+                StreamingSubscriptionRequest {
+                    subscribe_to_all_folders: None,
+                    folder_ids: Some(vec![BaseFolderId::DistinguishedFolderId {
+                        id: DistinguishedFolderName::Inbox,
+                        change_key: None,
+                        mailbox: None,
+                    }]),
+                    event_types: EventTypes(vec![EventType::NewMailEvent]),
+                },
+            ),
+        };
+
+        let expected = r#"<Subscribe xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><StreamingSubscriptionRequest><t:FolderIds><t:DistinguishedFolderId Id="inbox"/></t:FolderIds><t:EventTypes><t:EventType>NewMailEvent</t:EventType></t:EventTypes></StreamingSubscriptionRequest></Subscribe>"#;
+
+        assert_serialized_content(&subscribe, "Subscribe", expected);
+    }
+
+    #[test]
+    fn serialize_push_subscription() {
+        let subscribe = Subscribe {
+            subscription_request: SubscriptionRequest::PushSubscriptionRequest(
+                PushSubscriptionRequest {
+                    subscribe_to_all_folders: Some(true),
+                    folder_ids: None,
+                    event_types: EventTypes(vec![EventType::NewMailEvent, EventType::DeletedEvent]),
+                    watermark: None,
+                    status_frequency: 5,
+                    URL: "https://client.example.com/ews/push".to_string(),
+                    caller_data: Some("mailbox-42".to_string()),
+                },
+            ),
+        };
+
+        let expected = r#"<Subscribe xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><PushSubscriptionRequest SubscribeToAllFolders="true"><t:EventTypes><t:EventType>NewMailEvent</t:EventType><t:EventType>DeletedEvent</t:EventType></t:EventTypes><t:StatusFrequency>5</t:StatusFrequency><t:URL>https://client.example.com/ews/push</t:URL><t:CallerData>mailbox-42</t:CallerData></PushSubscriptionRequest></Subscribe>"#;
+
+        assert_serialized_content(&subscribe, "Subscribe", expected);
+    }
+
+    #[test]
+    fn deserialize_subscribe_response() {
+        let content = r#"<m:SubscribeResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:SubscribeResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:SubscriptionId>4d1f3c8a-5c1b-4f34-a0a1-9d5c1a3e5b7f</m:SubscriptionId>
+                        <m:Watermark>AAAAAMAGAAAAAAAAAQ==</m:Watermark>
+                    </m:SubscribeResponseMessage>
+                </m:ResponseMessages>
+            </m:SubscribeResponse>"#;
+
+        let expected = SubscribeResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(SubscribeResponseMessage {
+                    subscription_id: "4d1f3c8a-5c1b-4f34-a0a1-9d5c1a3e5b7f".to_string(),
+                    watermark: Some("AAAAAMAGAAAAAAAAAQ==".to_string()),
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
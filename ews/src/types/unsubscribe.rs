@@ -0,0 +1,67 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::MESSAGES_NS_URI;
+
+/// A request to end a pull or streaming subscription.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unsubscribe>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(UnsubscribeResponseMessage)]
+pub struct Unsubscribe {
+    /// The identifier of the subscription, as returned by [`Subscribe`].
+    ///
+    /// [`Subscribe`]: crate::subscribe::Subscribe
+    pub subscription_id: String,
+}
+
+/// A response to an [`Unsubscribe`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/unsubscriberesponse>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct UnsubscribeResponseMessage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_unsubscribe() {
+        let unsubscribe = Unsubscribe {
+            subscription_id: "f6bc657d-dde1-4f94-952d-143b95d6483d".to_string(),
+        };
+
+        let expected = r#"<Unsubscribe xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><SubscriptionId>f6bc657d-dde1-4f94-952d-143b95d6483d</SubscriptionId></Unsubscribe>"#;
+
+        assert_serialized_content(&unsubscribe, "Unsubscribe", expected);
+    }
+
+    #[test]
+    fn deserialize_unsubscribe_response() {
+        let content = r#"<m:UnsubscribeResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:ResponseMessages>
+                    <m:UnsubscribeResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                    </m:UnsubscribeResponseMessage>
+                </m:ResponseMessages>
+            </m:UnsubscribeResponse>"#;
+
+        let expected = UnsubscribeResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(UnsubscribeResponseMessage {})],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
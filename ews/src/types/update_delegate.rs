@@ -0,0 +1,78 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    DelegateResponseMessage, DelegateUsers, DeliverMeetingRequests, Mailbox, MESSAGES_NS_URI,
+};
+
+/// A request to change the permissions of existing delegates of a mailbox,
+/// or where its meeting requests are delivered.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updatedelegate-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(DelegateResponseMessage, single_message)]
+pub struct UpdateDelegate {
+    /// The mailbox to update the delegates of. Only the address is required.
+    pub mailbox: Mailbox,
+
+    pub delegate_users: Option<DelegateUsers>,
+
+    pub deliver_meeting_requests: Option<DeliverMeetingRequests>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        response::{ResponseCode, ResponseError},
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        DelegateFolderPermissionLevel, DelegatePermissions, DelegateUser, ResponseClass, UserId,
+    };
+
+    #[test]
+    fn serialize_update_delegate() {
+        let update_delegate = UpdateDelegate {
+            mailbox: Mailbox {
+                email_address: Some("boss@example.com".to_string()),
+                ..Default::default()
+            },
+            delegate_users: Some(DelegateUsers(vec![DelegateUser::new(
+                UserId::from_smtp_address("assistant@example.com"),
+                DelegatePermissions {
+                    inbox_folder_permission_level: Some(DelegateFolderPermissionLevel::Reviewer),
+                    ..Default::default()
+                },
+            )])),
+            deliver_meeting_requests: None,
+        };
+
+        let expected = r#"<UpdateDelegate xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><Mailbox><t:EmailAddress>boss@example.com</t:EmailAddress></Mailbox><DelegateUsers><t:DelegateUser><t:UserId><t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress></t:UserId><t:DelegatePermissions><t:InboxFolderPermissionLevel>Reviewer</t:InboxFolderPermissionLevel></t:DelegatePermissions></t:DelegateUser></DelegateUsers></UpdateDelegate>"#;
+
+        assert_serialized_content(&update_delegate, "UpdateDelegate", expected);
+    }
+
+    #[test]
+    fn deserialize_update_delegate_error_response() {
+        let content = r#"<m:UpdateDelegateResponse ResponseClass="Error"
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:MessageText>The specified object was not found in the store.</m:MessageText>
+                <m:ResponseCode>ErrorItemNotFound</m:ResponseCode>
+                <m:DescriptiveLinkKey>0</m:DescriptiveLinkKey>
+            </m:UpdateDelegateResponse>"#;
+
+        let expected = UpdateDelegateResponse {
+            response_message: ResponseClass::Error(ResponseError {
+                message_text: "The specified object was not found in the store.".to_string(),
+                response_code: ResponseCode::ErrorItemNotFound,
+                message_xml: None,
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -13,6 +13,9 @@ use super::{Folder, Folders, PathToElement};
 /// The unique identifier of an update to be performed on a folder.
 ///
 /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updates-folder>
+// Boxing the folder would make this type more awkward to build, and only a
+// single update is ever held at a time.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, XmlSerialize)]
 #[xml_struct(variant_ns_prefix = "t")]
 pub enum Updates {
@@ -82,7 +85,11 @@ mod tests {
     use super::*;
     use crate::test_utils::assert_deserialized_content;
     use crate::test_utils::assert_serialized_content;
-    use crate::{BaseFolderId, FolderId, ResponseClass, ResponseMessages};
+    use crate::{
+        BaseFolderId, DistinguishedFolderName, DistinguishedUser, FolderId, Permission,
+        PermissionLevel, PermissionSet, Permissions, ResponseClass, ResponseMessages,
+        UnindexedFieldURI, UserId,
+    };
 
     #[test]
     fn serialize_update_request() {
@@ -95,7 +102,7 @@ mod tests {
                     },
                     updates: Updates::SetFolderField {
                         field_URI: PathToElement::FieldURI {
-                            field_URI: "folder:DisplayName".to_string(),
+                            field_URI: UnindexedFieldURI::FolderDisplayName,
                         },
                         folder: Folder::Folder {
                             display_name: Some("NewFolderName".to_string()),
@@ -105,6 +112,7 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None,
                         },
                     },
@@ -117,6 +125,60 @@ mod tests {
         assert_serialized_content(&update_folder, "UpdateFolder", expected);
     }
 
+    #[test]
+    fn serialize_update_permissions_request() {
+        let update_folder = UpdateFolder {
+            folder_changes: FolderChanges {
+                folder_change: FolderChange {
+                    folder_id: BaseFolderId::DistinguishedFolderId {
+                        id: DistinguishedFolderName::Inbox,
+                        change_key: None,
+                        mailbox: None,
+                    },
+                    updates: Updates::SetFolderField {
+                        field_URI: PathToElement::FieldURI {
+                            field_URI: UnindexedFieldURI::FolderPermissionSet,
+                        },
+                        folder: Folder::Folder {
+                            folder_id: None,
+                            parent_folder_id: None,
+                            folder_class: None,
+                            display_name: None,
+                            total_count: None,
+                            child_folder_count: None,
+                            extended_property: None,
+                            permission_set: Some(PermissionSet {
+                                permissions: Some(Permissions {
+                                    inner: vec![
+                                        Permission::new(
+                                            UserId {
+                                                distinguished_user: Some(
+                                                    DistinguishedUser::Default,
+                                                ),
+                                                ..Default::default()
+                                            },
+                                            PermissionLevel::None,
+                                        ),
+                                        Permission::new(
+                                            UserId::from_smtp_address("assistant@example.com"),
+                                            PermissionLevel::Reviewer,
+                                        ),
+                                    ],
+                                }),
+                                calendar_permissions: None,
+                            }),
+                            unread_count: None,
+                        },
+                    },
+                },
+            },
+        };
+
+        let expected = r#"<UpdateFolder xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><FolderChanges><t:FolderChange><t:DistinguishedFolderId Id="inbox"/><t:Updates><t:SetFolderField><t:FieldURI FieldURI="folder:PermissionSet"/><t:Folder><t:PermissionSet><t:Permissions><t:Permission><t:UserId><t:DistinguishedUser>Default</t:DistinguishedUser></t:UserId><t:PermissionLevel>None</t:PermissionLevel></t:Permission><t:Permission><t:UserId><t:PrimarySmtpAddress>assistant@example.com</t:PrimarySmtpAddress></t:UserId><t:PermissionLevel>Reviewer</t:PermissionLevel></t:Permission></t:Permissions></t:PermissionSet></t:Folder></t:SetFolderField></t:Updates></t:FolderChange></FolderChanges></UpdateFolder>"#;
+
+        assert_serialized_content(&update_folder, "UpdateFolder", expected);
+    }
+
     #[test]
     fn deserialize_update_response() {
         let content = r#"<UpdateFolderResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages"
@@ -149,6 +211,7 @@ mod tests {
                             total_count: None,
                             child_folder_count: None,
                             extended_property: None,
+                            permission_set: None,
                             unread_count: None,
                         }],
                     },
@@ -0,0 +1,183 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{
+    response::ResponseCode, MessageXml, RuleOperation, RuleOperationErrors, MESSAGES_NS_URI,
+};
+
+/// A request to create, change or delete Inbox rules of a mailbox.
+///
+/// If the request fails validation, the failed operations are listed in the
+/// [`UpdateInboxRulesError`] of the response.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateinboxrules-operation>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(
+    UpdateInboxRulesResponseMessage,
+    single_message,
+    error = UpdateInboxRulesError
+)]
+pub struct UpdateInboxRules {
+    /// The address of the mailbox to update the rules of, if not the
+    /// authenticated user's.
+    pub mailbox_smtp_address: Option<String>,
+
+    /// Whether to remove rules created by Outlook. The request fails if
+    /// such rules exist and this isn't set.
+    pub remove_outlook_rule_blob: Option<bool>,
+
+    /// The changes to make, which are applied in order.
+    pub operations: Vec<RuleOperation>,
+}
+
+/// The result of a successful [`UpdateInboxRules`] operation, which carries
+/// no data beyond its response class.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct UpdateInboxRulesResponseMessage {}
+
+/// The error returned by a failed [`UpdateInboxRules`] operation.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct UpdateInboxRulesError {
+    pub message_text: String,
+    pub response_code: ResponseCode,
+    pub message_xml: Option<MessageXml>,
+
+    /// The rule operations which failed validation, with
+    /// [`ResponseCode::ErrorInboxRulesValidationError`].
+    pub rule_operation_errors: Option<RuleOperationErrors>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        ArrayOfStrings, BaseFolderId, DistinguishedFolderName, ResponseClass, Rule, RuleActions,
+        RuleFieldURI, RuleOperationError, RulePredicates, RuleValidationError,
+        RuleValidationErrorCode, RuleValidationErrors, TargetFolderId,
+    };
+
+    #[test]
+    fn serialize_update_inbox_rules() {
+        let update_inbox_rules = UpdateInboxRules {
+            mailbox_smtp_address: None,
+            remove_outlook_rule_blob: Some(true),
+            operations: vec![
+                RuleOperation::CreateRuleOperation {
+                    rule: Rule {
+                        display_name: "Invoices".to_string(),
+                        priority: 2,
+                        is_enabled: true,
+                        conditions: Some(RulePredicates {
+                            contains_subject_strings: Some(ArrayOfStrings {
+                                inner: vec!["Invoice".to_string()],
+                            }),
+                            ..Default::default()
+                        }),
+                        actions: Some(RuleActions {
+                            assign_categories: Some(ArrayOfStrings {
+                                inner: vec!["Finance".to_string()],
+                            }),
+                            move_to_folder: Some(TargetFolderId {
+                                folder_id: BaseFolderId::DistinguishedFolderId {
+                                    id: DistinguishedFolderName::Other("archive".to_string()),
+                                    change_key: None,
+                                    mailbox: None,
+                                },
+                            }),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    },
+                },
+                RuleOperation::DeleteRuleOperation {
+                    rule_id: "AQAAAAAAAAE=".to_string(),
+                },
+            ],
+        };
+
+        let expected = r#"<UpdateInboxRules xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><RemoveOutlookRuleBlob>true</RemoveOutlookRuleBlob><Operations><t:CreateRuleOperation><t:Rule><t:DisplayName>Invoices</t:DisplayName><t:Priority>2</t:Priority><t:IsEnabled>true</t:IsEnabled><t:Conditions><t:ContainsSubjectStrings><t:String>Invoice</t:String></t:ContainsSubjectStrings></t:Conditions><t:Actions><t:AssignCategories><t:String>Finance</t:String></t:AssignCategories><t:MoveToFolder><t:DistinguishedFolderId Id="archive"/></t:MoveToFolder></t:Actions></t:Rule></t:CreateRuleOperation><t:DeleteRuleOperation><t:RuleId>AQAAAAAAAAE=</t:RuleId></t:DeleteRuleOperation></Operations></UpdateInboxRules>"#;
+
+        assert_serialized_content(&update_inbox_rules, "UpdateInboxRules", expected);
+    }
+
+    #[test]
+    fn deserialize_update_inbox_rules_response() {
+        let content = r#"<UpdateInboxRulesResponse ResponseClass="Success"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <ResponseCode>NoError</ResponseCode>
+            </UpdateInboxRulesResponse>"#;
+
+        let expected = UpdateInboxRulesResponse {
+            response_message: ResponseClass::Success(UpdateInboxRulesResponseMessage {}),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn deserialize_update_inbox_rules_validation_error() {
+        let content = r#"<UpdateInboxRulesResponse ResponseClass="Error"
+                xmlns="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <MessageText>Validation error occurred while processing inbox rules.</MessageText>
+                <ResponseCode>ErrorInboxRulesValidationError</ResponseCode>
+                <DescriptiveLinkKey>0</DescriptiveLinkKey>
+                <RuleOperationErrors>
+                    <RuleOperationError xmlns="http://schemas.microsoft.com/exchange/services/2006/types">
+                        <OperationIndex>0</OperationIndex>
+                        <ValidationErrors>
+                            <Error>
+                                <FieldURI>ActionMoveToFolder</FieldURI>
+                                <ErrorCode>FolderDoesNotExist</ErrorCode>
+                                <ErrorMessage>The folder doesn't exist.</ErrorMessage>
+                                <FieldValue>AAMkAGEz</FieldValue>
+                            </Error>
+                            <Error>
+                                <FieldURI>ConditionFromContacts</FieldURI>
+                                <ErrorCode>ContactsNotSupported</ErrorCode>
+                                <ErrorMessage>Contacts aren't supported.</ErrorMessage>
+                            </Error>
+                        </ValidationErrors>
+                    </RuleOperationError>
+                </RuleOperationErrors>
+            </UpdateInboxRulesResponse>"#;
+
+        let expected = UpdateInboxRulesResponse {
+            response_message: ResponseClass::Error(UpdateInboxRulesError {
+                message_text: "Validation error occurred while processing inbox rules.".to_string(),
+                response_code: ResponseCode::ErrorInboxRulesValidationError,
+                message_xml: None,
+                rule_operation_errors: Some(RuleOperationErrors {
+                    inner: vec![RuleOperationError {
+                        operation_index: 0,
+                        validation_errors: RuleValidationErrors {
+                            inner: vec![
+                                RuleValidationError {
+                                    field_uri: RuleFieldURI::ActionMoveToFolder,
+                                    error_code: RuleValidationErrorCode::FolderDoesNotExist,
+                                    error_message: "The folder doesn't exist.".to_string(),
+                                    field_value: Some("AAMkAGEz".to_string()),
+                                },
+                                RuleValidationError {
+                                    field_uri: RuleFieldURI::Unknown,
+                                    error_code: RuleValidationErrorCode::Unknown,
+                                    error_message: "Contacts aren't supported.".to_string(),
+                                    field_value: None,
+                                },
+                            ],
+                        },
+                    }],
+                }),
+            }),
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
@@ -6,8 +6,8 @@ use ews_proc_macros::operation_response;
 use serde::Deserialize;
 use xml_struct::XmlSerialize;
 
-use crate::types::common::{BaseItemId, Message, MessageDisposition, PathToElement};
-use crate::{Items, MESSAGES_NS_URI};
+use crate::types::common::{BaseItemId, MessageDisposition, PathToElement, RealItem};
+use crate::{Flag, Items, Message, UnindexedFieldURI, MESSAGES_NS_URI};
 
 /// A request to update properties of one or more Exchange items.
 ///
@@ -35,6 +35,21 @@ pub struct UpdateItem {
     #[xml_struct(attribute)]
     pub conflict_resolution: Option<ConflictResolution>,
 
+    /// Whether and to whom meeting invitations or cancellations are sent when
+    /// updating a calendar item.
+    ///
+    /// This field is required for and only applicable to calendar items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateitem#sendmeetinginvitationsorcancellations-attribute>
+    #[xml_struct(attribute)]
+    pub send_meeting_invitations_or_cancellations: Option<SendMeetingInvitationsOrCancellations>,
+
+    /// Whether to suppress read receipts for the updated items.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateitem#suppressreadreceipts-attribute>
+    #[xml_struct(attribute)]
+    pub suppress_read_receipts: Option<bool>,
+
     /// A list of items and their corresponding updates.
     ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/itemchanges>
@@ -45,6 +60,36 @@ pub struct UpdateItem {
 #[serde(rename_all = "PascalCase")]
 pub struct UpdateItemResponseMessage {
     pub items: Items,
+
+    /// The number of conflicts encountered while applying the update.
+    ///
+    /// Only present in responses to requests using
+    /// [`ConflictResolution::NeverOverwrite`] or
+    /// [`ConflictResolution::AutoResolve`].
+    pub conflict_results: Option<ConflictResults>,
+}
+
+/// The number of conflicts encountered while updating an item.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/conflictresults>
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ConflictResults {
+    pub count: u32,
+}
+
+/// Whether and to whom meeting invitations or cancellations are sent when a
+/// calendar item is updated.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateitem#sendmeetinginvitationsorcancellations-attribute>
+#[derive(Clone, Copy, Debug, XmlSerialize)]
+#[xml_struct(text)]
+pub enum SendMeetingInvitationsOrCancellations {
+    SendToNone,
+    SendOnlyToAll,
+    SendOnlyToChanged,
+    SendToAllAndSaveCopy,
+    SendToChangedAndSaveCopy,
 }
 
 /// The method used by the Exchange server to resolve conflicts between item
@@ -101,14 +146,286 @@ pub struct Updates {
 pub enum ItemChangeDescription {
     /// An update setting the value of a single field.
     ///
+    /// When updating a single entry of a dictionary-based property, such as a
+    /// contact's email addresses, `field_uri` should be a
+    /// [`PathToElement::IndexedFieldURI`] and `item` should only contain the
+    /// entry with the matching key.
+    ///
     /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/setitemfield>
     SetItemField {
         /// The field to be updated.
         #[xml_struct(flatten, ns_prefix = "t")]
         field_uri: PathToElement,
 
-        /// The new value of the specified field.
-        #[xml_struct(ns_prefix = "t")]
-        message: Message,
+        /// An item containing the new value of the specified field.
+        #[xml_struct(flatten, ns_prefix = "t")]
+        item: RealItem,
+    },
+
+    /// An update appending to the value of a single field.
+    ///
+    /// Only a few fields support appending, notably the body of an item and
+    /// the recipients of a message or the attendees of a calendar item.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/appendtoitemfield>
+    AppendToItemField {
+        /// The field to be appended to.
+        #[xml_struct(flatten, ns_prefix = "t")]
+        field_uri: PathToElement,
+
+        /// An item containing the value to append to the specified field.
+        #[xml_struct(flatten, ns_prefix = "t")]
+        item: RealItem,
+    },
+
+    /// An update removing the value of a single field, such as a flag.
+    ///
+    /// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/deleteitemfield>
+    DeleteItemField {
+        /// The field to be removed.
+        #[xml_struct(flatten, ns_prefix = "t")]
+        field_uri: PathToElement,
     },
 }
+
+impl ItemChangeDescription {
+    /// An update setting the follow-up flag of a message.
+    ///
+    /// See [`Flag::flagged`], [`Flag::flagged_between`] and
+    /// [`Flag::completed`] for common flags.
+    pub fn set_flag(flag: Flag) -> Self {
+        Self::SetItemField {
+            field_uri: PathToElement::FieldURI {
+                field_URI: UnindexedFieldURI::ItemFlag,
+            },
+            item: RealItem::Message(Message {
+                flag: Some(flag),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// An update clearing the follow-up flag of a message.
+    ///
+    /// Exchange doesn't support removing the flag field, so this sets it to
+    /// [`FlagStatus::NotFlagged`] instead.
+    ///
+    /// [`FlagStatus::NotFlagged`]: crate::FlagStatus::NotFlagged
+    pub fn clear_flag() -> Self {
+        Self::set_flag(Flag::not_flagged())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        Body, BodyType, CalendarItem, Contact, DateTime, Dictionary, DictionaryURI,
+        EmailAddressEntry, EmailAddressKey, ItemId, ResponseClass, ResponseMessages,
+    };
+
+    #[test]
+    fn serialize_update_message_field() {
+        let update_item = UpdateItem {
+            message_disposition: MessageDisposition::SaveOnly,
+            conflict_resolution: None,
+            send_meeting_invitations_or_cancellations: None,
+            suppress_read_receipts: None,
+            item_changes: vec![ItemChange {
+                item_change: ItemChangeInner {
+                    item_id: BaseItemId::ItemId {
+                        id: "AAMkAGE=".to_string(),
+                        change_key: Some("CQAAABYA".to_string()),
+                    },
+                    updates: Updates {
+                        inner: vec![ItemChangeDescription::SetItemField {
+                            field_uri: PathToElement::FieldURI {
+                                field_URI: UnindexedFieldURI::MessageIsRead,
+                            },
+                            item: RealItem::Message(Message {
+                                is_read: Some(true),
+                                ..Default::default()
+                            }),
+                        }],
+                    },
+                },
+            }],
+        };
+
+        let expected = r#"<UpdateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly"><ItemChanges><t:ItemChange><t:ItemId Id="AAMkAGE=" ChangeKey="CQAAABYA"/><t:Updates><t:SetItemField><t:FieldURI FieldURI="message:IsRead"/><t:Message><t:IsRead>true</t:IsRead></t:Message></t:SetItemField></t:Updates></t:ItemChange></ItemChanges></UpdateItem>"#;
+
+        assert_serialized_content(&update_item, "UpdateItem", expected);
+    }
+
+    #[test]
+    fn serialize_update_contact_indexed_field() {
+        let update_item = UpdateItem {
+            message_disposition: MessageDisposition::SaveOnly,
+            conflict_resolution: Some(ConflictResolution::AlwaysOverwrite),
+            send_meeting_invitations_or_cancellations: None,
+            suppress_read_receipts: None,
+            item_changes: vec![ItemChange {
+                item_change: ItemChangeInner {
+                    item_id: BaseItemId::ItemId {
+                        id: "AAMkADk=".to_string(),
+                        change_key: Some("EQAAABYA".to_string()),
+                    },
+                    updates: Updates {
+                        inner: vec![ItemChangeDescription::SetItemField {
+                            field_uri: PathToElement::IndexedFieldURI {
+                                field_URI: DictionaryURI::ContactsEmailAddress,
+                                field_index: "EmailAddress2".to_string(),
+                            },
+                            item: RealItem::Contact(Contact {
+                                email_addresses: Some(Dictionary {
+                                    entries: vec![EmailAddressEntry {
+                                        key: EmailAddressKey::EmailAddress2,
+                                        name: None,
+                                        routing_type: None,
+                                        mailbox_type: None,
+                                        address: "alice@example.org".to_string(),
+                                    }],
+                                }),
+                                ..Default::default()
+                            }),
+                        }],
+                    },
+                },
+            }],
+        };
+
+        let expected = r#"<UpdateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly" ConflictResolution="AlwaysOverwrite"><ItemChanges><t:ItemChange><t:ItemId Id="AAMkADk=" ChangeKey="EQAAABYA"/><t:Updates><t:SetItemField><t:IndexedFieldURI FieldURI="contacts:EmailAddress" FieldIndex="EmailAddress2"/><t:Contact><t:EmailAddresses><t:Entry Key="EmailAddress2">alice@example.org</t:Entry></t:EmailAddresses></t:Contact></t:SetItemField></t:Updates></t:ItemChange></ItemChanges></UpdateItem>"#;
+
+        assert_serialized_content(&update_item, "UpdateItem", expected);
+    }
+
+    #[test]
+    fn serialize_append_and_delete_fields() {
+        let update_item = UpdateItem {
+            message_disposition: MessageDisposition::SaveOnly,
+            conflict_resolution: Some(ConflictResolution::NeverOverwrite),
+            send_meeting_invitations_or_cancellations: Some(
+                SendMeetingInvitationsOrCancellations::SendToNone,
+            ),
+            suppress_read_receipts: Some(true),
+            item_changes: vec![ItemChange {
+                item_change: ItemChangeInner {
+                    item_id: BaseItemId::ItemId {
+                        id: "AAMkAGE=".to_string(),
+                        change_key: Some("DwAAABYA".to_string()),
+                    },
+                    updates: Updates {
+                        inner: vec![
+                            ItemChangeDescription::AppendToItemField {
+                                field_uri: PathToElement::FieldURI {
+                                    field_URI: UnindexedFieldURI::ItemBody,
+                                },
+                                item: RealItem::CalendarItem(CalendarItem {
+                                    body: Some(Body {
+                                        body_type: BodyType::Text,
+                                        is_truncated: None,
+                                        content: Some("Agenda attached.".to_string()),
+                                    }),
+                                    ..Default::default()
+                                }),
+                            },
+                            ItemChangeDescription::DeleteItemField {
+                                field_uri: PathToElement::FieldURI {
+                                    field_URI: UnindexedFieldURI::ItemCategories,
+                                },
+                            },
+                        ],
+                    },
+                },
+            }],
+        };
+
+        let expected = r#"<UpdateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly" ConflictResolution="NeverOverwrite" SendMeetingInvitationsOrCancellations="SendToNone" SuppressReadReceipts="true"><ItemChanges><t:ItemChange><t:ItemId Id="AAMkAGE=" ChangeKey="DwAAABYA"/><t:Updates><t:AppendToItemField><t:FieldURI FieldURI="item:Body"/><t:CalendarItem><t:Body BodyType="Text">Agenda attached.</t:Body></t:CalendarItem></t:AppendToItemField><t:DeleteItemField><t:FieldURI FieldURI="item:Categories"/></t:DeleteItemField></t:Updates></t:ItemChange></ItemChanges></UpdateItem>"#;
+
+        assert_serialized_content(&update_item, "UpdateItem", expected);
+    }
+
+    #[test]
+    fn deserialize_update_item_response_with_conflicts() {
+        let content = r#"<m:UpdateItemResponse xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages" xmlns:t="http://schemas.microsoft.com/exchange/services/2006/types">
+                <m:ResponseMessages>
+                    <m:UpdateItemResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                        <m:Items>
+                            <t:Message>
+                                <t:ItemId Id="AAMkAGE=" ChangeKey="DwAAABYB"/>
+                            </t:Message>
+                        </m:Items>
+                        <m:ConflictResults>
+                            <t:Count>1</t:Count>
+                        </m:ConflictResults>
+                    </m:UpdateItemResponseMessage>
+                </m:ResponseMessages>
+            </m:UpdateItemResponse>"#;
+
+        let expected = UpdateItemResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(UpdateItemResponseMessage {
+                    items: Items {
+                        inner: vec![RealItem::Message(Message {
+                            item_id: Some(ItemId {
+                                id: "AAMkAGE=".to_string(),
+                                change_key: Some("DwAAABYB".to_string()),
+                            }),
+                            ..Default::default()
+                        })],
+                    },
+                    conflict_results: Some(ConflictResults { count: 1 }),
+                })],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+
+    #[test]
+    fn serialize_flag_updates() {
+        let complete_date =
+            DateTime(time::OffsetDateTime::from_unix_timestamp(1714641164).unwrap());
+
+        let update_item = UpdateItem {
+            message_disposition: MessageDisposition::SaveOnly,
+            conflict_resolution: None,
+            send_meeting_invitations_or_cancellations: None,
+            suppress_read_receipts: None,
+            item_changes: [
+                ItemChangeDescription::set_flag(Flag::flagged()),
+                ItemChangeDescription::set_flag(Flag::completed(complete_date)),
+                ItemChangeDescription::clear_flag(),
+            ]
+            .into_iter()
+            .map(|change| ItemChange {
+                item_change: ItemChangeInner {
+                    item_id: BaseItemId::ItemId {
+                        id: "AAMkAGE=".to_string(),
+                        change_key: None,
+                    },
+                    updates: Updates {
+                        inner: vec![change],
+                    },
+                },
+            })
+            .collect(),
+        };
+
+        let change = |flag: &str| {
+            format!(
+                r#"<t:ItemChange><t:ItemId Id="AAMkAGE="/><t:Updates><t:SetItemField><t:FieldURI FieldURI="item:Flag"/><t:Message><t:Flag>{flag}</t:Flag></t:Message></t:SetItemField></t:Updates></t:ItemChange>"#
+            )
+        };
+        let expected = format!(
+            r#"<UpdateItem xmlns="http://schemas.microsoft.com/exchange/services/2006/messages" MessageDisposition="SaveOnly"><ItemChanges>{}{}{}</ItemChanges></UpdateItem>"#,
+            change("<t:FlagStatus>Flagged</t:FlagStatus>"),
+            change("<t:FlagStatus>Complete</t:FlagStatus><t:CompleteDate>2024-05-02T09:12:44.000000000Z</t:CompleteDate>"),
+            change("<t:FlagStatus>NotFlagged</t:FlagStatus>"),
+        );
+
+        assert_serialized_content(&update_item, "UpdateItem", &expected);
+    }
+}
@@ -0,0 +1,83 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+use ews_proc_macros::operation_response;
+use serde::Deserialize;
+use xml_struct::XmlSerialize;
+
+use crate::{UserConfiguration, MESSAGES_NS_URI};
+
+/// A request to replace the contents of an existing user configuration
+/// object.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateuserconfiguration>
+#[derive(Clone, Debug, XmlSerialize)]
+#[xml_struct(default_ns = MESSAGES_NS_URI)]
+#[operation_response(UpdateUserConfigurationResponseMessage)]
+pub struct UpdateUserConfiguration {
+    pub user_configuration: UserConfiguration,
+}
+
+/// A response to an [`UpdateUserConfiguration`] request.
+///
+/// See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/updateuserconfigurationresponsemessage>
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+pub struct UpdateUserConfigurationResponseMessage {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{assert_deserialized_content, assert_serialized_content},
+        BaseFolderId, DistinguishedFolderName, ResponseClass, ResponseMessages,
+        UserConfigurationName,
+    };
+
+    #[test]
+    fn serialize_update_user_configuration() {
+        let update_user_configuration = UpdateUserConfiguration {
+            user_configuration: UserConfiguration::with_xml_data(
+                UserConfigurationName {
+                    name: "CategoryList".to_string(),
+                    folder_id: BaseFolderId::DistinguishedFolderId {
+                        id: DistinguishedFolderName::Calendar,
+                        change_key: None,
+                        mailbox: None,
+                    },
+                },
+                b"<categories/>",
+            ),
+        };
+
+        let expected = r#"<UpdateUserConfiguration xmlns="http://schemas.microsoft.com/exchange/services/2006/messages"><UserConfiguration><t:UserConfigurationName Name="CategoryList"><t:DistinguishedFolderId Id="calendar"/></t:UserConfigurationName><t:XmlData>PGNhdGVnb3JpZXMvPg==</t:XmlData></UserConfiguration></UpdateUserConfiguration>"#;
+
+        assert_serialized_content(
+            &update_user_configuration,
+            "UpdateUserConfiguration",
+            expected,
+        );
+    }
+
+    #[test]
+    fn deserialize_update_user_configuration_response() {
+        let content = r#"<UpdateUserConfigurationResponse
+                xmlns:m="http://schemas.microsoft.com/exchange/services/2006/messages">
+                <m:ResponseMessages>
+                    <m:UpdateUserConfigurationResponseMessage ResponseClass="Success">
+                        <m:ResponseCode>NoError</m:ResponseCode>
+                    </m:UpdateUserConfigurationResponseMessage>
+                </m:ResponseMessages>
+            </UpdateUserConfigurationResponse>"#;
+
+        let expected = UpdateUserConfigurationResponse {
+            response_messages: ResponseMessages {
+                response_messages: vec![ResponseClass::Success(
+                    UpdateUserConfigurationResponseMessage {},
+                )],
+            },
+        };
+
+        assert_deserialized_content(content, expected);
+    }
+}
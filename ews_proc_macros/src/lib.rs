@@ -1,60 +1,216 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Ident, ItemStruct};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, Ident, ItemStruct, LitStr, Path, Token,
+};
 
 /// Annotate a struct as having its response include response messages of the given type,
 /// and generate a response struct for it with the expected attributes and methods.
 ///
 /// Response structs are named by appending "Response" to the end of the name of this struct.
+///
+/// The message type may be followed by options, for operations which don't follow the usual
+/// conventions:
+///
+/// - `single_message`: the response contains a single response message rather than a
+///   `ResponseMessages` list, and the response element is itself that message.
+/// - `single_message = "ElementName"`: as above, but the message is the child of the response
+///   element with the given name.
+/// - `name = "ElementName"`: the name of the request element, if it isn't the name of the
+///   annotated struct.
+/// - `response_name = "ElementName"`: the name of the response element, if it isn't the name of
+///   the response struct.
+/// - `try_from = Type`: the response struct is deserialized from the given type, which must
+///   implement `TryFrom` for it. Only valid with `single_message`.
+/// - `error = Type`: the type of error response messages, for operations whose errors carry
+///   details beyond those of a `ResponseError`.
 #[proc_macro_attribute]
 pub fn operation_response(attr: TokenStream, annotated_item: TokenStream) -> TokenStream {
-    let response_type = parse_macro_input!(attr as Ident);
+    let args = parse_macro_input!(attr as OperationResponseArgs);
     let input_struct = parse_macro_input!(annotated_item as ItemStruct);
 
+    let response_type = &args.message_type;
     let request_name = input_struct.ident.clone();
     let response_name = Ident::new(&format!("{request_name}Response"), request_name.span());
 
+    let request_element = args
+        .name
+        .map(|name| name.value())
+        .unwrap_or_else(|| request_name.to_string());
+    let response_element = args
+        .response_name
+        .map(|name| name.value())
+        .unwrap_or_else(|| response_name.to_string());
+
     let response_doc = format!(
         r#"A response to a [`{request_name}`] operation.
 
 See <https://learn.microsoft.com/en-us/exchange/client-developer/web-service-reference/{}>"#,
-        response_name.to_string().to_ascii_lowercase()
+        response_element.to_ascii_lowercase()
     );
     let response_doc_attr = quote! { #[doc = #response_doc] };
 
+    let error_type = match &args.error {
+        Some(error) => quote! { #error },
+        None => quote! { crate::response::ResponseError },
+    };
+
+    let response_struct = match (args.single_message, args.try_from) {
+        (None, None) => quote! {
+            #response_doc_attr
+            #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct #response_name {
+                pub response_messages: crate::ResponseMessages<#response_type, #error_type>,
+            }
+
+            impl crate::OperationResponse for #response_name {
+                type Message = #response_type;
+                type Error = #error_type;
+                fn response_messages(&self) -> &[crate::ResponseClass<Self::Message, Self::Error>] {
+                    self.response_messages.response_messages.as_slice()
+                }
+                fn into_response_messages(
+                    self,
+                ) -> Vec<crate::ResponseClass<Self::Message, Self::Error>> {
+                    self.response_messages.response_messages
+                }
+            }
+        },
+        (None, Some(try_from)) => {
+            return syn::Error::new_spanned(try_from, "`try_from` requires `single_message`")
+                .into_compile_error()
+                .into();
+        }
+        (Some(single_message), try_from) => {
+            let container_attr = match (&single_message, try_from) {
+                (_, Some(try_from)) => {
+                    let try_from = quote!(#try_from).to_string().replace(' ', "");
+                    quote! { #[serde(try_from = #try_from)] }
+                }
+                (SingleMessage::Element(_), None) => quote! {},
+                (SingleMessage::Transparent, None) => quote! { #[serde(transparent)] },
+            };
+            let field_attr = match &single_message {
+                SingleMessage::Element(element) => quote! { #[serde(rename = #element)] },
+                SingleMessage::Transparent => quote! {},
+            };
+
+            quote! {
+                #response_doc_attr
+                #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
+                #container_attr
+                pub struct #response_name {
+                    #field_attr
+                    pub response_message: crate::ResponseClass<#response_type, #error_type>,
+                }
+
+                impl crate::OperationResponse for #response_name {
+                    type Message = #response_type;
+                    type Error = #error_type;
+                    fn response_messages(
+                        &self,
+                    ) -> &[crate::ResponseClass<Self::Message, Self::Error>] {
+                        std::slice::from_ref(&self.response_message)
+                    }
+                    fn into_response_messages(
+                        self,
+                    ) -> Vec<crate::ResponseClass<Self::Message, Self::Error>> {
+                        vec![self.response_message]
+                    }
+                }
+            }
+        }
+    };
+
     let expanded = quote! {
         #input_struct
 
         impl crate::Operation for #request_name {
             type Response = #response_name;
-            const NAME: &'static str = stringify!(#request_name);
+            const NAME: &'static str = #request_element;
         }
 
         impl crate::types::sealed::EnvelopeBodyContents for #request_name {
-            const NAME: &'static str = stringify!(#request_name);
+            const NAME: &'static str = #request_element;
         }
 
-        #response_doc_attr
-        #[derive(Clone, Debug, serde::Deserialize, PartialEq, Eq)]
-        #[serde(rename_all = "PascalCase")]
-        pub struct #response_name {
-            pub response_messages: crate::ResponseMessages<#response_type>,
-        }
-
-        impl crate::OperationResponse for #response_name {
-            type Message = #response_type;
-            fn response_messages(&self) -> &[crate::ResponseClass<Self::Message>] {
-                self.response_messages.response_messages.as_slice()
-            }
-            fn into_response_messages(self) -> Vec<crate::ResponseClass<Self::Message>> {
-                self.response_messages.response_messages
-            }
-        }
+        #response_struct
 
         impl crate::types::sealed::EnvelopeBodyContents for #response_name {
-            const NAME: &'static str = stringify!(#response_name);
+            const NAME: &'static str = #response_element;
         }
     };
 
     TokenStream::from(expanded)
 }
+
+/// The arguments of the `operation_response` attribute.
+struct OperationResponseArgs {
+    message_type: Ident,
+    single_message: Option<SingleMessage>,
+    name: Option<LitStr>,
+    response_name: Option<LitStr>,
+    try_from: Option<Path>,
+    error: Option<Path>,
+}
+
+/// Where the message of a response with a single response message is found.
+enum SingleMessage {
+    /// The response element is itself the message.
+    Transparent,
+
+    /// The message is the child element with the given name.
+    Element(LitStr),
+}
+
+impl Parse for OperationResponseArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = Self {
+            message_type: input.parse()?,
+            single_message: None,
+            name: None,
+            response_name: None,
+            try_from: None,
+            error: None,
+        };
+
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let option: Ident = input.parse()?;
+            match option.to_string().as_str() {
+                "single_message" => {
+                    args.single_message = if input.parse::<Option<Token![=]>>()?.is_some() {
+                        Some(SingleMessage::Element(input.parse()?))
+                    } else {
+                        Some(SingleMessage::Transparent)
+                    };
+                }
+                "name" => {
+                    input.parse::<Token![=]>()?;
+                    args.name = Some(input.parse()?);
+                }
+                "response_name" => {
+                    input.parse::<Token![=]>()?;
+                    args.response_name = Some(input.parse()?);
+                }
+                "try_from" => {
+                    input.parse::<Token![=]>()?;
+                    args.try_from = Some(input.parse()?);
+                }
+                "error" => {
+                    input.parse::<Token![=]>()?;
+                    args.error = Some(input.parse()?);
+                }
+                _ => return Err(syn::Error::new_spanned(option, "unknown option")),
+            }
+        }
+
+        Ok(args)
+    }
+}